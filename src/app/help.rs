@@ -47,6 +47,13 @@ impl<'b, 'c, T> ArgWithOrder<'b, 'c> for T
     }
 }
 
+// Whether an arg is left out of the help message, which may depend on `-h` vs. `--help`
+fn is_hidden(arg: &ArgWithDisplay, use_long: bool) -> bool {
+    arg.is_set(ArgSettings::Hidden) ||
+    (use_long && arg.is_set(ArgSettings::HiddenLongHelp)) ||
+    (!use_long && arg.is_set(ArgSettings::HiddenShortHelp))
+}
+
 fn as_arg_trait<'a, 'b, T: ArgWithOrder<'a, 'b>>(x: &T) -> &ArgWithOrder<'a, 'b> {
     x
 }
@@ -86,6 +93,7 @@ pub struct Help<'a> {
     cizer: Colorizer,
     longest: usize,
     force_next_line: bool,
+    use_long: bool,
 }
 
 // Public Functions
@@ -115,6 +123,7 @@ impl<'a> Help<'a> {
             cizer: cizer,
             longest: 0,
             force_next_line: false,
+            use_long: false,
         }
     }
 
@@ -129,7 +138,14 @@ impl<'a> Help<'a> {
     /// and write its help to the wrapped stream.
     pub fn write_parser_help(w: &'a mut Write, parser: &Parser) -> ClapResult<()> {
         debugln!("fn=Help::write_parser_help;");
-        Self::_write_parser_help(w, parser, false)
+        Self::_write_parser_help(w, parser, false, false)
+    }
+
+    /// Reads help settings from a Parser
+    /// and write its long help (as displayed by `--help`) to the wrapped stream.
+    pub fn write_parser_long_help(w: &'a mut Write, parser: &Parser) -> ClapResult<()> {
+        debugln!("fn=Help::write_parser_long_help;");
+        Self::_write_parser_help(w, parser, false, true)
     }
 
     /// Reads help settings from a Parser
@@ -137,11 +153,15 @@ impl<'a> Help<'a> {
     /// formatting when required.
     pub fn write_parser_help_to_stderr(w: &'a mut Write, parser: &Parser) -> ClapResult<()> {
         debugln!("fn=Help::write_parser_help;");
-        Self::_write_parser_help(w, parser, true)
+        Self::_write_parser_help(w, parser, true, false)
     }
 
     #[doc(hidden)]
-    pub fn _write_parser_help(w: &'a mut Write, parser: &Parser, stderr: bool) -> ClapResult<()> {
+    pub fn _write_parser_help(w: &'a mut Write,
+                              parser: &Parser,
+                              stderr: bool,
+                              use_long: bool)
+                              -> ClapResult<()> {
         debugln!("fn=Help::write_parser_help;");
         let nlh = parser.is_set(AppSettings::NextLineHelp);
         let hide_v = parser.is_set(AppSettings::HidePossibleValuesInHelp);
@@ -150,7 +170,9 @@ impl<'a> Help<'a> {
             use_stderr: stderr,
             when: parser.color(),
         };
-        Self::new(w, nlh, hide_v, color, cizer, parser.meta.term_w, parser.meta.max_w).write_help(parser)
+        let mut h = Self::new(w, nlh, hide_v, color, cizer, parser.meta.term_w, parser.meta.max_w);
+        h.use_long = use_long;
+        h.write_help(parser)
     }
 
     /// Writes the parser help to the wrapped stream.
@@ -176,15 +198,12 @@ impl<'a> Help<'a> {
         // The shortest an arg can legally be is 2 (i.e. '-x')
         self.longest = 2;
         let mut arg_v = Vec::with_capacity(10);
-        for arg in args.filter(|arg| {
-            !(arg.is_set(ArgSettings::Hidden)) || arg.is_set(ArgSettings::NextLineHelp)
-        }) {
-            if arg.longest_filter() {
-                self.longest = cmp::max(self.longest, arg.to_string().len());
-            }
-            if !arg.is_set(ArgSettings::Hidden) {
-                arg_v.push(arg)
+        let use_long = self.use_long;
+        for arg in args.filter(|arg| !is_hidden(arg.as_base(), use_long)) {
+            if arg.longest_filter() && !arg.is_set(ArgSettings::NextLineHelp) {
+                self.longest = cmp::max(self.longest, self.column_width(arg.as_base()));
             }
+            arg_v.push(arg)
         }
         let mut first = true;
         for arg in arg_v {
@@ -206,16 +225,15 @@ impl<'a> Help<'a> {
         // The shortest an arg can legally be is 2 (i.e. '-x')
         self.longest = 2;
         let mut ord_m = VecMap::new();
+        let use_long = self.use_long;
         // Determine the longest
-        for arg in args.filter(|arg| {
-            // If it's NextLineHelp, but we don't care to compute how long because it may be 
-            // NextLineHelp on purpose *because* it's so long and would throw off all other 
+        for arg in args.filter(|arg| !is_hidden(arg.as_base(), use_long)) {
+            // If it's NextLineHelp we don't care to compute how long, because it may be
+            // NextLineHelp on purpose *because* it's so long and would throw off all other
             // args alignment
-            !arg.is_set(ArgSettings::Hidden) || arg.is_set(ArgSettings::NextLineHelp)
-        }) {
-            if arg.longest_filter() {
+            if arg.longest_filter() && !arg.is_set(ArgSettings::NextLineHelp) {
                 debugln!("Longest...{}", self.longest);
-                self.longest = cmp::max(self.longest, arg.to_string().len());
+                self.longest = cmp::max(self.longest, self.column_width(arg.as_base()));
                 debugln!("New Longest...{}", self.longest);
             }
             let btm = ord_m.entry(arg.disp_ord()).or_insert(BTreeMap::new());
@@ -235,6 +253,15 @@ impl<'a> Help<'a> {
         Ok(())
     }
 
+    /// The width an argument takes up in the first column, or 0 if it's too wide to be taken into
+    /// account. The help column is never pushed past half of the terminal width, so that a single
+    /// long argument can't squeeze the help of all the others. Any argument wider than that
+    /// displays its help on the next line instead.
+    fn column_width<'b, 'c>(&self, arg: &ArgWithDisplay<'b, 'c>) -> usize {
+        let w = str_width(&*arg.to_string());
+        if w + 12 > self.term_w / 2 { 0 } else { w }
+    }
+
     /// Writes help for an argument to the wrapped stream.
     fn write_arg<'b, 'c>(&mut self,
                          arg: &ArgWithDisplay<'b, 'c>)
@@ -273,7 +300,8 @@ impl<'a> Help<'a> {
                 }
                 try!(color!(self, "--{}", l, good))
             }
-            try!(write!(self.writer, " "));
+            let sep = if arg.is_set(ArgSettings::RequireEquals) { "=" } else { " " };
+            try!(write!(self.writer, "{}", sep));
         } else if let Some(l) = arg.long() {
             if arg.short().is_some() {
                 try!(write!(self.writer, ", "));
@@ -321,13 +349,16 @@ impl<'a> Help<'a> {
         }
 
         let spec_vals = self.spec_vals(arg);
-        let h = arg.help().unwrap_or("");
+        let h = self.arg_help(arg).unwrap_or("");
         let h_w = str_width(h) + str_width(&*spec_vals);
         let nlh = self.next_line_help || arg.is_set(ArgSettings::NextLineHelp);
         let taken = self.longest + 12;
-        self.force_next_line = !nlh && self.term_w >= taken &&
-            (taken as f32 / self.term_w as f32) > 0.40 &&
-            h_w > (self.term_w - taken);
+        let self_len = str_width(&*arg.to_string());
+        let too_wide = arg.longest_filter() && self_len > self.longest;
+        self.force_next_line = !nlh &&
+            (too_wide ||
+             self.term_w >= taken && (taken as f32 / self.term_w as f32) > 0.40 &&
+             h_w > (self.term_w - taken));
 
         debug!("Has switch...");
         if arg.has_switch() {
@@ -340,7 +371,6 @@ impl<'a> Help<'a> {
             debug!("next_line...");
             if !(nlh || self.force_next_line) {
                 sdebugln!("No");
-                let self_len = arg.to_string().len();
                 // subtract ourself
                 let mut spcs = self.longest - self_len;
                 // Since we're writing spaces from the tab point we first need to know if we
@@ -359,7 +389,7 @@ impl<'a> Help<'a> {
             }
         } else if !(nlh || self.force_next_line) {
             sdebugln!("No, and not next_line");
-            write_nspaces!(self.writer, self.longest + 4 - (arg.to_string().len()));
+            write_nspaces!(self.writer, self.longest + 4 - self_len);
         } else {
             sdebugln!("No");
         }
@@ -414,11 +444,20 @@ impl<'a> Help<'a> {
         Ok(())
     }
 
+    /// Picks the help text of an argument, preferring the long help when displaying `--help`.
+    fn arg_help<'b, 'c>(&self, arg: &ArgWithDisplay<'b, 'c>) -> Option<&'c str> {
+        if self.use_long {
+            arg.long_help().or_else(|| arg.help())
+        } else {
+            arg.help()
+        }
+    }
+
     /// Writes argument's help to the wrapped stream.
     fn help<'b, 'c>(&mut self, arg: &ArgWithDisplay<'b, 'c>, spec_vals: &str) -> io::Result<()> {
         debugln!("fn=help;");
         let mut help = String::new();
-        let h = arg.help().unwrap_or("");
+        let h = self.arg_help(arg).unwrap_or("");
         let nlh = self.next_line_help || arg.is_set(ArgSettings::NextLineHelp);
         debugln!("Next Line...{:?}", nlh);
 
@@ -492,14 +531,17 @@ impl<'a> Help<'a> {
     fn spec_vals(&self, a: &ArgWithDisplay) -> String {
         debugln!("fn=spec_vals;a={}", a);
         let mut spec_vals = vec![];
-        if let Some(pv) = a.default_val() {
-            debugln!("Found default value...[{}]", pv);
-            spec_vals.push(format!(" [default: {}]",
-                if self.color {
-                    self.cizer.good(pv)
-                } else {
-                    Format::None(pv)
-            }));
+        if !a.is_set(ArgSettings::HideDefaultValue) {
+            if let Some(pv) = a.default_val() {
+                debugln!("Found default value...[{:?}]", pv);
+                let pv = pv.to_string_lossy();
+                spec_vals.push(format!(" [default: {}]",
+                    if self.color {
+                        self.cizer.good(pv)
+                    } else {
+                        Format::None(pv)
+                }));
+            }
         } 
         if let Some(ref aliases) = a.aliases() {
             debugln!("Found aliases...{:?}", aliases);
@@ -539,9 +581,12 @@ impl<'a> Help<'a> {
     #[cfg_attr(feature = "lints", allow(useless_let_if_seq))]
     pub fn write_all_args(&mut self, parser: &Parser) -> ClapResult<()> {
 
-        let flags = parser.has_flags();
-        let pos = parser.has_positionals();
-        let opts = parser.has_opts();
+        // Args with a help heading are written in their own sections below, and a section whose
+        // args are all hidden isn't written at all
+        let use_long = self.use_long;
+        let flags = parser.flags().any(|f| f.help_heading.is_none() && !is_hidden(f, use_long));
+        let pos = parser.positionals().any(|p| !is_hidden(p, use_long));
+        let opts = parser.opts().any(|o| o.help_heading.is_none() && !is_hidden(o, use_long));
         let subcmds = parser.has_subcommands();
 
         let unified_help = parser.is_set(AppSettings::UnifiedHelpMessage);
@@ -550,8 +595,9 @@ impl<'a> Help<'a> {
 
         if unified_help && (flags || opts) {
             let opts_flags = parser.flags()
+                .filter(|f| f.help_heading.is_none())
                 .map(as_arg_trait)
-                .chain(parser.opts().map(as_arg_trait));
+                .chain(parser.opts().filter(|o| o.help_heading.is_none()).map(as_arg_trait));
             try!(color!(self, "OPTIONS:\n", warning));
             try!(self.write_args(opts_flags));
             first = false;
@@ -559,6 +605,7 @@ impl<'a> Help<'a> {
             if flags {
                 try!(color!(self, "FLAGS:\n", warning));
                 try!(self.write_args(parser.flags()
+                    .filter(|f| f.help_heading.is_none())
                     .map(as_arg_trait)));
                 first = false;
             }
@@ -567,11 +614,35 @@ impl<'a> Help<'a> {
                     try!(self.writer.write(b"\n\n"));
                 }
                 try!(color!(self, "OPTIONS:\n", warning));
-                try!(self.write_args(parser.opts().map(as_arg_trait)));
+                try!(self.write_args(parser.opts()
+                    .filter(|o| o.help_heading.is_none())
+                    .map(as_arg_trait)));
                 first = false;
             }
         }
 
+        // Headings are written in the order they were first used, and the args within each one
+        // are sorted by display order just like any other section
+        for &heading in &parser.help_headings {
+            let in_heading = |h: Option<&str>| h == Some(heading);
+            let visible = parser.flags()
+                .any(|f| in_heading(f.help_heading) && !is_hidden(f, self.use_long)) ||
+                parser.opts()
+                .any(|o| in_heading(o.help_heading) && !is_hidden(o, self.use_long));
+            if !visible {
+                continue;
+            }
+            if !first {
+                try!(self.writer.write(b"\n\n"));
+            }
+            try!(color!(self, "{}:\n", heading, warning));
+            try!(self.write_args(parser.flags()
+                .filter(|f| in_heading(f.help_heading))
+                .map(as_arg_trait)
+                .chain(parser.opts().filter(|o| in_heading(o.help_heading)).map(as_arg_trait))));
+            first = false;
+        }
+
         if pos {
             if !first {
                 try!(self.writer.write(b"\n\n"));
@@ -587,6 +658,15 @@ impl<'a> Help<'a> {
             }
             try!(color!(self, "SUBCOMMANDS:\n", warning));
             try!(self.write_subcommands(&parser));
+            first = false;
+        }
+
+        if parser.meta.help_topics.is_some() {
+            if !first {
+                try!(self.writer.write(b"\n\n"));
+            }
+            try!(color!(self, "TOPICS:\n", warning));
+            try!(self.write_help_topics(&parser));
         }
 
         Ok(())
@@ -618,6 +698,22 @@ impl<'a> Help<'a> {
         Ok(())
     }
 
+    /// Writes the help topic names of a Parser Object to the wrapped stream.
+    fn write_help_topics(&mut self, parser: &Parser) -> io::Result<()> {
+        debugln!("fn=write_help_topics;");
+        let mut first = true;
+        for &(name, _) in parser.meta.help_topics.iter().flat_map(|t| t.iter()) {
+            if first {
+                first = false;
+            } else {
+                try!(self.writer.write(b"\n"));
+            }
+            try!(write!(self.writer, "{}", TAB));
+            try!(color!(self, name, good));
+        }
+        Ok(())
+    }
+
     /// Writes version of a Parser Object to the wrapped stream.
     fn write_version(&mut self, parser: &Parser) -> io::Result<()> {
         try!(write!(self.writer, "{}", parser.meta.version.unwrap_or("".into())));
@@ -670,12 +766,14 @@ impl<'a> Help<'a> {
         let opts = parser.has_opts();
         let subcmds = parser.has_subcommands();
 
-        if flags || opts || pos || subcmds {
+        let topics = parser.meta.help_topics.is_some();
+
+        if flags || opts || pos || subcmds || topics {
             try!(self.write_all_args(&parser));
         }
 
         if let Some(h) = parser.meta.more_help {
-            if flags || opts || pos || subcmds {
+            if flags || opts || pos || subcmds || topics {
                 try!(self.writer.write(b"\n\n"));
             }
             try!(self.write_before_after_help(h));
@@ -797,6 +895,7 @@ impl<'a> Help<'a> {
     ///     * `{bin}`         - Binary name.
     ///     * `{version}`     - Version number.
     ///     * `{author}`      - Author information.
+    ///     * `{about}`       - General description.
     ///     * `{usage}`       - Automatically generated or given usage string.
     ///     * `{all-args}`    - Help for all arguments (options, flags, positionals arguments,
     ///                         and subcommands) including titles.
@@ -805,6 +904,7 @@ impl<'a> Help<'a> {
     ///     * `{options}`     - Help for options.
     ///     * `{positionals}` - Help for positionals arguments.
     ///     * `{subcommands}` - Help for subcommands.
+    ///     * `{topics}`      - Names of the help topics.
     ///     * `{after-help}`  - Info to be displayed after the help message.
     ///     * `{before-help}` - Info to be displayed before the help message.
     ///
@@ -884,15 +984,18 @@ impl<'a> Help<'a> {
                 b"subcommands" => {
                     try!(self.write_subcommands(&parser));
                 }
+                b"topics" => {
+                    try!(self.write_help_topics(&parser));
+                }
                 b"after-help" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.more_help.unwrap_or("unknown after-help")));
+                    try!(self.write_before_after_help(parser.meta
+                        .more_help
+                        .unwrap_or("unknown after-help")));
                 }
                 b"before-help" => {
-                    try!(write!(self.writer,
-                                "{}",
-                                parser.meta.pre_help.unwrap_or("unknown before-help")));
+                    try!(self.write_before_after_help(parser.meta
+                        .pre_help
+                        .unwrap_or("unknown before-help")));
                 }
                 // Unknown tag, write it back.
                 r => {
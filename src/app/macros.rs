@@ -38,8 +38,11 @@ macro_rules! arg_post_processing {
         debug!("Does '{}' have overrides...", $arg.to_string());
         if let Some(or) = $arg.overrides() {
             sdebugln!("Yes");
-            $matcher.remove_all(or);
-            for pa in or { remove_overriden!($me, pa); }
+            // An argument which overrides itself was already handled before it was parsed
+            for pa in or.iter().filter(|&&pa| pa != $arg.name()) {
+                $matcher.remove(pa);
+                remove_overriden!($me, pa);
+            }
             $me.overrides.extend(or);
             vec_remove_all!($me.required, or);
         } else { sdebugln!("No"); }
@@ -136,6 +139,16 @@ macro_rules! validate_multiples {
                 &*$_self.create_current_usage($m),
                 $_self.color()))
         }
+        if let Some(max) = $a.max_occurs {
+            let occurs = $m.get($a.name).map_or(0, |ma| ma.occurs);
+            if occurs >= max {
+                return Err(Error::too_many_occurrences($a,
+                    max,
+                    occurs + 1,
+                    &*$_self.create_current_usage($m),
+                    $_self.color()))
+            }
+        }
     };
 }
 
@@ -174,6 +187,9 @@ macro_rules! find_from {
     ($_self:ident, $arg_name:expr, $from:ident, $matcher:expr) => {{
         let mut ret = None;
         for k in $matcher.arg_names() {
+            if k == *$arg_name {
+                continue;
+            }
             if let Some(f) = $_self.find_flag(k) {
                 if let Some(ref v) = f.$from {
                     if v.contains($arg_name) {
@@ -204,6 +220,10 @@ macro_rules! find_name_from {
     ($_self:ident, $arg_name:expr, $from:ident, $matcher:expr) => {{
         let mut ret = None;
         for k in $matcher.arg_names() {
+            // An argument which overrides itself only had its previous occurrence dropped
+            if k == *$arg_name {
+                continue;
+            }
             if let Some(f) = $_self.find_flag(k) {
                 if let Some(ref v) = f.$from {
                     if v.contains($arg_name) {
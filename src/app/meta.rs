@@ -5,6 +5,7 @@ pub struct AppMeta<'b> {
     pub bin_name: Option<String>,
     pub author: Option<&'b str>,
     pub version: Option<&'b str>,
+    pub long_version: Option<&'b str>,
     pub about: Option<&'b str>,
     pub more_help: Option<&'b str>,
     pub pre_help: Option<&'b str>,
@@ -16,6 +17,7 @@ pub struct AppMeta<'b> {
     pub term_w: Option<usize>,
     pub max_w: Option<usize>,
     pub template: Option<&'b str>,
+    pub help_topics: Option<Vec<(&'b str, &'b str)>>, // (name, text)
 }
 
 impl<'b> Default for AppMeta<'b> {
@@ -27,6 +29,7 @@ impl<'b> Default for AppMeta<'b> {
             more_help: None,
             pre_help: None,
             version: None,
+            long_version: None,
             usage_str: None,
             usage: None,
             bin_name: None,
@@ -36,6 +39,7 @@ impl<'b> Default for AppMeta<'b> {
             aliases: None,
             term_w: None,
             max_w: None,
+            help_topics: None,
         }
     }
 }
@@ -58,6 +62,7 @@ impl<'b> Clone for AppMeta<'b> {
             more_help: self.more_help,
             pre_help: self.pre_help,
             version: self.version,
+            long_version: self.long_version,
             usage_str: self.usage_str,
             usage: self.usage.clone(),
             bin_name: self.bin_name.clone(),
@@ -67,6 +72,7 @@ impl<'b> Clone for AppMeta<'b> {
             aliases: self.aliases.clone(),
             term_w: self.term_w,
             max_w: self.max_w,
+            help_topics: self.help_topics.clone(),
         }
     }
 }
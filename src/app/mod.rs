@@ -8,14 +8,16 @@ mod help;
 // Std
 use std::borrow::Borrow;
 use std::env;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use osstringext::OsStrExt2;
 use std::fmt;
 use std::io::{self, BufRead, BufWriter, Write};
+use std::iter::Map;
 use std::path::Path;
 use std::process;
 use std::rc::Rc;
 use std::result::Result as StdResult;
+use std::slice;
 
 // Third Party
 use vec_map::VecMap;
@@ -25,11 +27,12 @@ use yaml_rust::Yaml;
 // Internal
 use app::help::Help;
 use app::parser::Parser;
-use args::{AnyArg, Arg, ArgGroup, ArgMatcher, ArgMatches, ArgSettings};
+use args::{AnyArg, Arg, ArgGroup, ArgMatcher, ArgMatches, ArgSettings, ValueParser};
 use errors::Error;
 use errors::Result as ClapResult;
+use fmt::ColorChoice;
 pub use self::settings::AppSettings;
-use completions::Shell;
+use completions::{COMPLETION_VALUES_FLAG, Shell};
 
 /// Used to create a representation of a command line program and all possible command line
 /// arguments. Application settings are set using the "builder pattern" with the
@@ -94,6 +97,100 @@ impl<'a, 'b> App<'a, 'b> {
         self.p.meta.bin_name.as_ref().map(|s| s.as_str())
     }
 
+    /// Returns an iterator over the names of the direct [`SubCommand`]s of this `App`, in the
+    /// order they were defined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, SubCommand};
+    /// let app = App::new("myprog")
+    ///     .subcommand(SubCommand::with_name("install"))
+    ///     .subcommand(SubCommand::with_name("remove"));
+    ///
+    /// assert_eq!(app.subcommand_names().collect::<Vec<_>>(), ["install", "remove"]);
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn subcommand_names<'c>(&'c self)
+        -> Map<slice::Iter<'c, App<'a, 'b>>, fn(&'c App<'a, 'b>) -> &'c str> {
+        fn name<'c, 'a, 'b>(sc: &'c App<'a, 'b>) -> &'c str {
+            &*sc.p.meta.name
+        }
+        let name: fn(&'c App<'a, 'b>) -> &'c str = name; // coerce to fn pointer
+        self.p.subcommands.iter().map(name)
+    }
+
+    /// Returns an iterator over the definitions of the direct [`SubCommand`]s of this `App`, in
+    /// the order they were defined. Together with the other getters this allows walking the
+    /// whole tree of commands, for example to generate documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, SubCommand};
+    /// let app = App::new("myprog")
+    ///     .subcommand(SubCommand::with_name("remote")
+    ///         .subcommand(SubCommand::with_name("add")));
+    ///
+    /// for sc in app.get_subcommands() {
+    ///     assert_eq!(sc.get_name(), "remote");
+    ///     assert_eq!(sc.subcommand_names().collect::<Vec<_>>(), ["add"]);
+    /// }
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn get_subcommands(&self) -> slice::Iter<App<'a, 'b>> {
+        self.p.subcommands.iter()
+    }
+
+    /// Returns an iterator over the definitions of all arguments of this `App`, in the order they
+    /// were added. This allows external code, such as custom help renderers or completion
+    /// generators, to inspect the arguments' names, help, values and settings.
+    ///
+    /// **NOTE:** The `help` and `version` flags `clap` generates itself are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("myprog")
+    ///     .arg(Arg::with_name("verbose").short("v"))
+    ///     .arg(Arg::with_name("config").long("config").takes_value(true))
+    ///     .arg(Arg::with_name("input"));
+    ///
+    /// assert_eq!(app.get_arguments().count(), 3);
+    /// ```
+    pub fn get_arguments(&self) -> slice::Iter<Arg<'a, 'b>> {
+        self.p.args.iter()
+    }
+
+    /// Finds the definition of a direct [`SubCommand`] of this `App` by its name, or by any of
+    /// its aliases (whether visible or not). This inspects the definition only, and can be used
+    /// before parsing, for example to document the registered subcommands. To find out which
+    /// subcommand was used at runtime see [`ArgMatches::subcommand_matches`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, SubCommand};
+    /// let app = App::new("myprog")
+    ///     .subcommand(SubCommand::with_name("install")
+    ///         .about("Installs a package")
+    ///         .visible_alias("add"));
+    ///
+    /// assert_eq!(app.find_subcommand("install").unwrap().get_name(), "install");
+    /// assert_eq!(app.find_subcommand("add").unwrap().get_name(), "install");
+    /// assert!(app.find_subcommand("remove").is_none());
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ArgMatches::subcommand_matches`]: ./struct.ArgMatches.html#method.subcommand_matches
+    pub fn find_subcommand<S: AsRef<str>>(&self, name: S) -> Option<&App<'a, 'b>> {
+        let name = name.as_ref();
+        self.p.subcommands.iter().find(|sc| {
+            sc.p.meta.name == name ||
+            sc.p.meta.aliases.as_ref().map_or(false, |als| als.iter().any(|&(a, _)| a == name))
+        })
+    }
+
     /// Creates a new instance of an application requiring a name, but uses the [`crate_authors!`]
     /// and [`crate_version!`] macros to fill in the [`App::author`] and [`App::version`] fields.
     ///
@@ -228,6 +325,9 @@ impl<'a, 'b> App<'a, 'b> {
     /// information is displayed **after** the auto-generated help information. This is often used
     /// to describe how to use the arguments, or caveats to be noted.
     ///
+    /// The text is displayed by both `-h` and `--help`, and is wrapped to the terminal width like
+    /// the rest of the help message. Use `{n}` to force a line break.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -245,6 +345,9 @@ impl<'a, 'b> App<'a, 'b> {
     /// information is displayed **before** the auto-generated help information. This is often used
     /// for header information.
     ///
+    /// The text is displayed by both `-h` and `--help`, and is wrapped to the terminal width like
+    /// the rest of the help message. Use `{n}` to force a line break.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -280,6 +383,26 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Sets a string of the version to be displayed when the user passes `--version`, which may
+    /// be longer and more detailed than the one set with [`App::version`] (such as the commit
+    /// hash, build date or enabled features). `-V` still displays the version set with
+    /// [`App::version`], and if no long version is set `--version` displays that one as well.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .version("v0.1.24")
+    ///     .long_version("v0.1.24\ncommit: 4a6f2bd\nfeatures: yaml")
+    /// # ;
+    /// ```
+    /// [`App::version`]: ./struct.App.html#method.version
+    pub fn long_version<S: Into<&'b str>>(mut self, ver: S) -> Self {
+        self.p.meta.long_version = Some(ver.into());
+        self
+    }
+
     /// Sets a custom usage string to override the auto-generated usage string.
     ///
     /// This will be displayed to the user when errors are found in argument parsing, or when you
@@ -300,15 +423,31 @@ impl<'a, 'b> App<'a, 'b> {
     /// ```no_run
     /// # use clap::{App, Arg};
     /// App::new("myprog")
-    ///     .usage("myapp [-clDas] <some_file>")
+    ///     .override_usage("myapp [-clDas] <some_file>")
     /// # ;
     /// ```
     /// [`ArgMatches::usage`]: ./struct.ArgMatches.html#method.usage
-    pub fn usage<S: Into<&'b str>>(mut self, usage: S) -> Self {
+    pub fn override_usage<S: Into<&'b str>>(mut self, usage: S) -> Self {
         self.p.meta.usage_str = Some(usage.into());
         self
     }
 
+    /// Sets a custom usage string to override the auto-generated usage string. This is the same
+    /// as [`App::override_usage`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .usage("myapp [-clDas] <some_file>")
+    /// # ;
+    /// ```
+    /// [`App::override_usage`]: ./struct.App.html#method.override_usage
+    pub fn usage<S: Into<&'b str>>(self, usage: S) -> Self {
+        self.override_usage(usage)
+    }
+
     /// Sets a custom help message and overrides the auto-generated one. This should only be used
     /// when the auto-generated message does not suffice.
     ///
@@ -318,14 +457,14 @@ impl<'a, 'b> App<'a, 'b> {
     ///
     /// **NOTE:** This **only** replaces the help message for the current command, meaning if you
     /// are using subcommands, those help messages will still be auto-generated unless you
-    /// specify a [`Arg::help`] for them as well.
+    /// specify an [`App::override_help`] for them as well.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use clap::{App, Arg};
     /// App::new("myapp")
-    ///     .help("myapp v1.0\n\
+    ///     .override_help("myapp v1.0\n\
     ///            Does awesome things\n\
     ///            (C) me@mail.com\n\n\
     ///
@@ -342,12 +481,29 @@ impl<'a, 'b> App<'a, 'b> {
     ///            work             Do some work")
     /// # ;
     /// ```
-    /// [`Arg::help`]: ./struct.Arg.html#method.help
-    pub fn help<S: Into<&'b str>>(mut self, help: S) -> Self {
+    /// [`App::override_help`]: ./struct.App.html#method.override_help
+    pub fn override_help<S: Into<&'b str>>(mut self, help: S) -> Self {
         self.p.meta.help_str = Some(help.into());
         self
     }
 
+    /// Sets a custom help message and overrides the auto-generated one. This is the same as
+    /// [`App::override_help`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myapp")
+    ///     .help("myapp v1.0\n\
+    ///            Does awesome things")
+    /// # ;
+    /// ```
+    /// [`App::override_help`]: ./struct.App.html#method.override_help
+    pub fn help<S: Into<&'b str>>(self, help: S) -> Self {
+        self.override_help(help)
+    }
+
     /// Sets the [`short`] for the auto-generated `help` argument.
     ///
     /// By default `clap` automatically assigns `h`, but this can be overridden if you have a
@@ -360,14 +516,26 @@ impl<'a, 'b> App<'a, 'b> {
     /// **NOTE:** Any leading `-` characters will be stripped, and only the first
     /// non `-` character will be used as the [`short`] version
     ///
+    /// **NOTE:** The [`short`] set here must not be used by any other argument, which is checked
+    /// by [`App::debug_assert`]
+    ///
     /// # Examples
     ///
-    /// ```no_run
-    /// # use clap::{App, Arg};
-    /// App::new("myprog")
-    ///     .help_short("H") // Using an uppercase `H` instead of the default lowercase `h`
-    /// # ;
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = App::new("myprog")
+    ///     .help_short("?") // Using `-?` instead of the default `-h`
+    ///     .arg(Arg::with_name("host")
+    ///         .short("h")
+    ///         .takes_value(true));
+    ///
+    /// let res = app.clone().get_matches_from_safe(vec!["myprog", "-?"]);
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::HelpDisplayed);
+    ///
+    /// let m = app.get_matches_from(vec!["myprog", "-h", "localhost"]);
+    /// assert_eq!(m.value_of("host"), Some("localhost"));
     /// ```
+    /// [`App::debug_assert`]: ./struct.App.html#method.debug_assert
     /// [`short`]: ./struct.Arg.html#method.short
     pub fn help_short<S: AsRef<str> + 'b>(mut self, s: S) -> Self {
         self.p.help_short(s.as_ref());
@@ -386,6 +554,9 @@ impl<'a, 'b> App<'a, 'b> {
     /// **NOTE:** Any leading `-` characters will be stripped, and only the first
     /// non `-` character will be used as the `short` version
     ///
+    /// **NOTE:** The [`short`] set here must not be used by any other argument, which is checked
+    /// by [`App::debug_assert`]
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -395,20 +566,24 @@ impl<'a, 'b> App<'a, 'b> {
     /// # ;
     /// ```
     /// [`short`]: ./struct.Arg.html#method.short
+    /// [`App::debug_assert`]: ./struct.App.html#method.debug_assert
     pub fn version_short<S: AsRef<str>>(mut self, s: S) -> Self {
         self.p.version_short(s.as_ref());
         self
     }
 
-    /// Sets the help template to be used, overriding the default format.
+    /// Sets the help template to be used, overriding the default format. Each tag in the
+    /// template is replaced with the section of help it names, so the sections appear in
+    /// whichever order the template lists them.
     ///
-    /// Tags arg given inside curly brackets.
+    /// Tags are given inside curly brackets.
     ///
     /// Valid tags are:
     ///
     ///   * `{bin}`         - Binary name.
     ///   * `{version}`     - Version number.
     ///   * `{author}`      - Author information.
+    ///   * `{about}`       - General description (from [`App::about`]).
     ///   * `{usage}`       - Automatically generated or given usage string.
     ///   * `{all-args}`    - Help for all arguments (options, flags, positionals arguments,
     ///                       and subcommands) including titles.
@@ -417,7 +592,12 @@ impl<'a, 'b> App<'a, 'b> {
     ///   * `{options}`     - Help for options.
     ///   * `{positionals}` - Help for positionals arguments.
     ///   * `{subcommands}` - Help for subcommands.
-    ///   * `{after-help}`  - Help for flags.
+    ///   * `{topics}`      - Names of the help topics.
+    ///   * `{after-help}`  - Help from [`App::after_help`].
+    ///   * `{before-help}` - Help from [`App::before_help`].
+    ///
+    /// Any tag which isn't listed above is written to the help message literally, including the
+    /// curly brackets.
     ///
     /// # Examples
     ///
@@ -425,16 +605,67 @@ impl<'a, 'b> App<'a, 'b> {
     /// # use clap::{App, Arg};
     /// App::new("myprog")
     ///     .version("1.0")
-    ///     .template("{bin} ({version}) - {usage}")
+    ///     .help_template("{bin} ({version}) - {usage}")
     /// # ;
     /// ```
-    /// **NOTE:**The template system is, on purpose, very simple. Therefore the tags have to writen
-    /// in the lowercase and without spacing.
-    pub fn template<S: Into<&'b str>>(mut self, s: S) -> Self {
+    /// **NOTE:** The template system is, on purpose, very simple. Therefore the tags have to
+    /// written in the lowercase and without spacing.
+    /// [`App::about`]: ./struct.App.html#method.about
+    /// [`App::after_help`]: ./struct.App.html#method.after_help
+    /// [`App::before_help`]: ./struct.App.html#method.before_help
+    pub fn help_template<S: Into<&'b str>>(mut self, s: S) -> Self {
         self.p.meta.template = Some(s.into());
         self
     }
 
+    /// Sets the help template to be used, overriding the default format. This is the same as
+    /// [`App::help_template`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .version("1.0")
+    ///     .template("{bin} ({version}) - {usage}")
+    /// # ;
+    /// ```
+    /// [`App::help_template`]: ./struct.App.html#method.help_template
+    pub fn template<S: Into<&'b str>>(self, s: S) -> Self {
+        self.help_template(s)
+    }
+
+    /// Adds a help topic, which is a piece of free form documentation (such as a tutorial or
+    /// the explanation of a concept) that isn't tied to any particular argument or
+    /// [`SubCommand`].
+    ///
+    /// Topics are displayed with `--help=<topic>`, or `help topic <topic>` when the `help`
+    /// subcommand is available. The names of all topics are listed under a `TOPICS:` section of
+    /// the normal help message. Asking for a topic which doesn't exist results in an
+    /// [`ErrorKind::InvalidValue`] error listing the available topics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .help_topic("config", "The config file is read from ~/.myprog")
+    ///     .get_matches_from_safe(vec!["myprog", "--help=config"]);
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    /// assert_eq!(err.message, "The config file is read from ~/.myprog");
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ErrorKind::InvalidValue`]: ./enum.ErrorKind.html#variant.InvalidValue
+    pub fn help_topic(mut self, name: &'b str, text: &'b str) -> Self {
+        if let Some(ref mut topics) = self.p.meta.help_topics {
+            topics.push((name, text));
+        } else {
+            self.p.meta.help_topics = Some(vec![(name, text)]);
+        }
+        self
+    }
+
     /// Enables a single command, or [`SubCommand`], level settings.
     ///
     /// See [`AppSettings`] for a full list of possibilities and examples.
@@ -483,6 +714,9 @@ impl<'a, 'b> App<'a, 'b> {
     ///
     /// **NOTE**: The setting is *only* propogated *down* and not up through parent commands.
     ///
+    /// **NOTE**: Propagation happens when parsing begins, so [`SubCommand`]s added *after* this
+    /// call, as well as their own nested [`SubCommand`]s, inherit the setting as well.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -565,6 +799,107 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Checks if the given [`AppSettings`] is currently enabled for this command, or
+    /// [`SubCommand`].
+    ///
+    /// **NOTE:** Settings enabled with [`App::global_setting`] are reported immediately for the
+    /// command they were set on, but only reach its [`SubCommand`]s once they're propagated at
+    /// parse time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings};
+    /// let app = App::new("myprog")
+    ///     .setting(AppSettings::SubcommandRequired)
+    ///     .global_setting(AppSettings::ColoredHelp);
+    ///
+    /// assert!(app.is_set(AppSettings::SubcommandRequired));
+    /// assert!(app.is_set(AppSettings::ColoredHelp));
+    /// assert!(!app.is_set(AppSettings::WaitOnError));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`AppSettings`]: ./enum.AppSettings.html
+    /// [`App::global_setting`]: ./struct.App.html#method.global_setting
+    pub fn is_set(&self, setting: AppSettings) -> bool {
+        self.p.is_set(setting)
+    }
+
+    /// Requires a [`SubCommand`] to be used, displaying the help message and exiting with a
+    /// non-zero status when none was given. This is a shorthand for setting, or unsetting,
+    /// [`AppSettings::SubcommandRequiredElseHelp`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, SubCommand, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .subcommand_required_else_help(true)
+    ///     .subcommand(SubCommand::with_name("run"))
+    ///     .get_matches_from_safe(vec!["myprog"]);
+    ///
+    /// assert!(res.is_err());
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::MissingArgumentOrSubcommand);
+    /// assert!(err.use_stderr());
+    /// assert!(err.message.contains("USAGE:"));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`AppSettings::SubcommandRequiredElseHelp`]: ./enum.AppSettings.html#variant.SubcommandRequiredElseHelp
+    pub fn subcommand_required_else_help(self, yes: bool) -> Self {
+        if yes {
+            self.setting(AppSettings::SubcommandRequiredElseHelp)
+        } else {
+            self.unset_setting(AppSettings::SubcommandRequiredElseHelp)
+        }
+    }
+
+    /// Sets when help and error messages are colored. This is a shorthand for setting one of
+    /// [`AppSettings::ColorAuto`], [`AppSettings::ColorAlways`] or [`AppSettings::ColorNever`],
+    /// replacing whichever was set before.
+    ///
+    /// With [`ColorChoice::Auto`] (the default) output is only colored when it goes to a
+    /// terminal/TTY, so escape codes never end up in pipes or files. The `NO_COLOR`, `CLICOLOR`
+    /// and `CLICOLOR_FORCE` environment variables are honored as described for
+    /// [`AppSettings::ColorAuto`]. An explicit [`ColorChoice::Always`] or [`ColorChoice::Never`]
+    /// takes precedence over the environment.
+    ///
+    /// **NOTE:** Help messages are only colored when [`AppSettings::ColoredHelp`] is set as well.
+    ///
+    /// **NOTE:** Must be compiled with the `color` cargo feature.
+    ///
+    /// # Platform Specific
+    ///
+    /// This setting only applies to Unix, Linux, and OSX (i.e. non-Windows platforms).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, AppSettings, ColorChoice};
+    /// App::new("myprog")
+    ///     .setting(AppSettings::ColoredHelp)
+    ///     .color(ColorChoice::Always)
+    ///     .get_matches();
+    /// ```
+    /// [`AppSettings::ColorAuto`]: ./enum.AppSettings.html#variant.ColorAuto
+    /// [`AppSettings::ColorAlways`]: ./enum.AppSettings.html#variant.ColorAlways
+    /// [`AppSettings::ColorNever`]: ./enum.AppSettings.html#variant.ColorNever
+    /// [`AppSettings::ColoredHelp`]: ./enum.AppSettings.html#variant.ColoredHelp
+    /// [`ColorChoice::Auto`]: ./enum.ColorChoice.html#variant.Auto
+    /// [`ColorChoice::Always`]: ./enum.ColorChoice.html#variant.Always
+    /// [`ColorChoice::Never`]: ./enum.ColorChoice.html#variant.Never
+    pub fn color(mut self, choice: ColorChoice) -> Self {
+        self.p.unset(AppSettings::ColorAuto);
+        self.p.unset(AppSettings::ColorAlways);
+        self.p.unset(AppSettings::ColorNever);
+        self.p.set(match choice {
+            ColorChoice::Auto => AppSettings::ColorAuto,
+            ColorChoice::Always => AppSettings::ColorAlways,
+            ColorChoice::Never => AppSettings::ColorNever,
+        });
+        self
+    }
+
     /// Sets the terminal width at which to wrap help messages. Defaults to `120`. Using `0` will
     /// ignore terminal widths and use source formatting.
     ///
@@ -572,6 +907,10 @@ impl<'a, 'b> App<'a, 'b> {
     /// if the `wrap_help` cargo "feature" has been used while compiling. If the terminal width
     /// cannot be determined, `clap` defaults to `120`.
     ///
+    /// The help of arguments is aligned in a column after the longest argument, and wrapped with
+    /// a hanging indent. That column never goes past half of the terminal width, any argument too
+    /// wide for it displays its help on the next line instead.
+    ///
     /// **NOTE:** This setting applies globally and *not* on a per-command basis.
     ///
     /// **NOTE:** This setting must be set **before** any subcommands are added!
@@ -649,7 +988,10 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
-    /// Adds multiple [arguments] to the list of valid possibilties
+    /// Adds multiple [arguments] to the list of valid possibilties by iterating over an
+    /// [`IntoIterator`] of [`Arg`]s (or references to them). This is the same as calling
+    /// [`App::arg`] for each of them in turn, so the order they're declared in is kept, such as
+    /// for [`AppSettings::DeriveDisplayOrder`].
     ///
     /// # Examples
     ///
@@ -662,10 +1004,31 @@ impl<'a, 'b> App<'a, 'b> {
     ///     )
     /// # ;
     /// ```
+    ///
+    /// Args returned by a function can be added directly
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// fn common_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    ///     vec![Arg::with_name("verbose").short("v"),
+    ///          Arg::with_name("config").long("config").takes_value(true)]
+    /// }
+    ///
+    /// App::new("myprog")
+    ///     .args(common_args())
+    /// # ;
+    /// ```
     /// [arguments]: ./struct.Arg.html
-    pub fn args(mut self, args: &[Arg<'a, 'b>]) -> Self {
+    /// [`Arg`]: ./struct.Arg.html
+    /// [`App::arg`]: ./struct.App.html#method.arg
+    /// [`AppSettings::DeriveDisplayOrder`]: ./enum.AppSettings.html#variant.DeriveDisplayOrder
+    /// [`IntoIterator`]: https://doc.rust-lang.org/std/iter/trait.IntoIterator.html
+    pub fn args<I, A>(mut self, args: I) -> Self
+        where I: IntoIterator<Item = A>,
+              A: Borrow<Arg<'a, 'b>>
+    {
         for arg in args {
-            self.p.add_arg(arg);
+            self.p.add_arg(arg.borrow());
         }
         self
     }
@@ -725,6 +1088,70 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Displays every flag and option added after this call under the given heading in the help
+    /// message, instead of under `FLAGS:` or `OPTIONS:`. Calling it again starts a new heading for
+    /// the arguments which follow, and arguments added before the first call keep their default
+    /// sections.
+    ///
+    /// This is the same as calling [`Arg::help_heading`] on each of those arguments, and an
+    /// argument which sets its own heading with [`Arg::help_heading`] keeps it.
+    ///
+    /// **NOTE:** This setting is ignored for [positional arguments].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v")
+    ///         .help("Use verbose output"))
+    ///     .help_heading("NETWORK OPTIONS")
+    ///     .arg(Arg::with_name("host")
+    ///         .long("host")
+    ///         .takes_value(true)
+    ///         .help("The host to connect to"))
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .help("The port to connect to"))
+    ///     .help_heading("OUTPUT OPTIONS")
+    ///     .arg(Arg::with_name("out")
+    ///         .long("out")
+    ///         .takes_value(true)
+    ///         .help("Where to write the results"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--help"
+    ///     ]);
+    /// ```
+    ///
+    /// The above example displays the following help message
+    ///
+    /// ```notrust
+    /// prog
+    ///
+    /// USAGE:
+    ///     prog [FLAGS] [OPTIONS]
+    ///
+    /// FLAGS:
+    ///     -h, --help       Prints help information
+    ///     -V, --version    Prints version information
+    ///     -v               Use verbose output
+    ///
+    /// NETWORK OPTIONS:
+    ///         --host <host>    The host to connect to
+    ///         --port <port>    The port to connect to
+    ///
+    /// OUTPUT OPTIONS:
+    ///         --out <out>    Where to write the results
+    /// ```
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    pub fn help_heading(mut self, heading: &'b str) -> Self {
+        self.p.current_help_heading = Some(heading);
+        self
+    }
+
     /// Allows adding a [`SubCommand`] alias, which function as "hidden" subcommands that
     /// automatically dispatch as if this subcommand was used. This is more efficient, and easier
     /// than creating multiple hidden subcommands as one only needs to check for the existence of
@@ -925,7 +1352,9 @@ impl<'a, 'b> App<'a, 'b> {
     }
 
     /// Adds multiple subcommands to the list of valid possibilties by iterating over an
-    /// [`IntoIterator`] of [`SubCommand`]s
+    /// [`IntoIterator`] of [`SubCommand`]s. This is the same as calling [`App::subcommand`] for
+    /// each of them in turn, and is handy when subcommands are generated at runtime (such as by a
+    /// plugin system).
     ///
     /// # Examples
     ///
@@ -939,6 +1368,7 @@ impl<'a, 'b> App<'a, 'b> {
     /// # ;
     /// ```
     /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`App::subcommand`]: ./struct.App.html#method.subcommand
     /// [`IntoIterator`]: https://doc.rust-lang.org/std/iter/trait.IntoIterator.html
     pub fn subcommands<I>(mut self, subcmds: I) -> Self
         where I: IntoIterator<Item = App<'a, 'b>>
@@ -1001,7 +1431,94 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
-    /// Prints the full help message to [`io::stdout()`] using a [`BufWriter`]
+    /// Replaces a single token on the command line with several others before any parsing
+    /// happens, which makes it easy to offer a shorthand for a commonly used bundle of
+    /// arguments. Any arguments can be used in the replacement, including options along with
+    /// their values.
+    ///
+    /// The tokens a replacement produces are never replaced again, so a replacement may refer to
+    /// itself, or to other replaced tokens, without looping forever. Nothing after a `--` is
+    /// replaced. Replacements registered on a [`SubCommand`] apply to the arguments following
+    /// that subcommand's name. Registering the same token again overrides the earlier
+    /// replacement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("foo").long("foo"))
+    ///     .arg(Arg::with_name("bar").long("bar"))
+    ///     .arg(Arg::with_name("baz").long("baz"))
+    ///     .replace("--all", &["--foo", "--bar", "--baz"])
+    ///     .get_matches_from(vec![
+    ///         "prog", "--all"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("foo"));
+    /// assert!(m.is_present("bar"));
+    /// assert!(m.is_present("baz"));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn replace(mut self, from: &'b str, to: &'b [&'b str]) -> Self {
+        self.p.add_replacer(from, to);
+        self
+    }
+
+    /// Validates the definition of this `App` and all of its [`SubCommand`]s, and `panic!`s with
+    /// a precise message at the first mistake found. This checks that
+    ///
+    /// * argument names, as well as [`Arg::short`]s and [`Arg::long`]s, are unique
+    /// * arguments named by [`Arg::requires`], [`Arg::conflicts_with`], [`Arg::overrides_with`]
+    ///   and [`Arg::required_unless`] (and their variants) exist as arguments or groups
+    /// * the members of every [`ArgGroup`] exist
+    /// * positional indices start at `1` and have no gaps
+    ///
+    /// This is done automatically for the command being parsed by the `get_matches*` methods in
+    /// debug builds, so calling it is mostly useful in a test, which checks every subcommand
+    /// without having to use each of them.
+    ///
+    /// **NOTE:** Because of this, a mistake which used to be silently ignored, such as an
+    /// [`Arg::requires`] naming an argument which doesn't exist, now `panic!`s in debug builds
+    /// as soon as the `App` is parsed. Release builds don't run these checks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand};
+    /// App::new("myprog")
+    ///     .arg(Arg::with_name("debug").short("d").conflicts_with("quiet"))
+    ///     .arg(Arg::with_name("quiet").short("q"))
+    ///     .subcommand(SubCommand::with_name("test")
+    ///         .arg(Arg::with_name("input")))
+    ///     .debug_assert();
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use clap::{App, Arg};
+    /// // "quiet" was never defined
+    /// App::new("myprog")
+    ///     .arg(Arg::with_name("debug").short("d").conflicts_with("quiet"))
+    ///     .debug_assert();
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`Arg::short`]: ./struct.Arg.html#method.short
+    /// [`Arg::long`]: ./struct.Arg.html#method.long
+    /// [`Arg::requires`]: ./struct.Arg.html#method.requires
+    /// [`Arg::conflicts_with`]: ./struct.Arg.html#method.conflicts_with
+    /// [`Arg::overrides_with`]: ./struct.Arg.html#method.overrides_with
+    /// [`Arg::required_unless`]: ./struct.Arg.html#method.required_unless
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    pub fn debug_assert(&self) {
+        self.p.debug_asserts();
+        for sc in &self.p.subcommands {
+            sc.debug_assert();
+        }
+    }
+
+    /// Prints the full help message to [`io::stdout()`] using a [`BufWriter`]. This is the same
+    /// help message which `--help` displays, but it can be printed at any time (such as from a
+    /// `help` builtin of a REPL) without exiting the process.
     ///
     /// # Examples
     ///
@@ -1019,7 +1536,9 @@ impl<'a, 'b> App<'a, 'b> {
         self.write_help(&mut buf_w)
     }
 
-    /// Writes the full help message to the user to a [`io::Write`] object
+    /// Writes the full help message to the user to a [`io::Write`] object. This is the same help
+    /// message which `--help` displays, including the help and version flags, even if the `App`
+    /// hasn't parsed any arguments yet.
     ///
     /// # Examples
     ///
@@ -1030,9 +1549,57 @@ impl<'a, 'b> App<'a, 'b> {
     /// let mut out = io::stdout();
     /// app.write_help(&mut out).expect("failed to write to stdout");
     /// ```
+    ///
+    /// Help can also be captured in a buffer
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("myprog")
+    ///     .arg(Arg::with_name("input")
+    ///         .help("the input file"));
+    /// let mut buf = Vec::new();
+    /// app.write_help(&mut buf).expect("failed to write to buffer");
+    ///
+    /// let help = String::from_utf8(buf).unwrap();
+    /// assert!(help.contains("the input file"));
+    /// ```
     /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
     pub fn write_help<W: Write>(&self, w: &mut W) -> ClapResult<()> {
-        Help::write_app_help(w, self)
+        // The help and version flags may not have been built yet, nor the display order derived,
+        // so a copy is used in order to display the same help as --help would
+        let mut app = self.clone();
+        app.p.propogate_settings();
+        app.p.derive_display_order();
+        app.p.create_help_and_version();
+        Help::write_app_help(w, &app)
+    }
+
+    /// Writes the long help message (as displayed by `--help`) to a [`io::Write`] object. This is
+    /// the same as [`App::write_help`] except that [`Arg::long_help`] is used where it was set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("myprog")
+    ///     .arg(Arg::with_name("input")
+    ///         .help("the input file")
+    ///         .long_help("the input file, or '-' to read from stdin"));
+    /// let mut buf = Vec::new();
+    /// app.write_long_help(&mut buf).expect("failed to write to buffer");
+    ///
+    /// let help = String::from_utf8(buf).unwrap();
+    /// assert!(help.contains("or '-' to read from stdin"));
+    /// ```
+    /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`App::write_help`]: ./struct.App.html#method.write_help
+    /// [`Arg::long_help`]: ./struct.Arg.html#method.long_help
+    pub fn write_long_help<W: Write>(&self, w: &mut W) -> ClapResult<()> {
+        let mut app = self.clone();
+        app.p.propogate_settings();
+        app.p.derive_display_order();
+        app.p.create_help_and_version();
+        app.p.write_long_help(w)
     }
 
     /// Writes the version message to the user to a [`io::Write`] object
@@ -1048,7 +1615,70 @@ impl<'a, 'b> App<'a, 'b> {
     /// ```
     /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
     pub fn write_version<W: Write>(&self, w: &mut W) -> ClapResult<()> {
-        self.p.write_version(w).map_err(From::from)
+        self.p.write_version(w, false).map_err(From::from)
+    }
+
+    /// Writes the long version message (as displayed by `--version`) to the user to a
+    /// [`io::Write`] object. If no [`App::long_version`] was set this is the same as
+    /// [`App::write_version`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let app = App::new("myprog")
+    ///     .version("1.0")
+    ///     .long_version("1.0 (commit 4a6f2bd)");
+    /// let mut buf = Vec::new();
+    /// app.write_long_version(&mut buf).expect("failed to write to buffer");
+    ///
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "myprog 1.0 (commit 4a6f2bd)");
+    /// ```
+    /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`App::long_version`]: ./struct.App.html#method.long_version
+    /// [`App::write_version`]: ./struct.App.html#method.write_version
+    pub fn write_long_version<W: Write>(&self, w: &mut W) -> ClapResult<()> {
+        self.p.write_version(w, true).map_err(From::from)
+    }
+
+    /// Prints the version message (as displayed by `-V`) to [`io::stdout()`], without exiting
+    /// the process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let app = App::new("myprog")
+    ///     .version("1.0");
+    /// app.print_version().expect("failed to write to stdout");
+    /// ```
+    /// [`io::stdout()`]: https://doc.rust-lang.org/std/io/fn.stdout.html
+    pub fn print_version(&self) -> ClapResult<()> {
+        let out = io::stdout();
+        let mut buf_w = BufWriter::new(out.lock());
+        self.p.print_version(&mut buf_w, false)
+    }
+
+    /// Prints the long version message (as displayed by `--version`) to [`io::stdout()`],
+    /// without exiting the process. If no [`App::long_version`] was set this is the same as
+    /// [`App::print_version`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let app = App::new("myprog")
+    ///     .version("1.0")
+    ///     .long_version("1.0 (commit 4a6f2bd)");
+    /// app.print_long_version().expect("failed to write to stdout");
+    /// ```
+    /// [`io::stdout()`]: https://doc.rust-lang.org/std/io/fn.stdout.html
+    /// [`App::long_version`]: ./struct.App.html#method.long_version
+    /// [`App::print_version`]: ./struct.App.html#method.print_version
+    pub fn print_long_version(&self) -> ClapResult<()> {
+        let out = io::stdout();
+        let mut buf_w = BufWriter::new(out.lock());
+        self.p.print_version(&mut buf_w, true)
     }
 
 
@@ -1180,6 +1810,53 @@ impl<'a, 'b> App<'a, 'b> {
         self.p.gen_completions_to(for_shell, buf);
     }
 
+    /// Creates a ready made `completions <shell>` [`SubCommand`], so that users can ask the
+    /// program itself for a completion script. The `shell` argument only accepts the names of
+    /// the supported shells (see [`Shell::variants`]), so an unknown shell is reported like any
+    /// other invalid value, along with the list of valid ones.
+    ///
+    /// Once the subcommand is matched, the shell can be retrieved as a [`Shell`] with
+    /// [`value_t!`] and given to [`App::gen_completions_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[macro_use]
+    /// # extern crate clap;
+    /// # use clap::{App, Arg, Shell};
+    /// # use std::io;
+    /// # fn main() {
+    /// fn build_cli() -> App<'static, 'static> {
+    ///     App::new("myapp")
+    ///         .arg(Arg::with_name("verbose")
+    ///             .short("v"))
+    ///         .subcommand(App::completion_subcommand())
+    /// }
+    ///
+    /// let m = build_cli().get_matches_from(vec![
+    ///         "myapp", "completions", "bash"
+    ///     ]);
+    ///
+    /// if let Some(sub_m) = m.subcommand_matches("completions") {
+    ///     let shell = value_t!(sub_m, "shell", Shell).unwrap_or_else(|e| e.exit());
+    ///     build_cli().gen_completions_to("myapp", shell, &mut io::stdout());
+    /// }
+    /// # }
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`Shell`]: ./enum.Shell.html
+    /// [`Shell::variants`]: ./enum.Shell.html#method.variants
+    /// [`value_t!`]: ./macro.value_t!.html
+    /// [`App::gen_completions_to`]: ./struct.App.html#method.gen_completions_to
+    pub fn completion_subcommand() -> App<'a, 'b> {
+        App::new("completions")
+            .about("Generates a completion script for the given shell")
+            .arg(Arg::with_name("shell")
+                .help("The shell to generate the completion script for")
+                .required(true)
+                .possible_values(&Shell::variants()))
+    }
+
     /// Starts the parsing process, upon a failed parse an error will be displayed to the user and
     /// the process will exit with the appropriate error code. By default this method gets all user
     /// provided arguments from [`env::args_os`] in order to allow for invalid UTF-8 code points,
@@ -1330,6 +2007,62 @@ impl<'a, 'b> App<'a, 'b> {
         self.get_matches_from_safe_borrow(itr)
     }
 
+    /// Starts the parsing process from [`env::args_os`], and returns the outcome without ever
+    /// printing anything or exiting the process. This makes it suitable for programs which must
+    /// stay in control, such as long running servers, or when testing an `App`.
+    ///
+    /// Requests for help or version information are returned as an [`Err`] as well, with the
+    /// [`kind`] being [`ErrorKind::HelpDisplayed`] or [`ErrorKind::VersionDisplayed`], and the
+    /// rendered text as the [`Error::message`]. Calling [`Error::exit`] prints it, and exits,
+    /// just as [`App::get_matches`] would.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// let res = App::new("myprog")
+    ///     // Args and options go here...
+    ///     .try_get_matches();
+    /// ```
+    /// [`env::args_os`]: https://doc.rust-lang.org/std/env/fn.args_os.html
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    /// [`kind`]: ./struct.Error.html
+    /// [`ErrorKind::HelpDisplayed`]: ./enum.ErrorKind.html#variant.HelpDisplayed
+    /// [`ErrorKind::VersionDisplayed`]: ./enum.ErrorKind.html#variant.VersionDisplayed
+    /// [`Error::message`]: ./struct.Error.html#structfield.message
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
+    /// [`App::get_matches`]: ./struct.App.html#method.get_matches
+    pub fn try_get_matches(self) -> ClapResult<ArgMatches<'a>> {
+        self.get_matches_from_safe(&mut env::args_os())
+    }
+
+    /// Starts the parsing process from the given arguments, and returns the outcome without ever
+    /// printing anything or exiting the process. See [`App::try_get_matches`] for details.
+    ///
+    /// **NOTE:** The first argument will be parsed as the binary name unless
+    /// [`AppSettings::NoBinaryName`] is used
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind};
+    /// let err = App::new("myprog")
+    ///     .version("1.0")
+    ///     .try_get_matches_from(vec!["myprog", "--version"])
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.kind, ErrorKind::VersionDisplayed);
+    /// assert_eq!(err.message, "myprog 1.0");
+    /// ```
+    /// [`App::try_get_matches`]: ./struct.App.html#method.try_get_matches
+    /// [`AppSettings::NoBinaryName`]: ./enum.AppSettings.html#variant.NoBinaryName
+    pub fn try_get_matches_from<I, T>(self, itr: I) -> ClapResult<ArgMatches<'a>>
+        where I: IntoIterator<Item = T>,
+              T: Into<OsString> + Clone
+    {
+        self.get_matches_from_safe(itr)
+    }
+
     /// Starts the parsing process without consuming the [`App`] struct `self`. This is normally not
     /// the desired functionality, instead prefer [`App::get_matches_from_safe`] which *does*
     /// consume `self`.
@@ -1385,14 +2118,80 @@ impl<'a, 'b> App<'a, 'b> {
             }
         }
 
+        let mut it = it.peekable();
+        let wants_completion_values = it.peek().map_or(false, |a| {
+            let a: OsString = a.clone().into();
+            a.to_str() == Some(COMPLETION_VALUES_FLAG)
+        });
+        if wants_completion_values && self.p.has_completion_hooks() {
+            it.next();
+            let args = it.map(Into::into).collect::<Vec<OsString>>();
+            return Err(self.p.completion_values(&*args));
+        }
+
         // do the real parsing
-        if let Err(e) = self.p.get_matches_with(&mut matcher, &mut it.peekable()) {
+        let res = if self.p.replacers.is_empty() && !self.p.is_set(AppSettings::ResponseFiles) {
+            self.p.get_matches_with(&mut matcher, &mut it)
+        } else {
+            let mut args = it.map(Into::into).collect::<Vec<OsString>>();
+            if self.p.is_set(AppSettings::ResponseFiles) {
+                args = try!(self.p.expand_response_files(args));
+            }
+            let args = self.p.replace_args(args.into_iter());
+            self.p.get_matches_with(&mut matcher, &mut args.into_iter().peekable())
+        };
+        if let Err(e) = res {
             return Err(e);
         }
 
+        // make the values of global args visible in every subcommand below where they were used
+        let global_arg_names = self.p.global_args().map(|a| a.name).collect::<Vec<_>>();
+        matcher.propagate_globals(&*global_arg_names);
+
         Ok(matcher.into())
     }
 
+    /// Starts the parsing process without consuming the [`App`] struct `self`, and without
+    /// leaving any parsing state behind, so the same [`App`] can parse any number of independent
+    /// argument lists. This is useful for REPLs and other interactive programs which parse every
+    /// line they read with one definition.
+    ///
+    /// Like [`App::get_matches_from_safe`] this method will not exit on errors, or when help or
+    /// version information is requested.
+    ///
+    /// **NOTE:** Each call parses a fresh copy of the definition, since parsing keeps track of
+    /// things such as which requirements and conflicts are in effect.
+    ///
+    /// **NOTE:** The first argument will be parsed as the binary name unless
+    /// [`AppSettings::NoBinaryName`] is used
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings};
+    /// let mut app = App::new("repl")
+    ///     .setting(AppSettings::NoBinaryName)
+    ///     .arg(Arg::with_name("cmd").required(true))
+    ///     .arg(Arg::with_name("verbose").short("v"));
+    ///
+    /// let m = app.get_matches_from_mut(vec!["-v", "load"]).unwrap();
+    /// assert!(m.is_present("verbose"));
+    ///
+    /// let m = app.get_matches_from_mut(vec!["save"]).unwrap();
+    /// assert!(!m.is_present("verbose"));
+    /// assert_eq!(m.value_of("cmd"), Some("save"));
+    /// ```
+    /// [`App`]: ./struct.App.html
+    /// [`App::get_matches_from_safe`]: ./struct.App.html#method.get_matches_from_safe
+    /// [`AppSettings::NoBinaryName`]: ./enum.AppSettings.html#variant.NoBinaryName
+    pub fn get_matches_from_mut<I, T>(&mut self, itr: I) -> ClapResult<ArgMatches<'a>>
+        where I: IntoIterator<Item = T>,
+              T: Into<OsString> + Clone
+    {
+        let mut app = self.clone();
+        app.get_matches_from_safe_borrow(itr)
+    }
+
     // Re-implements ClapError::exit except it checks if we should wait for input before exiting
     // since ClapError doesn't have that info and the error message must be printed before exiting
     fn maybe_wait_for_exit(&self, e: Error) -> ! {
@@ -1404,13 +2203,44 @@ impl<'a, 'b> App<'a, 'b> {
                 let i = io::stdin();
                 i.lock().read_line(&mut s).unwrap();
             }
-            process::exit(1);
+            process::exit(e.exit_code());
         }
 
         e.exit()
     }
 }
 
+// Every key which is understood at the App level of a YAML file
+#[cfg(feature = "yaml")]
+const YAML_APP_KEYS: &'static [&'static str] = &["name",
+                                                 "version",
+                                                 "long_version",
+                                                 "author",
+                                                 "bin_name",
+                                                 "about",
+                                                 "before_help",
+                                                 "after_help",
+                                                 "template",
+                                                 "help_template",
+                                                 "usage",
+                                                 "override_usage",
+                                                 "help",
+                                                 "override_help",
+                                                 "help_short",
+                                                 "version_short",
+                                                 "alias",
+                                                 "visible_alias",
+                                                 "aliases",
+                                                 "visible_aliases",
+                                                 "display_order",
+                                                 "setting",
+                                                 "settings",
+                                                 "global_setting",
+                                                 "global_settings",
+                                                 "args",
+                                                 "subcommands",
+                                                 "groups"];
+
 #[cfg(feature = "yaml")]
 impl<'a> From<&'a Yaml> for App<'a, 'a> {
     fn from(mut yaml: &'a Yaml) -> Self {
@@ -1427,6 +2257,17 @@ impl<'a> From<&'a Yaml> for App<'a, 'a> {
         };
         yaml = if let Some(sc) = is_sc { sc } else { yaml };
 
+        if let Some(h) = yaml.as_hash() {
+            for k in h.keys() {
+                let k = k.as_str().expect("failed to convert YAML key to a string");
+                if !YAML_APP_KEYS.contains(&k) {
+                    panic!("Unknown App setting '{}' in YAML file for App '{}'",
+                           k,
+                           a.p.meta.name);
+                }
+            }
+        }
+
         macro_rules! yaml_str {
             ($a:ident, $y:ident, $i:ident) => {
                 if let Some(v) = $y[stringify!($i)].as_str() {
@@ -1438,13 +2279,18 @@ impl<'a> From<&'a Yaml> for App<'a, 'a> {
         }
 
         yaml_str!(a, yaml, version);
+        yaml_str!(a, yaml, long_version);
+        yaml_str!(a, yaml, author);
         yaml_str!(a, yaml, bin_name);
         yaml_str!(a, yaml, about);
         yaml_str!(a, yaml, before_help);
         yaml_str!(a, yaml, after_help);
         yaml_str!(a, yaml, template);
+        yaml_str!(a, yaml, help_template);
         yaml_str!(a, yaml, usage);
+        yaml_str!(a, yaml, override_usage);
         yaml_str!(a, yaml, help);
+        yaml_str!(a, yaml, override_help);
         yaml_str!(a, yaml, help_short);
         yaml_str!(a, yaml, version_short);
         yaml_str!(a, yaml, alias);
@@ -1586,6 +2432,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for App<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         None
     }
+    fn value_parser(&self) -> Option<&ValueParser> {
+        None
+    }
     fn min_vals(&self) -> Option<u64> {
         None
     }
@@ -1604,7 +2453,10 @@ impl<'n, 'e> AnyArg<'n, 'e> for App<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.p.meta.about
     }
-    fn default_val(&self) -> Option<&'n str> {
+    fn long_help(&self) -> Option<&'e str> {
+        None
+    }
+    fn default_val(&self) -> Option<&'n OsStr> {
         None
     }
     fn longest_filter(&self) -> bool {
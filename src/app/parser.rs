@@ -1,9 +1,10 @@
 // Std
 use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::env;
 use std::ffi::{OsStr, OsString};
 use std::fmt::Display;
 use std::fs::File;
-use std::io::{self, BufWriter, Write};
+use std::io::{self, BufWriter, Read, Write};
 #[cfg(feature = "debug")]
 use std::os::unix::ffi::OsStrExt;
 use std::path::PathBuf;
@@ -23,16 +24,19 @@ use app::meta::AppMeta;
 use app::settings::{AppFlags, AppSettings};
 use args::{AnyArg, ArgMatcher};
 use args::{Arg, ArgGroup, FlagBuilder, OptBuilder, PosBuilder};
-use args::MatchedArg;
+use args::{MatchedArg, ValueSource};
 use args::settings::ArgSettings;
 use completions::ComplGen;
 use errors::{Error, ErrorKind};
 use errors::Result as ClapResult;
-use fmt::{Format, ColorWhen};
-use osstringext::OsStrExt2;
+use fmt::{Colorizer, ColorWhen};
+use osstringext::{self, OsStrExt2};
 use completions::Shell;
 use suggestions;
 
+// The deepest response files may reference one another, see `AppSettings::ResponseFiles`
+const MAX_RESPONSE_FILE_DEPTH: usize = 10;
+
 #[allow(missing_debug_implementations)]
 #[doc(hidden)]
 pub struct Parser<'a, 'b>
@@ -48,6 +52,12 @@ pub struct Parser<'a, 'b>
     pub opts: Vec<OptBuilder<'a, 'b>>,
     // A list of positional arguments
     pub positionals: VecMap<PosBuilder<'a, 'b>>,
+    // Every argument as it was defined, in the order they were added
+    pub args: Vec<Arg<'a, 'b>>,
+    // The help headings used by flags and options, in the order they were first used
+    pub help_headings: Vec<&'b str>,
+    // The heading given to flags and options which don't set their own, see App::help_heading
+    pub current_help_heading: Option<&'b str>,
     // A list of subcommands
     #[doc(hidden)]
     pub subcommands: Vec<App<'a, 'b>>,
@@ -59,6 +69,8 @@ pub struct Parser<'a, 'b>
     settings: AppFlags,
     pub g_settings: Vec<AppSettings>,
     pub meta: AppMeta<'b>,
+    // Tokens which are expanded into several others before parsing
+    pub replacers: Vec<(&'b str, &'b [&'b str])>,
     trailing_vals: bool,
 }
 
@@ -68,6 +80,9 @@ impl<'a, 'b> Default for Parser<'a, 'b> {
             flags: vec![],
             opts: vec![],
             positionals: VecMap::new(),
+            args: vec![],
+            help_headings: vec![],
+            current_help_heading: None,
             subcommands: vec![],
             help_short: None,
             version_short: None,
@@ -81,6 +96,7 @@ impl<'a, 'b> Default for Parser<'a, 'b> {
             g_settings: vec![],
             settings: AppFlags::new(),
             meta: AppMeta::new(),
+            replacers: vec![],
             trailing_vals: false,
         }
     }
@@ -105,6 +121,85 @@ impl<'a, 'b> Parser<'a, 'b>
             .nth(0);
     }
 
+    pub fn add_replacer(&mut self, from: &'b str, to: &'b [&'b str]) {
+        self.replacers.retain(|&(f, _)| f != from);
+        self.replacers.push((from, to));
+    }
+
+    // Expands every token which has a replacement registered with `App::replace`. Each token is
+    // only expanded once, and the tokens it expands to are never expanded again, so
+    // self-referential replacements can't loop forever. Nothing after a `--` is expanded.
+    pub fn replace_args<I>(&self, it: I) -> Vec<OsString>
+        where I: Iterator<Item = OsString>
+    {
+        debugln!("fn=replace_args;");
+        let mut args = vec![];
+        let mut trailing = false;
+        for arg in it {
+            if !trailing {
+                if arg.to_str() == Some("--") {
+                    trailing = true;
+                } else if let Some(&(_, to)) = self.replacers
+                    .iter()
+                    .find(|&&(from, _)| arg.to_str() == Some(from)) {
+                    debugln!("Replacing {:?} with {:?}", arg, to);
+                    args.extend(to.iter().map(OsString::from));
+                    continue;
+                }
+            }
+            args.push(arg);
+        }
+        args
+    }
+
+    // Inserts the contents of every `@file` argument in its place, see
+    // `AppSettings::ResponseFiles`
+    pub fn expand_response_files(&self, args: Vec<OsString>) -> ClapResult<Vec<OsString>> {
+        debugln!("fn=expand_response_files;");
+        let mut expanded = vec![];
+        let mut trailing = false;
+        try!(self.expand_response_files_into(args, &mut expanded, &mut trailing, 0));
+        Ok(expanded)
+    }
+
+    fn expand_response_files_into(&self,
+                                  args: Vec<OsString>,
+                                  expanded: &mut Vec<OsString>,
+                                  trailing: &mut bool,
+                                  depth: usize)
+                                  -> ClapResult<()> {
+        for arg in args {
+            if *trailing || arg.len_() < 2 || !arg.starts_with(b"@") {
+                if arg.to_str() == Some("--") {
+                    *trailing = true;
+                }
+                expanded.push(arg);
+                continue;
+            }
+            let path = arg.split_at(1).1;
+            let path_str = path.to_string_lossy().into_owned();
+            debugln!("Reading arguments from {:?}", path);
+            if depth >= MAX_RESPONSE_FILE_DEPTH {
+                return Err(Error::response_file(path_str,
+                                                format!("response files are nested more than \
+                                                         {} levels deep",
+                                                        MAX_RESPONSE_FILE_DEPTH),
+                                                self.color()));
+            }
+            let mut contents = String::new();
+            if let Err(e) = File::open(path).and_then(|mut f| f.read_to_string(&mut contents)) {
+                return Err(Error::response_file(path_str, e, self.color()));
+            }
+            let file_args = contents.lines()
+                .filter(|l| !l.trim_left().starts_with('#'))
+                .flat_map(|l| l.split_whitespace())
+                .map(OsString::from)
+                .collect::<Vec<_>>();
+            try!(self.expand_response_files_into(file_args, expanded, trailing, depth + 1));
+        }
+        Ok(())
+    }
+
     pub fn gen_completions_to<W: Write>(&mut self, for_shell: Shell, buf: &mut W) {
 
         self.propogate_help_version();
@@ -132,12 +227,68 @@ impl<'a, 'b> Parser<'a, 'b>
         self.gen_completions_to(for_shell, &mut file)
     }
 
+    // Whether any argument of this app or its subcommands has an `Arg::completion` hook
+    pub fn has_completion_hooks(&self) -> bool {
+        self.opts.iter().any(|o| o.completion_fn.is_some()) ||
+        self.positionals.values().any(|p| p.completion_fn.is_some()) ||
+        self.subcommands.iter().any(|sc| sc.p.has_completion_hooks())
+    }
+
+    // Runs the completion hook of an argument for a completion script. The last of `args` is the
+    // name of the argument, and any before it are the path of subcommands which define it.
+    pub fn completion_values(&self, args: &[OsString]) -> Error {
+        debugln!("fn=completion_values;args={:?}", args);
+        let mut p = self;
+        let (name, path) = match args.split_last() {
+            Some((name, path)) => (name.to_string_lossy().into_owned(), path),
+            None => return Error::argument_not_found_auto(""),
+        };
+        for sc_name in path {
+            let sc_name = sc_name.to_string_lossy();
+            p = match p.subcommands.iter().find(|sc| sc.p.meta.name == sc_name ||
+                                                   sc.p.meta.aliases.as_ref().map_or(false, |als| {
+                                                       als.iter().any(|&(a, _)| a == sc_name)
+                                                   })) {
+                Some(sc) => &sc.p,
+                None => {
+                    return Error::unrecognized_subcommand(sc_name.to_string(),
+                                                          self.meta
+                                                              .bin_name
+                                                              .as_ref()
+                                                              .unwrap_or(&self.meta.name),
+                                                          self.color())
+                }
+            };
+        }
+        let hook = p.opts
+            .iter()
+            .find(|o| o.name == name)
+            .and_then(|o| o.completion_fn.as_ref())
+            .or_else(|| {
+                p.positionals
+                    .values()
+                    .find(|pos| pos.name == name)
+                    .and_then(|pos| pos.completion_fn.as_ref())
+            });
+        match hook {
+            Some(f) => {
+                Error {
+                    message: f().join("\n"),
+                    kind: ErrorKind::CompletionValuesDisplayed,
+                    info: None,
+                }
+            }
+            None => Error::argument_not_found_auto(name),
+        }
+    }
+
     // actually adds the arguments
     pub fn add_arg(&mut self, a: &Arg<'a, 'b>) {
         debug_assert!(!(self.flags.iter().any(|f| &f.name == &a.name) ||
                         self.opts.iter().any(|o| o.name == a.name) ||
                         self.positionals.values().any(|p| p.name == a.name)),
                       format!("Non-unique argument name: {} is already in use", a.name));
+        let heading = a.help_heading.or(self.current_help_heading);
         if let Some(ref grps) = a.group {
             for g in grps {
                 let ag = self.groups.entry(g).or_insert_with(|| ArgGroup::with_name(g));
@@ -170,19 +321,26 @@ impl<'a, 'b> Parser<'a, 'b>
             } else {
                 a.index.unwrap() as usize
             };
+            // Positionals without an explicit index are numbered in the order they're declared
             debug_assert!(!self.positionals.contains_key(i),
-                          format!("Argument \"{}\" has the same index as another positional \
-                    argument\n\n\tPerhaps try .multiple(true) to allow one positional argument \
-                    to take multiple values",
-                                  a.name));
+                          "Positional index {} defined twice, by \"{}\" and \"{}\"\n\n\tPerhaps \
+                          try .multiple(true) to allow one positional argument to take multiple \
+                          values",
+                          i,
+                          self.positionals.get(i).map_or("", |p| p.name),
+                          a.name);
             let pb = PosBuilder::from_arg(a, i as u64, &mut self.required);
             self.positionals.insert(i, pb);
         } else if a.is_set(ArgSettings::TakesValue) {
+            self.add_help_heading(heading);
             let mut ob = OptBuilder::from_arg(a, &mut self.required);
+            ob.help_heading = heading;
             ob.unified_ord = self.flags.len() + self.opts.len();
             self.opts.push(ob);
         } else {
+            self.add_help_heading(heading);
             let mut fb = FlagBuilder::from(a);
+            fb.help_heading = heading;
             fb.unified_ord = self.flags.len() + self.opts.len();
             self.flags.push(fb);
         }
@@ -191,7 +349,20 @@ impl<'a, 'b> Parser<'a, 'b>
                           format!("Global arguments cannot be required.\n\n\t'{}' is marked as \
                           global and required",
                                   a.name));
-            self.global_args.push(a.into());
+            let mut ga: Arg<'a, 'b> = a.into();
+            ga.help_heading = heading;
+            self.global_args.push(ga);
+        }
+        let mut arg: Arg<'a, 'b> = a.into();
+        arg.help_heading = heading;
+        self.args.push(arg);
+    }
+
+    fn add_help_heading(&mut self, heading: Option<&'b str>) {
+        if let Some(h) = heading {
+            if !self.help_headings.contains(&h) {
+                self.help_headings.push(h);
+            }
         }
     }
 
@@ -211,6 +382,8 @@ impl<'a, 'b> Parser<'a, 'b>
             grp.requires = group.requires.clone();
             grp.conflicts = group.conflicts.clone();
             grp.required = group.required;
+            grp.multiple = group.multiple;
+            grp.count = group.count;
             found = true;
         }
         if !found {
@@ -409,7 +582,9 @@ impl<'a, 'b> Parser<'a, 'b>
 
     pub fn get_args_tag(&self) -> Option<String> {
         let mut count = 0;
-        'outer: for p in self.positionals.values().filter(|p| !p.is_set(ArgSettings::Required)) {
+        'outer: for p in self.positionals
+            .values()
+            .filter(|p| !p.is_set(ArgSettings::Required) && !p.is_set(ArgSettings::Last)) {
             if let Some(g_vec) = self.groups_for_arg(p.name) {
                 for grp_s in &g_vec {
                     debugln!("iter;grp_s={};", grp_s);
@@ -430,7 +605,8 @@ impl<'a, 'b> Parser<'a, 'b>
                 count += 1;
             }
         }
-        if count > 1 || self.positionals.len() > 1 {
+        let num_pos = self.positionals.values().filter(|p| !p.is_set(ArgSettings::Last)).count();
+        if count > 1 || num_pos > 1 {
             return None;
         } else if count == 1 {
             let p = self.positionals.values().next().expect(INTERNAL_ERROR_MSG);
@@ -515,25 +691,40 @@ impl<'a, 'b> Parser<'a, 'b>
         // positional arguments to verify there are no gaps (i.e. supplying an index of 1 and 3
         // but no 2)
         if let Some((idx, p)) = self.positionals.iter().rev().next() {
+            let gap = (1..idx).find(|i| !self.positionals.contains_key(*i)).unwrap_or(0);
             debug_assert!(!(idx != self.positionals.len()),
-                    format!("Found positional argument \"{}\" who's index is {} but there are \
-                    only {} positional arguments defined",
-                            p.name,
-                            idx,
-                            self.positionals.len()));
-        }
-
-        // Next we verify that only the highest index has a .multiple(true) (if any)
+                          "Positional index {} is not defined, but \"{}\" has index {} (there are \
+                          only {} positional arguments defined)",
+                          gap,
+                          p.name,
+                          idx,
+                          self.positionals.len());
+        }
+
+        // Only the positional with the highest index may be marked as `last`
+        debug_assert!(self.positionals
+                          .values()
+                          .all(|p| !p.is_set(ArgSettings::Last) ||
+                                   p.index as usize == self.positionals.len()),
+                      "Only the positional argument with the highest index may use .last(true)");
+
+        // Next we verify that there is at most one variadic positional, since values can't be
+        // distributed between two of them unambiguously
+        let variadic: Vec<_> = self.positionals()
+            .filter(|p| p.settings.is_set(ArgSettings::Multiple) && p.num_vals.is_none())
+            .map(|p| p.name)
+            .collect();
+        debug_assert!(variadic.len() <= 1,
+                      "Only one positional argument with .multiple(true) set is allowed per \
+                      command, but found {:?}",
+                      variadic);
+
+        // Then that only the highest index has a .multiple(true) (if any)
         if self.positionals()
             .any(|a| {
                 a.settings.is_set(ArgSettings::Multiple) &&
                 (a.index as usize != self.positionals.len())
             }) {
-            debug_assert!(self.positionals()
-                .filter(|p| p.settings.is_set(ArgSettings::Multiple)
-                    && p.num_vals.is_none()).map(|_| 1).sum::<u64>() <= 1,
-                "Only one positional argument with .multiple(true) set is allowed per command");
-
             debug_assert!(self.positionals()
                 .rev()
                 .next()
@@ -552,19 +743,15 @@ impl<'a, 'b> Parser<'a, 'b>
             self.set(AppSettings::LowIndexMultiplePositional);
         }
 
-        debug_assert!(self.positionals()
-            .filter(|p| p.settings.is_set(ArgSettings::Multiple)
-                && p.num_vals.is_none())
-            .map(|_| 1)
-            .sum::<u64>() <= 1,
-            "Only one positional argument with .multiple(true) set is allowed per command");
-
         // If it's required we also need to ensure all previous positionals are
         // required too
+        // (AllowMissingPositional only relaxes this for the second to last positional)
+        let missing_pos = self.is_set(AppSettings::AllowMissingPositional);
         let mut found = false;
         for p in self.positionals.values().rev() {
             if found {
-                debug_assert!(p.settings.is_set(ArgSettings::Required),
+                debug_assert!(p.settings.is_set(ArgSettings::Required) ||
+                              (missing_pos && p.index as usize + 1 == self.positionals.len()),
                               "Found positional argument which is not required with a lower index \
                               than a required positional argument: {:?} index {}",
                               p.name,
@@ -576,13 +763,139 @@ impl<'a, 'b> Parser<'a, 'b>
         }
     }
 
+    // Validates the entire definition of this command (but not its subcommands), panicking with
+    // a precise message at the first mistake found
+    pub fn debug_asserts(&self) {
+        debugln!("fn=debug_asserts;");
+        let mut names: Vec<&str> = vec![];
+        let mut shorts: Vec<char> = vec![];
+        let mut longs: Vec<&str> = vec![];
+        macro_rules! check_unique {
+            ($a:ident) => {
+                assert!(!names.contains(&$a.name()),
+                        "Non-unique argument name: {} is already in use",
+                        $a.name());
+                names.push($a.name());
+                if let Some(s) = $a.short() {
+                    assert!(!shorts.contains(&s),
+                            "Argument short must be unique\n\n\t-{} is already in use (by \"{}\")",
+                            s,
+                            $a.name());
+                    shorts.push(s);
+                }
+                if let Some(l) = $a.long() {
+                    assert!(!longs.contains(&l),
+                            "Argument long must be unique\n\n\t--{} is already in use (by \"{}\")",
+                            l,
+                            $a.name());
+                    longs.push(l);
+                }
+            };
+        }
+        // A custom short for the generated help or version flag mustn't be taken by another arg
+        for &(short, used, flag, method) in
+            &[(self.help_short, self.uses_help_flags(), "hclap_help", "help_short"),
+              (self.version_short, self.uses_version_flags(), "vclap_version", "version_short")] {
+            if let (Some(s), true) = (short, used) {
+                let user = self.flags
+                    .iter()
+                    .filter(|f| f.name != flag)
+                    .map(|f| (f.name, f.short))
+                    .chain(self.opts.iter().map(|o| (o.name, o.short)))
+                    .find(|&(_, short)| short == Some(s));
+                if let Some((name, _)) = user {
+                    panic!("Argument short -{} of \"{}\" is also used by App::{}",
+                           s,
+                           name,
+                           method);
+                }
+            }
+        }
+        for f in &self.flags {
+            check_unique!(f);
+        }
+        for o in &self.opts {
+            check_unique!(o);
+        }
+        for p in self.positionals.values() {
+            check_unique!(p);
+        }
+
+        let exists = |n: &str| names.contains(&n) || self.groups.contains_key(n);
+        macro_rules! check_refs {
+            ($a:ident, $($kind:expr => $refs:expr),+) => {
+                $(
+                    for r in $refs.unwrap_or(&[]) {
+                        assert!(exists(r),
+                                "Argument \"{}\" {} \"{}\" which isn't a valid argument or group",
+                                $a.name(),
+                                $kind,
+                                r);
+                    }
+                )+
+            };
+        }
+        macro_rules! check_arg_refs {
+            ($a:ident) => {
+                check_refs!($a,
+                            "requires" => $a.requires(),
+                            "conflicts with" => $a.blacklist(),
+                            "overrides" => $a.overrides(),
+                            "is required unless" => $a.required_unless());
+            };
+        }
+        for f in &self.flags {
+            check_arg_refs!(f);
+        }
+        for o in &self.opts {
+            check_arg_refs!(o);
+            let r_ifs: Vec<_> = o.r_ifs.iter().flat_map(|v| v.iter().map(|&(_, n)| n)).collect();
+            check_refs!(o, "requires" => Some(&r_ifs[..]));
+        }
+        for p in self.positionals.values() {
+            check_arg_refs!(p);
+            let r_ifs: Vec<_> = p.r_ifs.iter().flat_map(|v| v.iter().map(|&(_, n)| n)).collect();
+            check_refs!(p, "requires" => Some(&r_ifs[..]));
+        }
+
+        for g in self.groups.values() {
+            for a in &g.args {
+                assert!(names.contains(a),
+                        "Argument group \"{}\" contains \"{}\" which isn't a valid argument",
+                        g.name,
+                        a);
+            }
+            for r in g.requires.iter().chain(g.conflicts.iter()).flat_map(|v| v.iter()) {
+                assert!(exists(r),
+                        "Argument group \"{}\" refers to \"{}\" which isn't a valid argument or \
+                        group",
+                        g.name,
+                        r);
+            }
+        }
+
+        for (i, p) in self.positionals.iter().enumerate() {
+            assert!(p.0 == i + 1,
+                    "Positional index {} is not defined, but \"{}\" has index {}",
+                    i + 1,
+                    p.1.name,
+                    p.0);
+        }
+    }
+
     pub fn propogate_globals(&mut self) {
         for sc in &mut self.subcommands {
             // We have to create a new scope in order to tell rustc the borrow of `sc` is
             // done and to recursively call this method
             {
                 for a in &self.global_args {
-                    sc.p.add_arg(a);
+                    // Inherited args are displayed apart from the subcommand's own ones, unless
+                    // they were given a heading of their own
+                    let mut ga = a.clone();
+                    if ga.help_heading.is_none() {
+                        ga.help_heading = Some("GLOBAL OPTIONS");
+                    }
+                    sc.p.add_arg(&ga);
                 }
             }
             sc.p.propogate_globals();
@@ -608,6 +921,53 @@ impl<'a, 'b> Parser<'a, 'b>
             })
     }
 
+    // With InferSubcommands, resolves a unique prefix of a subcommand name (or alias) to that
+    // subcommand's name. Exact matches are left to possible_subcommand, and a prefix shared by
+    // more than one subcommand is an error.
+    fn infer_subcommand(&self,
+                        arg_os: &OsStr,
+                        matcher: &ArgMatcher<'a>)
+                        -> ClapResult<Option<String>> {
+        debugln!("fn=infer_subcommand;");
+        if !self.is_set(AppSettings::InferSubcommands) || arg_os.is_empty_() ||
+           arg_os.starts_with(b"-") || self.possible_subcommand(arg_os) {
+            return Ok(None);
+        }
+        let arg = arg_os.to_string_lossy();
+        let candidates = self.subcommands
+            .iter()
+            .filter(|s| {
+                s.p.meta.name.starts_with(&*arg) ||
+                s.p
+                    .meta
+                    .aliases
+                    .as_ref()
+                    .map_or(false, |als| als.iter().any(|&(a, _)| a.starts_with(&*arg)))
+            })
+            .map(|s| &*s.p.meta.name)
+            .collect::<Vec<_>>();
+        match candidates.len() {
+            0 => Ok(None),
+            1 => Ok(Some(candidates[0].to_owned())),
+            _ => {
+                Err(Error::ambiguous_subcommand(&*arg,
+                                                &*candidates,
+                                                &*self.create_current_usage(matcher),
+                                                self.color()))
+            }
+        }
+    }
+
+    // Is the positional argument at `pos_counter` variadic, and has it already been given at
+    // least one value?
+    fn is_collecting_pos_vals(&self, pos_counter: usize, matcher: &ArgMatcher<'a>) -> bool {
+        debugln!("fn=is_collecting_pos_vals;");
+        if let Some(p) = self.positionals.get(pos_counter) {
+            return p.settings.is_set(ArgSettings::Multiple) && matcher.contains(p.name);
+        }
+        false
+    }
+
     #[inline]
     fn get_opt(&self, arg: &str) -> Option<&OptBuilder<'a, 'b>> {
         debugln!("fn=get_opt");
@@ -630,6 +990,14 @@ impl<'a, 'b> Parser<'a, 'b>
     {
         debugln!("fn=parse_help_subcommand;");
         let cmds: Vec<OsString> = it.map(|c| c.into()).collect();
+        // cmd help topic <name>
+        if self.meta.help_topics.is_some() && cmds.first().map_or(false, |c| c == "topic") &&
+           !self.subcommands.iter().any(|s| &*s.p.meta.name == "topic") {
+            if let Some(t) = cmds.get(1) {
+                return self._help_topic(t);
+            }
+            return self._help(true);
+        }
         let mut help_help = false;
         let mut bin_name = self.meta
             .bin_name
@@ -694,7 +1062,7 @@ impl<'a, 'b> Parser<'a, 'b>
         if sc.meta.bin_name != self.meta.bin_name {
             sc.meta.bin_name = Some(format!("{} {}", bin_name, sc.meta.name));
         }
-        sc._help()
+        sc._help(true)
     }
 
     // The actual parsing function
@@ -714,6 +1082,22 @@ impl<'a, 'b> Parser<'a, 'b>
         // necessary
         self.create_help_and_version();
 
+        if cfg!(debug_assertions) {
+            self.debug_asserts();
+        }
+
+        // A bare invocation only displays the help message, this is checked before parsing so
+        // default values can't make it look like arguments were used
+        if self.is_set(AppSettings::ArgRequiredElseHelp) && it.peek().is_none() {
+            let mut out = vec![];
+            try!(self.write_help_err(&mut out));
+            return Err(Error {
+                message: String::from_utf8_lossy(&*out).into_owned(),
+                kind: ErrorKind::MissingArgumentOrSubcommand,
+                info: None,
+            });
+        }
+
         let mut subcmd_name: Option<String> = None;
         let mut needs_val_of: Option<&str> = None;
         let mut pos_counter = 1;
@@ -727,14 +1111,33 @@ impl<'a, 'b> Parser<'a, 'b>
             // Has the user already passed '--'? Meaning only positional args follow
             if !self.trailing_vals {
                 // Does the arg match a subcommand name, or any of it's aliases (if defined)
-                let pos_sc = self.possible_subcommand(&arg_os);
+                let inferred_sc = if needs_val_of.is_none() {
+                    try!(self.infer_subcommand(&arg_os, matcher))
+                } else {
+                    None
+                };
+                let is_sc = inferred_sc.is_some() || self.possible_subcommand(&arg_os);
+                // A variadic positional which has already started collecting values keeps
+                // consuming them, unless subcommands were explicitly given precedence
+                let pos_sc = is_sc &&
+                             (self.is_set(AppSettings::SubcommandPrecedenceOverArg) ||
+                              !self.is_collecting_pos_vals(pos_counter, matcher));
+
+                // AllowNegativeNumbers only lets through values which are actually numbers, any
+                // other leading hyphen is still considered a new argument
+                let neg_num = self.is_set(AppSettings::AllowNegativeNumbers) &&
+                              (arg_os.to_string_lossy().parse::<i64>().is_ok() ||
+                               arg_os.to_string_lossy().parse::<f64>().is_ok());
+
+                // An option which allows hyphen values takes the next value no matter what it
+                // starts with
+                let hyphen_val = needs_val_of.and_then(|n| self.get_opt(n))
+                    .map_or(false, |o| o.is_set(ArgSettings::AllowHyphenValues));
 
                 // If the arg doesn't start with a `-` (except numbers, or AllowLeadingHyphen) and
                 // isn't a subcommand
-                if (!starts_new_arg ||
-                    (self.is_set(AppSettings::AllowLeadingHyphen) ||
-                     self.is_set(AppSettings::AllowNegativeNumbers))) &&
-                   !pos_sc {
+                if (!starts_new_arg || self.is_set(AppSettings::AllowLeadingHyphen) || neg_num ||
+                    hyphen_val) && !pos_sc {
                     // Check to see if parsing a value from an option
                     if let Some(arg) = needs_val_of {
                         // get the OptBuilder so we can check the settings
@@ -758,51 +1161,42 @@ impl<'a, 'b> Parser<'a, 'b>
                         continue;
                     }
                 } else if arg_os.starts_with(b"-") && arg_os.len_() != 1 {
-                    // Try to parse short args like normal, if AllowLeadingHyphen or
-                    // AllowNegativeNumbers is set, parse_short_arg will *not* throw
-                    // an error, and instead return Ok(None)
+                    // Try to parse short args like normal, if AllowLeadingHyphen is set, or
+                    // AllowNegativeNumbers is set and the arg is a number, parse_short_arg will
+                    // *not* throw an error, and instead return Ok(None)
                     needs_val_of = try!(self.parse_short_arg(matcher, &arg_os));
-                    // If it's None, we then check if one of those two AppSettings was set
+                    // If it's None, we then check if one of those two AppSettings applies
                     debugln!("AllowLeadingHyphen set...{:?}", self.is_set(AppSettings::AllowLeadingHyphen));
-                    debugln!("AllowNegativeNumbers set...{:?}", self.is_set(AppSettings::AllowNegativeNumbers));
-                    debugln!("Valid negative number...{:?}", (arg_os.to_string_lossy().parse::<i64>().is_ok() ||
-                                  arg_os.to_string_lossy().parse::<f64>().is_ok()));
-                    if needs_val_of.is_none() {
-                        if self.is_set(AppSettings::AllowNegativeNumbers) {
-                            if !(arg_os.to_string_lossy().parse::<i64>().is_ok() ||
-                                 arg_os.to_string_lossy().parse::<f64>().is_ok()) {
-                                return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
-                                                            "",
-                                                            &*self.create_current_usage(matcher),
-                                                            self.color()));
-                            }
-                        } else if !self.is_set(AppSettings::AllowLeadingHyphen) {
-                            continue;
-                        }
-                    } else {
+                    debugln!("Valid negative number...{:?}", neg_num);
+                    if needs_val_of.is_some() ||
+                       !(neg_num || self.is_set(AppSettings::AllowLeadingHyphen)) {
                         continue;
                     }
                 }
 
                 if pos_sc {
-                    if &*arg_os == "help" && self.is_set(AppSettings::NeedsSubcommandHelp) {
+                    let sc_name = inferred_sc.unwrap_or_else(|| {
+                        arg_os.to_str().expect(INVALID_UTF8).to_owned()
+                    });
+                    if sc_name == "help" && self.is_set(AppSettings::NeedsSubcommandHelp) {
                         try!(self.parse_help_subcommand(it));
                     }
-                    subcmd_name = Some(arg_os.to_str().expect(INVALID_UTF8).to_owned());
+                    subcmd_name = Some(sc_name);
                     break;
-                } else if let Some(cdate) =
-                              suggestions::did_you_mean(&*arg_os.to_string_lossy(),
-                                                        self.subcommands
-                                                            .iter()
-                                                            .map(|s| &s.p.meta.name)) {
-                    return Err(Error::invalid_subcommand(arg_os.to_string_lossy().into_owned(),
-                                                         cdate,
-                                                         self.meta
-                                                             .bin_name
-                                                             .as_ref()
-                                                             .unwrap_or(&self.meta.name),
-                                                         &*self.create_current_usage(matcher),
-                                                         self.color()));
+                } else if !is_sc {
+                    let sc_names = self.sc_suggestion_names();
+                    if let Some(cdate) = suggestions::did_you_mean(&*arg_os.to_string_lossy(),
+                                                                   sc_names.iter()) {
+                        return Err(Error::invalid_subcommand(arg_os.to_string_lossy()
+                                                                 .into_owned(),
+                                                             cdate,
+                                                             self.meta
+                                                                 .bin_name
+                                                                 .as_ref()
+                                                                 .unwrap_or(&self.meta.name),
+                                                             &*self.create_current_usage(matcher),
+                                                             self.color()));
+                    }
                 }
             }
 
@@ -813,9 +1207,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 if let Some(na) = it.peek() {
                     let n = (*na).clone().into();
                     if is_new_arg(&n) || self.possible_subcommand(&n) || suggestions::did_you_mean(&n.to_string_lossy(),
-                                                        self.subcommands
-                                                            .iter()
-                                                            .map(|s| &s.p.meta.name)).is_some() {
+                                                        self.sc_suggestion_names().iter()).is_some() {
                         debugln!("Bumping the positional counter...");
                         pos_counter += 1;
                     }
@@ -826,7 +1218,30 @@ impl<'a, 'b> Parser<'a, 'b>
             } else {
                 sdebugln!("None");
             }
+            debug!("Checking for a missing positional...");
+            if self.is_set(AppSettings::AllowMissingPositional) &&
+               pos_counter + 1 == self.positionals.len() && it.peek().is_none() &&
+               !self.positionals[pos_counter].is_set(ArgSettings::Required) &&
+               self.positionals[pos_counter + 1].is_set(ArgSettings::Required) {
+                sdebugln!("Found");
+                debugln!("Bumping the positional counter...");
+                pos_counter += 1;
+            } else {
+                sdebugln!("None");
+            }
+            // Everything after a '--' belongs to the positional marked as `last`, if there is one
+            if self.trailing_vals {
+                if let Some(p) = self.positionals.values().find(|p| p.is_set(ArgSettings::Last)) {
+                    pos_counter = p.index as usize;
+                }
+            }
             if let Some(p) = self.positionals.get(pos_counter) {
+                if p.is_set(ArgSettings::Last) && !self.trailing_vals {
+                    return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
+                                                       "",
+                                                       &*self.create_current_usage(matcher),
+                                                       self.color()));
+                }
                 parse_positional!(self, p, arg_os, pos_counter, matcher);
             } else if self.settings.is_set(AppSettings::AllowExternalSubcommands) {
                 // Get external subcommand name
@@ -851,6 +1266,7 @@ impl<'a, 'b> Parser<'a, 'b>
                                                            self.color()));
                         }
                     }
+                    sc_m.add_raw_val_to("", &a);
                     sc_m.add_val_to("", &a);
                 }
 
@@ -911,11 +1327,18 @@ impl<'a, 'b> Parser<'a, 'b>
         try!(self.validate_blacklist(matcher));
         try!(self.validate_num_args(matcher));
         matcher.usage(self.create_usage(&[]));
+        matcher.color(self.color());
 
-        if !(self.settings.is_set(AppSettings::SubcommandsNegateReqs) && subcmd_name.is_some()) &&
-           !reqs_validated {
-            try!(self.validate_required(matcher));
+        if !(self.settings.is_set(AppSettings::SubcommandsNegateReqs) && subcmd_name.is_some()) {
+            try!(self.validate_group_counts(matcher));
+            if !reqs_validated {
+                try!(self.validate_required(matcher));
+            }
+            try!(self.validate_required_ifs(matcher));
         }
+        self.record_satisfied_reqs(matcher);
+        self.record_groups(matcher);
+        self.record_possible_vals(matcher);
         if let Some(pos_sc_name) = subcmd_name {
             // is this is a real subcommand, or an alias
             let sc_name = if self.subcommands.iter().any(|sc| sc.p.meta.name == pos_sc_name) {
@@ -953,16 +1376,6 @@ impl<'a, 'b> Parser<'a, 'b>
                 info: None,
             });
         }
-        if matcher.is_empty() && matcher.subcommand_name().is_none() &&
-           self.is_set(AppSettings::ArgRequiredElseHelp) {
-            let mut out = vec![];
-            try!(self.write_help_err(&mut out));
-            return Err(Error {
-                message: String::from_utf8_lossy(&*out).into_owned(),
-                kind: ErrorKind::MissingArgumentOrSubcommand,
-                info: None,
-            });
-        }
         Ok(())
     }
 
@@ -1049,7 +1462,12 @@ impl<'a, 'b> Parser<'a, 'b>
                                                   ""
                                               },
                                               &*sc.p.meta.name));
-            try!(sc.p.get_matches_with(&mut sc_matcher, it));
+            if sc.p.replacers.is_empty() {
+                try!(sc.p.get_matches_with(&mut sc_matcher, it));
+            } else {
+                let args = sc.p.replace_args(it.map(Into::into));
+                try!(sc.p.get_matches_with(&mut sc_matcher, &mut args.into_iter().peekable()));
+            }
             matcher.subcommand(SubCommand {
                 name: sc.p.meta.name.clone(),
                 matches: sc_matcher.into(),
@@ -1130,7 +1548,12 @@ impl<'a, 'b> Parser<'a, 'b>
     pub fn create_help_and_version(&mut self) {
         debugln!("fn=create_help_and_version;");
         // name is "hclap_help" because flags are sorted by name
-        if self.is_set(AppSettings::NeedsLongHelp) {
+        //
+        // This may be called more than once (i.e. when printing help before parsing) so anything
+        // which was already built is skipped
+        if !self.settings.is_set(AppSettings::DisableHelpFlags) &&
+           self.is_set(AppSettings::NeedsLongHelp) &&
+           !self.flags.iter().any(|f| f.name == "hclap_help") {
             debugln!("Building --help");
             if self.help_short.is_none() && !self.short_list.contains(&'h') {
                 self.help_short = Some('h');
@@ -1149,7 +1572,8 @@ impl<'a, 'b> Parser<'a, 'b>
             self.flags.push(arg);
         }
         if !self.settings.is_set(AppSettings::DisableVersion) &&
-           self.is_set(AppSettings::NeedsLongVersion) {
+           self.is_set(AppSettings::NeedsLongVersion) &&
+           !self.flags.iter().any(|f| f.name == "vclap_version") {
             debugln!("Building --version");
             if self.version_short.is_none() && !self.short_list.contains(&'V') {
                 self.version_short = Some('V');
@@ -1168,7 +1592,8 @@ impl<'a, 'b> Parser<'a, 'b>
             self.long_list.push("version");
             self.flags.push(arg);
         }
-        if !self.subcommands.is_empty() && self.is_set(AppSettings::NeedsSubcommandHelp) {
+        if !self.subcommands.is_empty() && self.is_set(AppSettings::NeedsSubcommandHelp) &&
+           !self.subcommands.iter().any(|s| s.p.meta.name == "help") {
             debugln!("Building help");
             self.subcommands
                 .push(App::new("help")
@@ -1198,16 +1623,29 @@ impl<'a, 'b> Parser<'a, 'b>
             .collect::<Vec<_>>())
     }
 
+    // Whether the generated help flags are in use, i.e. the user didn't define --help themselves
+    fn uses_help_flags(&self) -> bool {
+        self.settings.is_set(AppSettings::NeedsLongHelp) &&
+        !self.settings.is_set(AppSettings::DisableHelpFlags)
+    }
+
+    // Whether the generated version flags are in use, i.e. the user didn't define --version
+    // themselves
+    fn uses_version_flags(&self) -> bool {
+        self.settings.is_set(AppSettings::NeedsLongVersion) &&
+        !self.settings.is_set(AppSettings::DisableVersion)
+    }
+
     fn check_for_help_and_version_str(&self, arg: &OsStr) -> ClapResult<()> {
         debug!("Checking if --{} is help or version...",
                arg.to_str().unwrap());
-        if arg == "help" && self.settings.is_set(AppSettings::NeedsLongHelp) {
+        if arg == "help" && self.uses_help_flags() {
             sdebugln!("Help");
-            try!(self._help());
+            try!(self._help(true));
         }
-        if arg == "version" && self.settings.is_set(AppSettings::NeedsLongVersion) {
+        if arg == "version" && self.uses_version_flags() {
             sdebugln!("Version");
-            try!(self._version());
+            try!(self._version(true));
         }
         sdebugln!("Neither");
 
@@ -1217,24 +1655,52 @@ impl<'a, 'b> Parser<'a, 'b>
     fn check_for_help_and_version_char(&self, arg: char) -> ClapResult<()> {
         debug!("Checking if -{} is help or version...", arg);
         if let Some(h) = self.help_short {
-            if arg == h && self.settings.is_set(AppSettings::NeedsLongHelp) {
+            if arg == h && self.uses_help_flags() {
                 sdebugln!("Help");
-                try!(self._help());
+                try!(self._help(false));
             }
         }
         if let Some(v) = self.version_short {
-            if arg == v && self.settings.is_set(AppSettings::NeedsLongVersion) {
+            if arg == v && self.uses_version_flags() {
                 sdebugln!("Version");
-                try!(self._version());
+                try!(self._version(false));
             }
         }
         sdebugln!("Neither");
         Ok(())
     }
 
-    fn _help(&self) -> ClapResult<()> {
+    fn check_for_help_topic(&self, arg: &OsStr, topic: &OsStr) -> ClapResult<()> {
+        debugln!("fn=check_for_help_topic;");
+        if arg == "help" && self.uses_help_flags() && self.meta.help_topics.is_some() &&
+           !topic.is_empty_() {
+            try!(self._help_topic(topic));
+        }
+        Ok(())
+    }
+
+    fn _help_topic(&self, topic: &OsStr) -> ClapResult<()> {
+        debugln!("fn=_help_topic; topic={:?}", topic);
+        let topics = self.meta.help_topics.as_ref().map_or(&[][..], |t| &t[..]);
+        if let Some(&(_, text)) = topics.iter().find(|&&(n, _)| n == &*topic) {
+            return Err(Error {
+                message: text.to_owned(),
+                kind: ErrorKind::HelpDisplayed,
+                info: None,
+            });
+        }
+        Err(Error::unknown_help_topic(topic.to_string_lossy(),
+                                      &*topics.iter().map(|&(n, _)| n).collect::<Vec<_>>(),
+                                      self.color()))
+    }
+
+    fn _help(&self, use_long: bool) -> ClapResult<()> {
         let mut buf = vec![];
-        try!(Help::write_parser_help(&mut buf, self));
+        if use_long {
+            try!(Help::write_parser_long_help(&mut buf, self));
+        } else {
+            try!(Help::write_parser_help(&mut buf, self));
+        }
         // let out = io::stdout();
         // let mut out_buf = BufWriter::new(out.lock());
         // try!(out_buf.write(&*buf));
@@ -1245,17 +1711,57 @@ impl<'a, 'b> Parser<'a, 'b>
         })
     }
 
-    fn _version(&self) -> ClapResult<()> {
-        let out = io::stdout();
-        let mut buf_w = BufWriter::new(out.lock());
-        try!(self.print_version(&mut buf_w));
+    fn _version(&self, use_long: bool) -> ClapResult<()> {
+        let mut buf = vec![];
+        try!(self.write_version(&mut buf, use_long));
         Err(Error {
-            message: String::new(),
+            message: String::from_utf8_lossy(&*buf).into_owned(),
             kind: ErrorKind::VersionDisplayed,
             info: None,
         })
     }
 
+    // With InferLongArgs, resolves a unique prefix of a long name (or alias) to the name of the
+    // flag or option it belongs to. Exact matches are left alone, and a prefix shared by more
+    // than one argument is an error.
+    fn infer_long_arg(&self,
+                      arg: &OsStr,
+                      matcher: &ArgMatcher<'a>)
+                      -> ClapResult<Option<&'b str>> {
+        debugln!("fn=infer_long_arg;");
+        if !self.is_set(AppSettings::InferLongArgs) || arg.is_empty_() {
+            return Ok(None);
+        }
+        let arg = arg.to_string_lossy();
+        let longs = self.flags
+            .iter()
+            .map(|f| (f.long, &f.aliases))
+            .chain(self.opts.iter().map(|o| (o.long, &o.aliases)))
+            .map(|(long, aliases)| {
+                long.into_iter()
+                    .chain(aliases.iter().flat_map(|als| als.iter().map(|&(a, _)| a)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        if longs.iter().any(|names| names.iter().any(|n| *n == &*arg)) {
+            return Ok(None);
+        }
+        let candidates = longs.iter()
+            .filter(|names| names.iter().any(|n| n.starts_with(&*arg)))
+            .map(|names| names[0])
+            .collect::<Vec<_>>();
+        match candidates.len() {
+            0 => Ok(None),
+            1 => Ok(Some(candidates[0])),
+            _ => {
+                Err(Error::ambiguous_argument(format!("--{}", arg),
+                                              &*candidates,
+                                              &*self.create_current_usage(matcher),
+                                              self.color()))
+            }
+        }
+    }
+
     fn parse_long_arg(&mut self,
                       matcher: &mut ArgMatcher<'a>,
                       full_arg: &OsStr)
@@ -1265,7 +1771,11 @@ impl<'a, 'b> Parser<'a, 'b>
         let mut val = None;
         debug!("Does it contain '='...");
         let arg = if full_arg.contains_byte(b'=') {
-            let (p0, p1) = full_arg.trim_left_matches(b'-').split_at_byte(b'=');
+            let trimmed = full_arg.trim_left_matches(b'-');
+            let (p0, _) = trimmed.split_at_byte(b'=');
+            // Keep the leading '=' so parse_opt knows the value was attached, and only split
+            // on the first one so values such as KEY=VALUE survive intact
+            let p1 = trimmed.split_at(p0.len_()).1;
             sdebugln!("Yes '{:?}'", p1);
             val = Some(p1);
             p0
@@ -1274,6 +1784,11 @@ impl<'a, 'b> Parser<'a, 'b>
             full_arg.trim_left_matches(b'-')
         };
 
+        let arg = match try!(self.infer_long_arg(arg, matcher)) {
+            Some(long) => OsStr::new(long),
+            None => arg,
+        };
+
         if let Some(opt) = self.opts
             .iter()
             .find(|v| {
@@ -1304,6 +1819,9 @@ impl<'a, 'b> Parser<'a, 'b>
             debugln!("Found valid flag '{}'", flag.to_string());
             // Only flags could be help or version, and we need to check the raw long
             // so this is the first point to check
+            if let Some(topic) = val {
+                try!(self.check_for_help_topic(&arg, topic.split_at(1).1));
+            }
             try!(self.check_for_help_and_version_str(&arg));
 
             try!(self.parse_flag(flag, matcher));
@@ -1376,7 +1894,9 @@ impl<'a, 'b> Parser<'a, 'b>
                 // Must be called here due to mutablilty
                 arg_post_processing!(self, flag, matcher);
             } else if !(self.is_set(AppSettings::AllowLeadingHyphen) ||
-                        self.is_set(AppSettings::AllowNegativeNumbers)) {
+                        (self.is_set(AppSettings::AllowNegativeNumbers) &&
+                         (full_arg.to_string_lossy().parse::<i64>().is_ok() ||
+                          full_arg.to_string_lossy().parse::<f64>().is_ok()))) {
                 let mut arg = String::new();
                 arg.push('-');
                 arg.push(c);
@@ -1395,13 +1915,26 @@ impl<'a, 'b> Parser<'a, 'b>
                  matcher: &mut ArgMatcher<'a>)
                  -> ClapResult<Option<&'a str>> {
         debugln!("fn=parse_opt;");
+        self.remove_self_override(opt, matcher);
         validate_multiples!(self, opt, matcher);
+        try!(self.validate_prev_occurrence(opt, matcher));
         let mut has_eq = false;
+        let require_equals = opt.is_set(ArgSettings::RequireEquals);
+
+        // With RequireEquals only an optional value may be left out entirely
+        if require_equals && !val.map_or(false, |v| v.starts_with(&[b'='])) &&
+           (val.is_some() || opt.min_vals != Some(0)) {
+            sdebugln!("RequireEquals set, but no '=' found - Error");
+            return Err(Error::no_equals(opt,
+                                        &*self.create_current_usage(matcher),
+                                        self.color()));
+        }
 
         debug!("Checking for val...");
         if let Some(fv) = val {
             has_eq = fv.starts_with(&[b'=']);
-            let v = fv.trim_left_matches(b'=');
+            // Only the single '=' joining the option and its value is removed
+            let v = if has_eq { fv.split_at(1).1 } else { fv };
             if !opt.is_set(ArgSettings::EmptyValues) && v.len_() == 0 {
                 sdebugln!("Found Empty - Error");
                 return Err(Error::empty_value(opt,
@@ -1416,10 +1949,14 @@ impl<'a, 'b> Parser<'a, 'b>
         }
 
         matcher.inc_occurrence_of(opt.name);
+        if opt.value_parser.is_some() {
+            // Even when the option was used without a value
+            matcher.init_typed_vals_of(opt.name);
+        }
         // Increment or create the group "args"
         self.groups_for_arg(opt.name).and_then(|vec| Some(matcher.inc_occurrences_of(&*vec)));
 
-        if val.is_none() ||
+        if (val.is_none() && !require_equals) ||
            !has_eq &&
            (opt.is_set(ArgSettings::Multiple) && !opt.is_set(ArgSettings::RequireDelimiter) &&
             matcher.needs_more_vals(opt)) {
@@ -1428,6 +1965,28 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(None)
     }
 
+    // Values are stored flat across all occurrences, so when an option with a fixed number of
+    // values is used again the previous occurrence must be checked before it's lost
+    fn validate_prev_occurrence(&self,
+                                opt: &OptBuilder<'a, 'b>,
+                                matcher: &ArgMatcher<'a>)
+                                -> ClapResult<()> {
+        debugln!("fn=validate_prev_occurrence;");
+        if let (Some(num), Some(ma)) = (opt.num_vals, matcher.get(opt.name)) {
+            let extra = ma.vals.len() % num as usize;
+            if extra != 0 {
+                debugln!("Sending error WrongNumberOfValues");
+                return Err(Error::wrong_number_of_values(opt,
+                                                         num,
+                                                         extra,
+                                                         if extra == 1 { "as" } else { "ere" },
+                                                         &*self.create_current_usage(matcher),
+                                                         self.color()));
+            }
+        }
+        Ok(())
+    }
+
     fn add_val_to_arg<A>(&self,
                          arg: &A,
                          val: &OsStr,
@@ -1436,6 +1995,12 @@ impl<'a, 'b> Parser<'a, 'b>
         where A: AnyArg<'a, 'b> + Display
     {
         debugln!("fn=add_val_to_arg;");
+        matcher.add_raw_val_to(arg.name(), val);
+        if let Some(grps) = self.groups_for_arg(arg.name()) {
+            for grp in grps {
+                matcher.add_raw_val_to(&*grp, val);
+            }
+        }
         let mut ret = None;
         if !(self.trailing_vals && self.is_set(AppSettings::DontDelimitTrailingValues)) {
             if let Some(delim) = arg.val_delim() {
@@ -1468,6 +2033,13 @@ impl<'a, 'b> Parser<'a, 'b>
         where A: AnyArg<'a, 'b> + Display
     {
         debugln!("adding val: {:?}", v);
+        let normalized;
+        let v = if arg.is_set(ArgSettings::NormalizePathSeparators) {
+            normalized = osstringext::normalize_path_separators(v);
+            &*normalized
+        } else {
+            v
+        };
         matcher.add_val_to(arg.name(), v);
 
         // Increment or create the group "args"
@@ -1479,7 +2051,14 @@ impl<'a, 'b> Parser<'a, 'b>
 
         // The validation must come AFTER inserting into 'matcher' or the usage string
         // can't be built
-        self.validate_value(arg, v, matcher)
+        let ret = try!(self.validate_value(arg, v, matcher));
+        if let Some(vp) = arg.value_parser() {
+            match vp(&*v.to_string_lossy()) {
+                Ok(typed) => matcher.add_typed_val_to(arg.name(), typed),
+                Err(e) => return Err(Error::value_validation(e, self.color())),
+            }
+        }
+        Ok(ret)
     }
 
     fn validate_value<A>(&self,
@@ -1518,11 +2097,23 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(None)
     }
 
+    // An argument which overrides itself only keeps its last occurrence, so the previous one is
+    // dropped before parsing the new one
+    fn remove_self_override<A>(&self, arg: &A, matcher: &mut ArgMatcher<'a>)
+        where A: AnyArg<'a, 'b>
+    {
+        if arg.overrides().map_or(false, |o| o.contains(&arg.name())) {
+            debugln!("fn=remove_self_override;arg={}", arg.name());
+            matcher.remove(arg.name());
+        }
+    }
+
     fn parse_flag(&self,
                   flag: &FlagBuilder<'a, 'b>,
                   matcher: &mut ArgMatcher<'a>)
                   -> ClapResult<()> {
         debugln!("fn=parse_flag;");
+        self.remove_self_override(flag, matcher);
         validate_multiples!(self, flag, matcher);
 
         matcher.inc_occurrence_of(flag.name);
@@ -1709,16 +2300,25 @@ impl<'a, 'b> Parser<'a, 'b>
             }
             let err =
                 if self.settings.is_set(AppSettings::ArgRequiredElseHelp) && matcher.is_empty() {
-                    self._help().unwrap_err()
+                    self._help(false).unwrap_err()
                 } else {
                     let mut reqs = self.required.iter().map(|&r| &*r).collect::<Vec<_>>();
                     reqs.retain(|n| !matcher.contains(n));
                     reqs.dedup();
+                    let unless = self.required_unless_notes(&*reqs);
+                    let c = Colorizer {
+                        use_stderr: true,
+                        when: self.color(),
+                    };
                     Error::missing_required_argument(
                 &*self.get_required_from(&*reqs, Some(matcher))
                       .iter()
-                      .fold(String::new(),
-                          |acc, s| acc + &format!("\n    {}", Format::Error(s))[..]),
+                      .fold(String::new(), |acc, s| {
+                          let note = unless.iter()
+                              .find(|&&(ref a, _)| a == s)
+                              .map_or(String::new(), |&(_, ref n)| format!(" {}", n));
+                          acc + &format!("\n    {}{}", c.error(s), note)[..]
+                      }),
                 &*self.create_current_usage(matcher),
                 self.color())
                 };
@@ -1727,6 +2327,129 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(())
     }
 
+    fn validate_group_counts(&self, matcher: &ArgMatcher<'a>) -> ClapResult<()> {
+        debugln!("fn=validate_group_counts;");
+        for grp in self.groups.values() {
+            if let Some((min, max)) = grp.count {
+                let used = grp.args
+                    .iter()
+                    .filter(|n| matcher.contains(n))
+                    .map(|n| self.find_any_arg(n).map_or(n.to_string(), |a| a.to_string()))
+                    .collect::<Vec<_>>();
+                debugln!("group={}; min={}; max={}; used={:?}", grp.name, min, max, used);
+                if used.len() < min || used.len() > max {
+                    let usage = self.create_current_usage(matcher);
+                    return Err(Error::wrong_number_of_group_args(grp.name,
+                                                                 min,
+                                                                 max,
+                                                                 &*used,
+                                                                 &*usage,
+                                                                 self.color()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_required_ifs(&self, matcher: &ArgMatcher<'a>) -> ClapResult<()> {
+        debugln!("fn=validate_required_ifs;");
+        macro_rules! check_r_ifs {
+            ($a:ident) => {
+                if let (Some(r_ifs), Some(ma)) = ($a.r_ifs.as_ref(), matcher.get($a.name)) {
+                    for &(val, name) in r_ifs.iter() {
+                        if !matcher.contains(name) && ma.vals.values().any(|v| v == val) {
+                            let req = self.find_flag(name)
+                                .map(|f| f.to_string())
+                                .or_else(|| self.find_option(name).map(|o| o.to_string()))
+                                .or_else(|| self.find_positional(name).map(|p| p.to_string()))
+                                .unwrap_or_else(|| name.to_owned());
+                            let usage = self.create_current_usage(matcher);
+                            return Err(Error::missing_required_if(req,
+                                                                  $a,
+                                                                  val,
+                                                                  &*usage,
+                                                                  self.color()));
+                        }
+                    }
+                }
+            };
+        }
+        for o in &self.opts {
+            check_r_ifs!(o);
+        }
+        for p in self.positionals.values() {
+            check_r_ifs!(p);
+        }
+        Ok(())
+    }
+
+    // Records every `requires` relationship which was fulfilled at runtime, so that it can later
+    // be queried from the ArgMatches
+    fn record_satisfied_reqs(&self, matcher: &mut ArgMatcher<'a>) {
+        debugln!("fn=record_satisfied_reqs;");
+        let mut satisfied = vec![];
+        for (name, _) in matcher.iter() {
+            if let Some(reqs) = self.find_any_arg(name).and_then(|a| a.requires()) {
+                for r in reqs.iter().filter(|r| matcher.contains(r)) {
+                    debugln!("'{}' requires '{}'...satisfied", name, r);
+                    satisfied.push((name.to_string(), r.to_string()));
+                }
+            }
+        }
+        satisfied.sort();
+        matcher.0.reqs_satisfied = satisfied;
+    }
+
+    // Carries the members of each group into the ArgMatches, so that the member which was used
+    // can later be queried by the group's name
+    fn record_groups(&self, matcher: &mut ArgMatcher<'a>) {
+        debugln!("fn=record_groups;");
+        for (name, grp) in &self.groups {
+            matcher.0.groups.insert(*name, grp.args.clone());
+        }
+    }
+
+    // Carries the declared possible values of each arg into the ArgMatches, so that they can be
+    // queried after parsing without declaring them again
+    fn record_possible_vals(&self, matcher: &mut ArgMatcher<'a>) {
+        debugln!("fn=record_possible_vals;");
+        for o in &self.opts {
+            if let Some(ref p_vals) = o.possible_vals {
+                matcher.0
+                    .possible_vals
+                    .insert(o.name, p_vals.iter().map(|v| v.to_string()).collect());
+            }
+        }
+        for p in self.positionals.values() {
+            if let Some(ref p_vals) = p.possible_vals {
+                matcher.0
+                    .possible_vals
+                    .insert(p.name, p_vals.iter().map(|v| v.to_string()).collect());
+            }
+        }
+    }
+
+    // Builds a note for each of the given args which are only required unless some alternatives
+    // are used, so that the missing required argument error can list those alternatives
+    fn required_unless_notes(&self, reqs: &[&str]) -> Vec<(String, String)> {
+        let mut notes = vec![];
+        for a in reqs.iter().filter_map(|r| self.find_any_arg(r)) {
+            if let Some(ru) = a.required_unless() {
+                let alts = ru.iter()
+                    .map(|n| self.find_any_arg(n).map_or(n.to_string(), |alt| alt.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let which = if a.is_set(ArgSettings::RequiredUnlessAll) {
+                    "all"
+                } else {
+                    "one"
+                };
+                notes.push((a.to_string(), format!("(unless {} of: {})", which, alts)));
+            }
+        }
+        notes
+    }
+
     fn is_missing_required_ok<A>(&self, a: &A, matcher: &ArgMatcher) -> bool
         where A: AnyArg<'a, 'b>
     {
@@ -1739,7 +2462,8 @@ impl<'a, 'b> Parser<'a, 'b>
                     return true;
                 }
             }
-        } else if let Some(ru) = a.required_unless() {
+        }
+        if let Some(ru) = a.required_unless() {
             let mut found_any = false;
             for n in ru.iter() {
                 if matcher.contains(n) ||
@@ -1759,24 +2483,55 @@ impl<'a, 'b> Parser<'a, 'b>
         false
     }
 
+    // Subcommand names and their visible aliases, which are suggested for mistyped subcommands
+    fn sc_suggestion_names(&self) -> Vec<&str> {
+        let mut names = vec![];
+        for sc in &self.subcommands {
+            names.push(&*sc.p.meta.name);
+            if let Some(ref als) = sc.p.meta.aliases {
+                names.extend(als.iter().filter(|&&(_, vis)| vis).map(|&(n, _)| n));
+            }
+        }
+        names
+    }
+
+    // Long flags and their visible aliases, which are suggested for mistyped long arguments
+    fn long_suggestion_names(&self) -> Vec<&'b str> {
+        let mut names = self.long_list.clone();
+        let aliases = self.flags
+            .iter()
+            .filter_map(|f| f.aliases.as_ref())
+            .chain(self.opts.iter().filter_map(|o| o.aliases.as_ref()));
+        for als in aliases {
+            names.extend(als.iter().filter(|&&(_, vis)| vis).map(|&(n, _)| n));
+        }
+        names
+    }
+
     fn did_you_mean_error(&self, arg: &str, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
         // Didn't match a flag or option...maybe it was a typo and close to one
+        let longs = self.long_suggestion_names();
         let suffix =
             suggestions::did_you_mean_suffix(arg,
-                                             self.long_list.iter(),
-                                             suggestions::DidYouMeanMessageStyle::LongFlag);
+                                             longs.iter(),
+                                             suggestions::DidYouMeanMessageStyle::LongFlag,
+                                             self.color());
+        let is_long_of = |long: Option<&str>, aliases: Option<&Vec<(&str, bool)>>, name: &str| {
+            long == Some(name) ||
+            aliases.map_or(false, |als| als.iter().any(|&(a, _)| a == name))
+        };
 
         // Add the arg to the matches to build a proper usage string
         if let Some(name) = suffix.1 {
             if let Some(opt) = self.opts
                 .iter()
-                .find(|o| o.long.is_some() && o.long.unwrap() == name) {
+                .find(|o| is_long_of(o.long, o.aliases.as_ref(), name)) {
                 self.groups_for_arg(&*opt.name)
                     .and_then(|grps| Some(matcher.inc_occurrences_of(&*grps)));
                 matcher.insert(&*opt.name);
             } else if let Some(flg) = self.flags
                 .iter()
-                .find(|f| f.long.is_some() && f.long.unwrap() == name) {
+                .find(|f| is_long_of(f.long, f.aliases.as_ref(), name)) {
                 self.groups_for_arg(&*flg.name)
                     .and_then(|grps| Some(matcher.inc_occurrences_of(&*grps)));
                 matcher.insert(&*flg.name);
@@ -1846,13 +2601,21 @@ impl<'a, 'b> Parser<'a, 'b>
                 usage.push_str(" [--]")
             }
             if self.has_positionals() &&
-               self.positionals.values().any(|a| !a.settings.is_set(ArgSettings::Required)) {
+               self.positionals.values().any(|a| {
+                !a.settings.is_set(ArgSettings::Required) && !a.settings.is_set(ArgSettings::Last)
+            }) {
                 if let Some(args_tag) = self.get_args_tag() {
                     usage.push_str(&*args_tag);
                 } else {
                     usage.push_str(" [ARGS]");
                 }
             }
+            // (a required `last` positional is already part of the required args)
+            if let Some(p) = self.positionals
+                .values()
+                .find(|p| p.is_set(ArgSettings::Last) && !p.is_set(ArgSettings::Required)) {
+                usage.push_str(&*format!(" [-- {}]", p));
+            }
 
 
             if self.has_subcommands() && !self.is_set(AppSettings::SubcommandRequired) {
@@ -1894,30 +2657,26 @@ impl<'a, 'b> Parser<'a, 'b>
     }
 
     // Prints the version to the user and exits if quit=true
-    fn print_version<W: Write>(&self, w: &mut W) -> ClapResult<()> {
-        try!(self.write_version(w));
+    pub fn print_version<W: Write>(&self, w: &mut W, use_long: bool) -> ClapResult<()> {
+        try!(self.write_version(w, use_long));
         w.flush().map_err(Error::from)
     }
 
-    pub fn write_version<W: Write>(&self, w: &mut W) -> io::Result<()> {
+    pub fn write_version<W: Write>(&self, w: &mut W, use_long: bool) -> io::Result<()> {
+        let ver = if use_long {
+            self.meta.long_version.or(self.meta.version).unwrap_or("")
+        } else {
+            self.meta.version.unwrap_or("")
+        };
         if let Some(bn) = self.meta.bin_name.as_ref() {
             if bn.contains(' ') {
                 // Incase we're dealing with subcommands i.e. git mv is translated to git-mv
-                write!(w,
-                         "{} {}",
-                         bn.replace(" ", "-"),
-                         self.meta.version.unwrap_or("".into()))
+                write!(w, "{} {}", bn.replace(" ", "-"), ver)
             } else {
-                write!(w,
-                         "{} {}",
-                         &self.meta.name[..],
-                         self.meta.version.unwrap_or("".into()))
+                write!(w, "{} {}", &self.meta.name[..], ver)
             }
         } else {
-            write!(w,
-                     "{} {}",
-                     &self.meta.name[..],
-                     self.meta.version.unwrap_or("".into()))
+            write!(w, "{} {}", &self.meta.name[..], ver)
         }
     }
 
@@ -1931,26 +2690,140 @@ impl<'a, 'b> Parser<'a, 'b>
         Help::write_parser_help(w, self)
     }
 
+    pub fn write_long_help<W: Write>(&self, w: &mut W) -> ClapResult<()> {
+        Help::write_parser_long_help(w, self)
+    }
+
     pub fn write_help_err<W: Write>(&self, w: &mut W) -> ClapResult<()> {
         Help::write_parser_help_to_stderr(w, self)
     }
 
+    // Values which weren't supplied by the user (such as defaults) are checked up front, so that
+    // a bad value is reported along with where it actually came from
+    fn validate_injected_val<A>(&self, arg: &A, val: &OsStr, source: &str) -> ClapResult<()>
+        where A: AnyArg<'a, 'b> + Display
+    {
+        debugln!("fn=validate_injected_val; val={:?}, source={}", val, source);
+        let vals: Vec<&OsStr> = match arg.val_delim() {
+            Some(delim) if !val.is_empty_() => val.split(delim as u32 as u8).collect(),
+            _ => vec![val],
+        };
+        for v in vals {
+            let v_str = v.to_string_lossy();
+            if let Some(p_vals) = arg.possible_vals() {
+                if !p_vals.contains(&&*v_str) {
+                    return Err(Error::invalid_injected_value(&*v_str,
+                                                             source,
+                                                             arg,
+                                                             format!("[values: {}]",
+                                                                     p_vals.join(", ")),
+                                                             ErrorKind::InvalidValue,
+                                                             self.color()));
+                }
+            }
+            if let Some(vtor) = arg.validator() {
+                if let Err(e) = vtor(v_str.clone().into_owned()) {
+                    return Err(Error::invalid_injected_value(&*v_str,
+                                                             source,
+                                                             arg,
+                                                             e,
+                                                             ErrorKind::ValueValidation,
+                                                             self.color()));
+                }
+            }
+            if let Some(vp) = arg.value_parser() {
+                if let Err(e) = vp(&*v_str) {
+                    return Err(Error::invalid_injected_value(&*v_str,
+                                                             source,
+                                                             arg,
+                                                             e,
+                                                             ErrorKind::ValueValidation,
+                                                             self.color()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Values from the environment take precedence over conditional defaults, which take precedence
+    // over default values, but none of them is used when the user supplied a value
     fn add_defaults(&mut self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
         macro_rules! add_val {
-            ($_self:ident, $a:ident, $m:ident) => {
+            ($_self:ident, $a:ident, $m:ident, $c:ident) => {
                 if $m.get($a.name).is_none() {
-                    try!($_self.add_val_to_arg($a, OsStr::new($a.default_val
-                                                                .as_ref()
-                                                                .unwrap()), $m));
-                    arg_post_processing!($_self, $a, $m);
+                    let env_val = $a.env
+                        .and_then(|n| env::var_os(n).map(|v| (n, v)))
+                        .and_then(|(n, v)| if v.is_empty() { None } else { Some((n, v)) });
+                    if let Some((name, ref val)) = env_val {
+                        debugln!("Using env var {:?} for '{}'", name, $a.name);
+                        let source = format!("env {}", name.to_string_lossy());
+                        try!($_self.validate_injected_val($a, val, &*source));
+                        try!($_self.add_val_to_arg($a, val, $m));
+                        $m.set_source($a.name, ValueSource::EnvVariable);
+                        arg_post_processing!($_self, $a, $m);
+                    } else if let Some(&(_, val)) = $c.iter().find(|&&(n, _)| n == $a.name) {
+                        try!($_self.validate_injected_val($a, val, "default value"));
+                        try!($_self.add_val_to_arg($a, val, $m));
+                        $m.set_source($a.name, ValueSource::DefaultValue);
+                        arg_post_processing!($_self, $a, $m);
+                    } else if let Some(ref f) = $a.default_fn {
+                        let val = f();
+                        try!($_self.validate_injected_val($a, &*val, "default value"));
+                        try!($_self.add_val_to_arg($a, &*val, $m));
+                        $m.set_source($a.name, ValueSource::DefaultValue);
+                        arg_post_processing!($_self, $a, $m);
+                    } else if let Some(val) = $a.default_val {
+                        try!($_self.validate_injected_val($a, val, "default value"));
+                        try!($_self.add_val_to_arg($a, val, $m));
+                        $m.set_source($a.name, ValueSource::DefaultValue);
+                        arg_post_processing!($_self, $a, $m);
+                    }
                 }
             };
         }
-        for o in self.opts.iter().filter(|o| o.default_val.is_some()) {
-            add_val!(self, o, matcher);
+        // Options which were used without a value get their default missing value
+        for o in self.opts.iter().filter(|o| o.default_missing_val.is_some()) {
+            if matcher.get(o.name).map_or(false, |ma| ma.vals.is_empty()) {
+                let val = o.default_missing_val.unwrap();
+                debugln!("Using default missing value for '{}'", o.name);
+                try!(self.validate_injected_val(o, val, "default missing value"));
+                try!(self.add_val_to_arg(o, val, matcher));
+            }
         }
-        for p in self.positionals.values().filter(|p| p.default_val.is_some()) {
-            add_val!(self, p, matcher);
+        // Conditional defaults are resolved up front, so that they only depend on the args which
+        // were used and not on the defaults added below
+        let mut cond_vals = vec![];
+        for (name, ifs) in self.opts
+            .iter()
+            .filter_map(|o| o.default_vals_ifs.as_ref().map(|ifs| (o.name, ifs)))
+            .chain(self.positionals
+                .values()
+                .filter_map(|p| p.default_vals_ifs.as_ref().map(|ifs| (p.name, ifs)))) {
+            let cond = ifs.iter().find(|&&(arg, val, _)| {
+                matcher.get(arg).map_or(false, |ma| {
+                    val.map_or(true, |v| ma.vals.values().any(|mv| mv.as_os_str() == v))
+                })
+            });
+            if let Some(&(_, _, default)) = cond {
+                debugln!("Using conditional default for '{}'", name);
+                cond_vals.push((name, default));
+            }
+        }
+        for o in self.opts
+            .iter()
+            .filter(|o| {
+                o.default_val.is_some() || o.default_fn.is_some() || o.env.is_some() ||
+                o.default_vals_ifs.is_some()
+            }) {
+            add_val!(self, o, matcher, cond_vals);
+        }
+        for p in self.positionals
+            .values()
+            .filter(|p| {
+                p.default_val.is_some() || p.default_fn.is_some() || p.env.is_some() ||
+                p.default_vals_ifs.is_some()
+            }) {
+            add_val!(self, p, matcher, cond_vals);
         }
         Ok(())
     }
@@ -1971,6 +2844,10 @@ impl<'a, 'b> Parser<'a, 'b>
         self.subcommands.iter()
     }
 
+    pub fn global_args(&self) -> Iter<Arg<'a, 'b>> {
+        self.global_args.iter()
+    }
+
     // Should we color the output? None=determined by output location, true=yes, false=no
     #[doc(hidden)]
     pub fn color(&self) -> ColorWhen {
@@ -2067,6 +2944,9 @@ impl<'a, 'b> Clone for Parser<'a, 'b>
             flags: self.flags.clone(),
             opts: self.opts.clone(),
             positionals: self.positionals.clone(),
+            args: self.args.clone(),
+            help_headings: self.help_headings.clone(),
+            current_help_heading: self.current_help_heading,
             subcommands: self.subcommands.clone(),
             groups: self.groups.clone(),
             global_args: self.global_args.clone(),
@@ -2076,6 +2956,7 @@ impl<'a, 'b> Clone for Parser<'a, 'b>
             settings: self.settings.clone(),
             g_settings: self.g_settings.clone(),
             meta: self.meta.clone(),
+            replacers: self.replacers.clone(),
             trailing_vals: self.trailing_vals,
         }
     }
@@ -3,7 +3,7 @@ use std::ascii::AsciiExt;
 use std::str::FromStr;
 
 bitflags! {
-    flags Flags: u32 {
+    flags Flags: u64 {
         const SC_NEGATE_REQS       = 0b00000000000000000000000000001,
         const SC_REQUIRED          = 0b00000000000000000000000000010,
         const A_REQUIRED_ELSE_HELP = 0b00000000000000000000000000100,
@@ -33,6 +33,12 @@ bitflags! {
         const DONT_DELIM_TRAIL     = 0b00100000000000000000000000000,
         const ALLOW_NEG_NUMS       = 0b01000000000000000000000000000,
         const LOW_INDEX_MUL_POS    = 0b10000000000000000000000000000,
+        const SC_PREC_OVER_ARG     = 0b100000000000000000000000000000,
+        const INFER_SUBCOMMANDS    = 0b1000000000000000000000000000000,
+        const INFER_LONG_ARGS      = 0b10000000000000000000000000000000,
+        const ALLOW_MISSING_POS    = 0b100000000000000000000000000000000,
+        const RESPONSE_FILES       = 0b1000000000000000000000000000000000,
+        const DISABLE_HELP_FLAGS   = 0b10000000000000000000000000000000000,
     }
 }
 
@@ -63,23 +69,29 @@ impl AppFlags {
         AllowInvalidUtf8 => UTF8_NONE,
         AllowLeadingHyphen => LEADING_HYPHEN,
         AllowNegativeNumbers => ALLOW_NEG_NUMS,
+        AllowMissingPositional => ALLOW_MISSING_POS,
         ColoredHelp => COLORED_HELP,
         ColorAlways => COLOR_ALWAYS,
         ColorAuto => COLOR_AUTO,
         ColorNever => COLOR_NEVER,
         DontDelimitTrailingValues => DONT_DELIM_TRAIL,
         DeriveDisplayOrder => DERIVE_DISP_ORDER,
+        DisableHelpFlags => DISABLE_HELP_FLAGS,
         DisableVersion => DISABLE_VERSION,
         GlobalVersion => GLOBAL_VERSION,
         HidePossibleValuesInHelp => NO_POS_VALUES,
         Hidden => HIDDEN,
+        InferLongArgs => INFER_LONG_ARGS,
+        InferSubcommands => INFER_SUBCOMMANDS,
         LowIndexMultiplePositional => LOW_INDEX_MUL_POS,
         NeedsLongHelp => NEEDS_LONG_HELP,
         NeedsLongVersion => NEEDS_LONG_VERSION,
         NeedsSubcommandHelp => NEEDS_SC_HELP,
         NoBinaryName => NO_BIN_NAME,
+        ResponseFiles => RESPONSE_FILES,
         StrictUtf8 => UTF8_STRICT,
         SubcommandsNegateReqs => SC_NEGATE_REQS,
+        SubcommandPrecedenceOverArg => SC_PREC_OVER_ARG,
         SubcommandRequired => SC_REQUIRED,
         SubcommandRequiredElseHelp => SC_REQUIRED_ELSE_HELP,
         TrailingVarArg => TRAILING_VARARG,
@@ -187,6 +199,36 @@ pub enum AppSettings {
     /// [`AllowLeadingHyphen`]: ./enum.AppSettings.html#variant.AllowLeadingHyphen
     AllowNegativeNumbers,
 
+    /// Allows the second to last positional argument to be omitted when it's optional and the
+    /// last positional argument is required, for patterns like `cp [SOURCE] DEST` where a single
+    /// value means `DEST`.
+    ///
+    /// Positional values are still assigned left to right, except that when the value which
+    /// would fill the optional positional is the final argument on the command line it's given
+    /// to the last positional instead.
+    ///
+    /// **NOTE:** Without this setting, an optional positional argument with a lower index than a
+    /// required one is a programming error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings};
+    /// let app = App::new("myprog")
+    ///     .setting(AppSettings::AllowMissingPositional)
+    ///     .arg(Arg::with_name("source"))
+    ///     .arg(Arg::with_name("dest").required(true));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["myprog", "out"]);
+    /// assert_eq!(m.value_of("source"), None);
+    /// assert_eq!(m.value_of("dest"), Some("out"));
+    ///
+    /// let m = app.get_matches_from(vec!["myprog", "in", "out"]);
+    /// assert_eq!(m.value_of("source"), Some("in"));
+    /// assert_eq!(m.value_of("dest"), Some("out"));
+    /// ```
+    AllowMissingPositional,
+
     /// Specifies that an unexpected positional argument,
     /// which would otherwise cause a [`ErrorKind::UnknownArgument`] error,
     /// should instead be treated as a [`SubCommand`] within the [`ArgMatches`] struct.
@@ -223,20 +265,29 @@ pub enum AppSettings {
     /// [`ArgMatches`]: ./struct.ArgMatches.html
     AllowExternalSubcommands,
 
-    /// Specifies that the help text should be displayed (and then exit gracefully),
-    /// if no arguments are present at runtime (i.e. an empty run such as, `$ myprog`.
+    /// Specifies that the help text should be displayed (on `stderr`, exiting with a status of
+    /// `2`), if no arguments are present at runtime (i.e. an empty run such as, `$ myprog`).
     ///
-    /// **NOTE:** [`SubCommand`]s count as arguments
+    /// Unlike [`AppSettings::SubcommandRequiredElseHelp`] this also applies to commands without
+    /// any [`SubCommand`]s, and any argument at all is enough to proceed with parsing.
+    ///
+    /// **NOTE:** [`SubCommand`]s count as arguments, while [default values] don't
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, AppSettings};
-    /// App::new("myprog")
+    /// # use clap::{App, Arg, AppSettings, ErrorKind};
+    /// let res = App::new("myprog")
     ///     .setting(AppSettings::ArgRequiredElseHelp)
-    /// # ;
+    ///     .arg(Arg::with_name("level").long("level").default_value("1"))
+    ///     .get_matches_from_safe(vec!["myprog"]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingArgumentOrSubcommand);
     /// ```
     /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`AppSettings::SubcommandRequiredElseHelp`]: ./enum.AppSettings.html#variant.SubcommandRequiredElseHelp
+    /// [default values]: ./struct.Arg.html#method.default_value
     ArgRequiredElseHelp,
 
     /// Uses colorized help messages.
@@ -257,7 +308,15 @@ pub enum AppSettings {
     /// ```
     ColoredHelp,
 
-    /// Enables colored output only when the output is going to a terminal or TTY.
+    /// Enables colored output only when the output is going to a terminal or TTY, unless the
+    /// environment says otherwise. In order of precedence
+    ///
+    /// * `NO_COLOR` set to a non-empty value disables color
+    /// * `CLICOLOR_FORCE` set to a non-empty value other than `0` forces color, even when the
+    ///   output isn't a terminal
+    /// * `CLICOLOR=0` disables color
+    ///
+    /// [`AppSettings::ColorAlways`] and [`AppSettings::ColorNever`] ignore these variables.
     ///
     /// **NOTE:** This is the default behavior of `clap`.
     ///
@@ -275,6 +334,8 @@ pub enum AppSettings {
     ///     .setting(AppSettings::ColorAuto)
     ///     .get_matches();
     /// ```
+    /// [`AppSettings::ColorAlways`]: ./enum.AppSettings.html#variant.ColorAlways
+    /// [`AppSettings::ColorNever`]: ./enum.AppSettings.html#variant.ColorNever
     ColorAuto,
 
     /// Enables colored output regardless of whether or not the output is going to a terminal/TTY.
@@ -332,6 +393,43 @@ pub enum AppSettings {
     /// [`Arg::use_delimiter(false)`]: ./struct.Arg.html#method.use_delimiter
     DontDelimitTrailingValues,
 
+    /// Disables the `-h` and `--help` flags `clap` generates for the [`App`], without affecting
+    /// any of its [`SubCommand`]s. This frees `-h` and `--help` for arguments of your own, and
+    /// when they aren't defined they are treated like any other unknown argument.
+    /// (Defaults to `false`; application *does* have help flags)
+    ///
+    /// **NOTE:** The `help` subcommand, which is generated when there are [`SubCommand`]s, is
+    /// not affected by this setting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .setting(AppSettings::DisableHelpFlags)
+    ///     .get_matches_from_safe(vec![
+    ///         "myprog", "-h"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+    /// ```
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings};
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::DisableHelpFlags)
+    ///     .arg(Arg::with_name("host")
+    ///         .short("h")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-h", "localhost"
+    ///     ]);
+    /// assert_eq!(m.value_of("host"), Some("localhost"));
+    /// ```
+    /// [`App`]: ./struct.App.html
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    DisableHelpFlags,
+
     /// Disables `-V` and `--version` [`App`] without affecting any of the [`SubCommand`]s
     /// (Defaults to `false`; application *does* have a version flag)
     ///
@@ -417,6 +515,66 @@ pub enum AppSettings {
     /// This can be useful if there are many values, or they are explained elsewhere.
     HidePossibleValuesInHelp,
 
+    /// Allows a long flag or option to be given as any unambiguous prefix of its name or one of
+    /// its aliases, i.e. `--verb` for `--verbose`. An exact name or alias always wins over a
+    /// prefix, and a prefix which matches more than one argument is an error listing every
+    /// candidate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, ErrorKind};
+    /// let m = App::new("prog")
+    ///     .setting(AppSettings::InferLongArgs)
+    ///     .arg(Arg::with_name("verbose").long("verbose"))
+    ///     .arg(Arg::with_name("output").long("output").takes_value(true))
+    ///     .get_matches_from(vec!["prog", "--verb", "--out=file"]);
+    /// assert!(m.is_present("verbose"));
+    /// assert_eq!(m.value_of("output"), Some("file"));
+    ///
+    /// // "--out" is a prefix of both "--output" and "--outdir"
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::InferLongArgs)
+    ///     .setting(AppSettings::ColorNever)
+    ///     .arg(Arg::with_name("output").long("output").takes_value(true))
+    ///     .arg(Arg::with_name("outdir").long("outdir").takes_value(true))
+    ///     .get_matches_from_safe(vec!["prog", "--out=file"]);
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    /// assert!(err.message.contains("It could match any of: '--output', '--outdir'"));
+    /// ```
+    InferLongArgs,
+
+    /// Allows a [`SubCommand`] to be selected by any unambiguous prefix of its name or one of
+    /// its aliases. An exact name or alias always wins over a prefix, and a prefix which matches
+    /// more than one [`SubCommand`] is an error listing every candidate.
+    ///
+    /// **NOTE:** Inference is only attempted for arguments which aren't being consumed as the
+    /// value of an option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, SubCommand, ErrorKind};
+    /// let m = App::new("prog")
+    ///     .setting(AppSettings::InferSubcommands)
+    ///     .subcommand(SubCommand::with_name("commit"))
+    ///     .subcommand(SubCommand::with_name("config"))
+    ///     .subcommand(SubCommand::with_name("status"))
+    ///     .get_matches_from(vec!["prog", "com"]);
+    /// assert_eq!(m.subcommand_name(), Some("commit"));
+    ///
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::InferSubcommands)
+    ///     .subcommand(SubCommand::with_name("commit"))
+    ///     .subcommand(SubCommand::with_name("config"))
+    ///     .get_matches_from_safe(vec!["prog", "co"]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidSubcommand);
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    InferSubcommands,
+
     /// Specifies that the parser should not assume the first argument passed is the binary name.
     /// This is normally the case when using a "daemon" style mode, or an interactive CLI where one
     /// one would not normally type the binary or program name for each command.
@@ -447,6 +605,42 @@ pub enum AppSettings {
     /// ```
     NextLineHelp,
 
+    /// Treats any argument beginning with `@` as the path to a "response file", whose contents
+    /// are inserted into the arguments at that position, as if they had been typed on the
+    /// command line. This allows invocations which would otherwise exceed the operating system's
+    /// limit on the length of a command line.
+    ///
+    /// The contents of a response file are split on whitespace and newlines. Lines whose first
+    /// non-whitespace character is `#` are comments, and are skipped. A response file may
+    /// itself use `@file` references, nested up to 10 levels deep. Arguments following a `--`
+    /// are never treated as response files.
+    ///
+    /// **NOTE:** This setting only applies when used on the top level [`App`], and the whole
+    /// command line (including any [`SubCommand`]s and their arguments) is expanded.
+    ///
+    /// **NOTE:** A response file which can't be read results in an [`ErrorKind::Io`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg, AppSettings};
+    /// // With "args.txt" containing "-v --output out.o"
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::ResponseFiles)
+    ///     .arg(Arg::with_name("verbose").short("v"))
+    ///     .arg(Arg::with_name("output").long("output").takes_value(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "@args.txt"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("verbose"));
+    /// assert_eq!(m.value_of("output"), Some("out.o"));
+    /// ```
+    /// [`App`]: ./struct.App.html
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ErrorKind::Io`]: ./enum.ErrorKind.html#variant.Io
+    ResponseFiles,
+
     /// Allows [`SubCommand`]s to override all requirements of the parent command.
     /// For example if you had a subcommand or top level application with a required argument
     /// that is only required as long as there is no subcommand present,
@@ -492,6 +686,34 @@ pub enum AppSettings {
     /// [`SubCommand`]: ./struct.SubCommand.html
     SubcommandsNegateReqs,
 
+    /// Specifies that any argument matching the name (or alias) of a known [`SubCommand`] should
+    /// *always* be treated as that subcommand, even when a variadic positional argument (i.e. one
+    /// with [`Arg::multiple(true)`]) is currently collecting values.
+    ///
+    /// By default, once a variadic positional argument has received its first value, any
+    /// following arguments are considered values of that positional, even if they happen to
+    /// match the name of a subcommand.
+    ///
+    /// **NOTE:** Using `--` will still force all following arguments to be treated as values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, SubCommand};
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::SubcommandPrecedenceOverArg)
+    ///     .arg(Arg::with_name("files").multiple(true))
+    ///     .subcommand(SubCommand::with_name("sync"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "a.txt", "b.txt", "sync"
+    ///     ]);
+    /// assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), ["a.txt", "b.txt"]);
+    /// assert_eq!(m.subcommand_name(), Some("sync"));
+    /// ```
+    /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    SubcommandPrecedenceOverArg,
+
     /// Specifies that the help text should be displayed (before exiting gracefully) if no
     /// [`SubCommand`]s are present at runtime (i.e. an empty run such as `$ myprog`).
     ///
@@ -679,21 +901,27 @@ impl FromStr for AppSettings {
             "allowleadinghyphen" => Ok(AppSettings::AllowLeadingHyphen),
             "allowexternalsubcommands" => Ok(AppSettings::AllowExternalSubcommands),
             "allownegativenumbers" => Ok(AppSettings::AllowNegativeNumbers),
+            "allowmissingpositional" => Ok(AppSettings::AllowMissingPositional),
             "colorauto" => Ok(AppSettings::ColorAuto),
             "coloralways" => Ok(AppSettings::ColorAlways),
             "colornever" => Ok(AppSettings::ColorNever),
             "coloredhelp" => Ok(AppSettings::ColoredHelp),
             "derivedisplayorder" => Ok(AppSettings::DeriveDisplayOrder),
             "dontdelimittrailingvalues" => Ok(AppSettings::DontDelimitTrailingValues),
+            "disablehelpflags" => Ok(AppSettings::DisableHelpFlags),
             "disableversion" => Ok(AppSettings::DisableVersion),
             "globalversion" => Ok(AppSettings::GlobalVersion),
             "hidden" => Ok(AppSettings::Hidden),
             "hidepossiblevaluesinhelp" => Ok(AppSettings::HidePossibleValuesInHelp),
+            "inferlongargs" => Ok(AppSettings::InferLongArgs),
+            "infersubcommands" => Ok(AppSettings::InferSubcommands),
             "lowindexmultiplepositional" => Ok(AppSettings::LowIndexMultiplePositional),
             "nobinaryname" => Ok(AppSettings::NoBinaryName),
             "nextlinehelp" => Ok(AppSettings::NextLineHelp),
+            "responsefiles" => Ok(AppSettings::ResponseFiles),
             "strictutf8" => Ok(AppSettings::StrictUtf8),
             "subcommandsnegatereqs" => Ok(AppSettings::SubcommandsNegateReqs),
+            "subcommandprecedenceoverarg" => Ok(AppSettings::SubcommandPrecedenceOverArg),
             "subcommandrequired" => Ok(AppSettings::SubcommandRequired),
             "subcommandrequiredelsehelp" => Ok(AppSettings::SubcommandRequiredElseHelp),
             "trailingvararg" => Ok(AppSettings::TrailingVarArg),
@@ -721,6 +949,8 @@ mod test {
                    AppSettings::AllowLeadingHyphen);
         assert_eq!("allownegativenumbers".parse::<AppSettings>().unwrap(),
                    AppSettings::AllowNegativeNumbers);
+        assert_eq!("allowmissingpositional".parse::<AppSettings>().unwrap(),
+                   AppSettings::AllowMissingPositional);
         assert_eq!("coloredhelp".parse::<AppSettings>().unwrap(),
                    AppSettings::ColoredHelp);
         assert_eq!("colorauto".parse::<AppSettings>().unwrap(),
@@ -729,6 +959,8 @@ mod test {
                    AppSettings::ColorAlways);
         assert_eq!("colornever".parse::<AppSettings>().unwrap(),
                    AppSettings::ColorNever);
+        assert_eq!("disablehelpflags".parse::<AppSettings>().unwrap(),
+                   AppSettings::DisableHelpFlags);
         assert_eq!("disableversion".parse::<AppSettings>().unwrap(),
                    AppSettings::DisableVersion);
         assert_eq!("dontdelimittrailingvalues".parse::<AppSettings>().unwrap(),
@@ -741,14 +973,22 @@ mod test {
                    AppSettings::Hidden);
         assert_eq!("hidepossiblevaluesinhelp".parse::<AppSettings>().unwrap(),
                    AppSettings::HidePossibleValuesInHelp);
+        assert_eq!("inferlongargs".parse::<AppSettings>().unwrap(),
+                   AppSettings::InferLongArgs);
+        assert_eq!("infersubcommands".parse::<AppSettings>().unwrap(),
+                   AppSettings::InferSubcommands);
         assert_eq!("lowindexmultiplePositional".parse::<AppSettings>().unwrap(),
                    AppSettings::LowIndexMultiplePositional);
         assert_eq!("nobinaryname".parse::<AppSettings>().unwrap(),
                    AppSettings::NoBinaryName);
         assert_eq!("nextlinehelp".parse::<AppSettings>().unwrap(),
                    AppSettings::NextLineHelp);
+        assert_eq!("responsefiles".parse::<AppSettings>().unwrap(),
+                   AppSettings::ResponseFiles);
         assert_eq!("subcommandsnegatereqs".parse::<AppSettings>().unwrap(),
                    AppSettings::SubcommandsNegateReqs);
+        assert_eq!("subcommandprecedenceoverarg".parse::<AppSettings>().unwrap(),
+                   AppSettings::SubcommandPrecedenceOverArg);
         assert_eq!("subcommandrequired".parse::<AppSettings>().unwrap(),
                    AppSettings::SubcommandRequired);
         assert_eq!("subcommandrequiredelsehelp".parse::<AppSettings>().unwrap(),
@@ -1,5 +1,8 @@
 // Std
+use std::ffi::OsStr;
+use std::any::Any;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::fmt as std_fmt; 
 
 // Third Party
@@ -8,6 +11,10 @@ use vec_map::VecMap;
 // Internal
 use args::settings::ArgSettings;
 
+// The conversion set by `Arg::value_parser`
+#[doc(hidden)]
+pub type ValueParser = Rc<Fn(&str) -> Result<Arc<Any + Send + Sync>, String>>;
+
 #[doc(hidden)]
 pub trait AnyArg<'n, 'e>: std_fmt::Display {
     fn name(&self) -> &'n str;
@@ -24,13 +31,15 @@ pub trait AnyArg<'n, 'e>: std_fmt::Display {
     fn num_vals(&self) -> Option<u64>;
     fn possible_vals(&self) -> Option<&[&'e str]>;
     fn validator(&self) -> Option<&Rc<Fn(String) -> Result<(), String>>>;
+    fn value_parser(&self) -> Option<&ValueParser>;
     fn short(&self) -> Option<char>;
     fn long(&self) -> Option<&'e str>;
     fn val_delim(&self) -> Option<char>;
     fn takes_value(&self) -> bool;
     fn val_names(&self) -> Option<&VecMap<&'e str>>;
     fn help(&self) -> Option<&'e str>;
-    fn default_val(&self) -> Option<&'n str>;
+    fn long_help(&self) -> Option<&'e str>;
+    fn default_val(&self) -> Option<&'n OsStr>;
     fn longest_filter(&self) -> bool;
 }
 
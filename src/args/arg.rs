@@ -1,12 +1,16 @@
 #[cfg(feature = "yaml")]
 use std::collections::BTreeMap;
+use std::ffi::{OsStr, OsString};
+use std::any::Any;
 use std::rc::Rc;
+use std::sync::Arc;
 
 #[cfg(feature = "yaml")]
 use yaml_rust::Yaml;
 use vec_map::VecMap;
 
 use usage_parser::UsageParser;
+use args::ValueParser;
 use args::settings::{ArgFlags, ArgSettings};
 
 /// The abstract representation of a command line argument. Used to set all the options and
@@ -46,6 +50,10 @@ pub struct Arg<'a, 'b>
     #[doc(hidden)]
     pub help: Option<&'b str>,
     #[doc(hidden)]
+    pub long_help: Option<&'b str>,
+    #[doc(hidden)]
+    pub max_occurs: Option<u64>,
+    #[doc(hidden)]
     pub index: Option<u64>,
     #[doc(hidden)]
     pub blacklist: Option<Vec<&'a str>>,
@@ -66,17 +74,33 @@ pub struct Arg<'a, 'b>
     #[doc(hidden)]
     pub validator: Option<Rc<Fn(String) -> Result<(), String>>>,
     #[doc(hidden)]
+    pub value_parser: Option<ValueParser>,
+    #[doc(hidden)]
     pub overrides: Option<Vec<&'a str>>,
     #[doc(hidden)]
     pub settings: ArgFlags,
     #[doc(hidden)]
     pub val_delim: Option<char>,
     #[doc(hidden)]
-    pub default_val: Option<&'a str>,
+    pub default_val: Option<&'a OsStr>,
+    #[doc(hidden)]
+    pub default_missing_val: Option<&'a OsStr>,
     #[doc(hidden)]
     pub disp_ord: usize,
     #[doc(hidden)]
+    pub help_heading: Option<&'b str>,
+    #[doc(hidden)]
     pub r_unless: Option<Vec<&'a str>>,
+    #[doc(hidden)]
+    pub r_ifs: Option<Vec<(&'b str, &'a str)>>,
+    #[doc(hidden)]
+    pub env: Option<&'a OsStr>,
+    #[doc(hidden)]
+    pub default_fn: Option<Rc<Fn() -> OsString>>,
+    #[doc(hidden)]
+    pub default_vals_ifs: Option<Vec<(&'a str, Option<&'a OsStr>, &'a OsStr)>>,
+    #[doc(hidden)]
+    pub completion_fn: Option<Rc<Fn() -> Vec<String>>>,
 }
 
 impl<'a, 'b> Default for Arg<'a, 'b> {
@@ -87,6 +111,8 @@ impl<'a, 'b> Default for Arg<'a, 'b> {
             long: None,
             aliases: None,
             help: None,
+            long_help: None,
+            max_occurs: None,
             index: None,
             blacklist: None,
             possible_vals: None,
@@ -97,12 +123,20 @@ impl<'a, 'b> Default for Arg<'a, 'b> {
             max_vals: None,
             min_vals: None,
             validator: None,
+            value_parser: None,
             overrides: None,
             settings: ArgFlags::new(),
             val_delim: None,
             default_val: None,
+            default_missing_val: None,
             disp_ord: 999,
+            help_heading: None,
             r_unless: None,
+            r_ifs: None,
+            env: None,
+            default_fn: None,
+            default_vals_ifs: None,
+            completion_fn: None,
         }
     }
 }
@@ -164,19 +198,32 @@ impl<'a, 'b> Arg<'a, 'b> {
                 "global" => yaml_to_bool!(a, v, global),
                 "multiple" => yaml_to_bool!(a, v, multiple),
                 "hidden" => yaml_to_bool!(a, v, hidden),
+                "hidden_short_help" => yaml_to_bool!(a, v, hidden_short_help),
+                "hidden_long_help" => yaml_to_bool!(a, v, hidden_long_help),
+                "last" => yaml_to_bool!(a, v, last),
+                "allow_hyphen_values" => yaml_to_bool!(a, v, allow_hyphen_values),
+                "require_equals" => yaml_to_bool!(a, v, require_equals),
                 "next_line_help" => yaml_to_bool!(a, v, next_line_help),
+                "hide_possible_values" => yaml_to_bool!(a, v, hide_possible_values),
+                "hide_default_value" => yaml_to_bool!(a, v, hide_default_value),
                 "empty_values" => yaml_to_bool!(a, v, empty_values),
                 "group" => yaml_to_str!(a, v, group),
                 "number_of_values" => yaml_to_u64!(a, v, number_of_values),
                 "max_values" => yaml_to_u64!(a, v, max_values),
+                "max_occurrences" => yaml_to_u64!(a, v, max_occurrences),
                 "min_values" => yaml_to_u64!(a, v, min_values),
                 "value_name" => yaml_to_str!(a, v, value_name),
                 "use_delimiter" => yaml_to_bool!(a, v, use_delimiter),
                 "require_delimiter" => yaml_to_bool!(a, v, require_delimiter),
+                "long_help" => yaml_to_str!(a, v, long_help),
+                "help_heading" => yaml_to_str!(a, v, help_heading),
+                "normalize_path_separators" => yaml_to_bool!(a, v, normalize_path_separators),
                 "value_delimiter" => yaml_to_str!(a, v, value_delimiter),
                 "required_unless" => yaml_to_str!(a, v, required_unless),
                 "display_order" => yaml_to_usize!(a, v, display_order),
                 "default_value" => yaml_to_str!(a, v, default_value),
+                "default_missing_value" => yaml_to_str!(a, v, default_missing_value),
+                "env" => yaml_to_str!(a, v, env),
                 "value_names" => yaml_vec_or_str!(v, a, value_name),
                 "groups" => yaml_vec_or_str!(v, a, group),
                 "requires" => yaml_vec_or_str!(v, a, requires),
@@ -298,6 +345,19 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// --rapid=<SPEED>... 'some required multiple option'
     /// ```
     ///
+    /// ### Default Values
+    ///
+    /// A value name, or the explicit name of a positional argument, may be followed by `=` and a
+    /// default value which is used just like [`Arg::default_value`]. The default value may not
+    /// contain `]` or `>`.
+    ///
+    /// Example default values are as follows (`info`, and `in.txt`):
+    ///
+    /// ```notrust
+    /// --level [LEVEL=info] 'some option with a default'
+    /// [input=in.txt] 'some positional with a default'
+    /// ```
+    ///
     /// ### Help String
     ///
     /// The help string is denoted between a pair of single quotes `''` and may contain any
@@ -331,6 +391,7 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// ```
     /// [`Arg`]: ./struct.Arg.html
     /// [`Arg::from_usage`]: ./struct.Arg.html#method.from_usage
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
     pub fn from_usage(u: &'a str) -> Self {
         let parser = UsageParser::from_usage(u);
         parser.parse()
@@ -577,6 +638,34 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Sets a longer, more detailed description of the argument which is only displayed when
+    /// the user passes `--help`. When the user passes `-h` the regular [`Arg::help`] is displayed
+    /// instead. If no long help is set, `--help` falls back to [`Arg::help`] as well.
+    ///
+    /// **NOTE:** Only `-h` and `--help` are affected. The `help` subcommand displays the same
+    /// message as `--help`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .long("config")
+    ///         .help("Sets the config file")
+    ///         .long_help("Sets the config file to use. If omitted the config file \
+    ///                     is searched for in the current directory and then in \
+    ///                     the user's home directory."))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--help"
+    ///     ]);
+    /// ```
+    /// [`Arg::help`]: ./struct.Arg.html#method.help
+    pub fn long_help(mut self, h: &'b str) -> Self {
+        self.long_help = Some(h);
+        self
+    }
+
     /// Sets whether or not the argument is required by default. Required by default means it is
     /// required, when no other conflicting rules have been evaluated. Conflicting rules take
     /// precedence over being required. **Default:** `false`
@@ -974,6 +1063,20 @@ impl<'a, 'b> Arg<'a, 'b> {
     ///                                 // was never used because it was overridden with color
     /// assert!(!m.is_present("flag"));
     /// ```
+    ///
+    /// An argument may also override itself, in which case only its last occurrence is kept
+    /// instead of it being an error to use it more than once.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("posix")
+    ///     .arg(Arg::from_usage("--color [when] 'when to use color'")
+    ///         .overrides_with("color"))
+    ///     .get_matches_from(vec!["posix", "--color=always", "--color=never"]);
+    ///
+    /// assert_eq!(m.value_of("color"), Some("never"));
+    /// assert_eq!(m.occurrences_of("color"), 1);
+    /// ```
     pub fn overrides_with(mut self, name: &'a str) -> Self {
         if let Some(ref mut vec) = self.overrides {
             vec.push(name.as_ref());
@@ -1084,6 +1187,72 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Allows specifying that another argument is required *only* when this argument is present
+    /// *and* has a specific value (i.e. `--mode=ssl` requires `--cert`, but `--mode=plain`
+    /// doesn't). This is a conditional version of [`Arg::requires`].
+    ///
+    /// **NOTE:** The value is compared against each of the values of this argument, so for
+    /// arguments which accept multiple values, any matching value will trigger the requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::with_name("mode")
+    ///     .requires_if("ssl", "cert")
+    /// # ;
+    /// ```
+    ///
+    /// Setting [`Arg::requires_if(val, arg)`] and using a different value for the defining
+    /// argument doesn't require the other argument.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("mode")
+    ///         .long("mode")
+    ///         .takes_value(true)
+    ///         .requires_if("ssl", "cert"))
+    ///     .arg(Arg::with_name("cert")
+    ///         .long("cert")
+    ///         .takes_value(true))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--mode", "plain"
+    ///     ]);
+    ///
+    /// assert!(res.is_ok()); // We didn't use --mode=ssl, so --cert wasn't required
+    /// ```
+    ///
+    /// Using the triggering value, and *not* supplying the other argument is an error.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("mode")
+    ///         .long("mode")
+    ///         .takes_value(true)
+    ///         .requires_if("ssl", "cert"))
+    ///     .arg(Arg::with_name("cert")
+    ///         .long("cert")
+    ///         .takes_value(true))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--mode", "ssl"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::requires`]: ./struct.Arg.html#method.requires
+    /// [`Arg::requires_if(val, arg)`]: ./struct.Arg.html#method.requires_if
+    pub fn requires_if(mut self, val: &'b str, arg: &'a str) -> Self {
+        if let Some(ref mut vec) = self.r_ifs {
+            vec.push((val, arg));
+        } else {
+            self.r_ifs = Some(vec![(val, arg)]);
+        }
+        self
+    }
+
     /// Sets multiple arguments by names that are required when this one is present I.e. when
     /// using this argument, the following arguments *must* be present.
     ///
@@ -1237,6 +1406,82 @@ impl<'a, 'b> Arg<'a, 'b> {
         }
     }
 
+    /// Specifies if the [default value] of an argument should be displayed in the help text or
+    /// not. Defaults to `false` (i.e. show the default value)
+    ///
+    /// The default value is still used at runtime, it is only omitted from the help text. This is
+    /// useful when the default is long, or depends on the environment (such as a path inside the
+    /// user's home directory).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// Arg::with_name("config")
+    ///     .hide_default_value(true)
+    /// # ;
+    /// ```
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("hide_default")
+    ///     .arg(Arg::with_name("cache")
+    ///         .long("cache")
+    ///         .takes_value(true)
+    ///         .default_value("/home/kevin/.cache")
+    ///         .hide_default_value(true))
+    ///     .get_matches_from(vec![
+    ///         "hide_default"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("cache"), Some("/home/kevin/.cache"));
+    /// ```
+    ///
+    /// If we were to run the above program with `--help` the `[default: /home/kevin/.cache]`
+    /// portion of the help text would be omitted.
+    /// [default value]: ./struct.Arg.html#method.default_value
+    pub fn hide_default_value(self, hide: bool) -> Self {
+        if hide {
+            self.set(ArgSettings::HideDefaultValue)
+        } else {
+            self.unset(ArgSettings::HideDefaultValue)
+        }
+    }
+
+    /// Specifies that any `/` or `\` found in the values of this argument should be converted to
+    /// the path separator of the current platform (i.e. [`std::path::MAIN_SEPARATOR`]) at parse
+    /// time. Defaults to `false`
+    ///
+    /// This is useful for arguments which take paths, so that consumers don't have to normalize
+    /// the separators of each value themselves. Values which aren't valid UTF-8 are handled as
+    /// well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// # use std::path::MAIN_SEPARATOR;
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("path")
+    ///         .long("path")
+    ///         .takes_value(true)
+    ///         .normalize_path_separators(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--path", "a/b\\c"
+    ///     ]);
+    ///
+    /// let expected = format!("a{0}b{0}c", MAIN_SEPARATOR);
+    /// assert_eq!(m.value_of("path"), Some(&*expected));
+    /// ```
+    /// [`std::path::MAIN_SEPARATOR`]: https://doc.rust-lang.org/std/path/constant.MAIN_SEPARATOR.html
+    pub fn normalize_path_separators(self, norm: bool) -> Self {
+        if norm {
+            self.set(ArgSettings::NormalizePathSeparators)
+        } else {
+            self.unset(ArgSettings::NormalizePathSeparators)
+        }
+    }
+
     /// Specifies the index of a positional argument **starting at** 1.
     ///
     /// **NOTE:** The index refers to position according to **other positional argument**. It does
@@ -1441,16 +1686,66 @@ impl<'a, 'b> Arg<'a, 'b> {
         }
     }
 
+    /// Specifies the maximum number of times an argument may be used at runtime, such as capping
+    /// a verbosity flag at `-vvv`. Using the argument more often than that is an error of the
+    /// kind [`ErrorKind::TooManyOccurrences`].
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::multiple(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v")
+    ///         .max_occurrences(3))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-vvv"
+    ///     ]);
+    ///
+    /// assert_eq!(m.occurrences_of("verbose"), 3);
+    /// ```
+    ///
+    /// Using the argument more often is an error
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v")
+    ///         .max_occurrences(3))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "-vvvv"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+    /// ```
+    /// [`ErrorKind::TooManyOccurrences`]: ./enum.ErrorKind.html#variant.TooManyOccurrences
+    /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
+    pub fn max_occurrences(mut self, qty: u64) -> Self {
+        self.max_occurs = Some(qty);
+        self.set(ArgSettings::Multiple)
+    }
+
     /// Specifies that an argument can be matched to all child [`SubCommand`]s.
     ///
     /// **NOTE:** Global arguments *only* propagate down, **not** up (to parent commands)
     ///
     /// **NOTE:** Global arguments *cannot* be [required].
     ///
-    /// **NOTE:** Global arguments, when matched, *only* exist in the command's matches that they
-    /// were matched to. For example, if you defined a `--flag` global argument in the top most
-    /// parent command, but the user supplied the arguments `top cmd1 cmd2 --flag` *only* `cmd2`'s
-    /// [`ArgMatches`] would return `true` if tested for [`ArgMatches::is_present("flag")`].
+    /// **NOTE:** Once parsing is done the value of a global argument is passed down to the
+    /// [`ArgMatches`] of every command *below* the one it was used in, but not above it. For
+    /// example, if you defined a `--flag` global argument in the top most parent command, and the
+    /// user supplied the arguments `top cmd1 --flag cmd2`, the [`ArgMatches`] of `cmd1` and `cmd2`
+    /// would return `true` if tested for [`ArgMatches::is_present("flag")`], but `top`'s would
+    /// not. When it's used at more than one level, the value used deepest in the chain wins. Use
+    /// [`ArgMatches::value_of_global`] to look for the value wherever it was used.
+    ///
+    /// **NOTE:** In the help message of a subcommand, inherited global arguments are displayed
+    /// under their own `GLOBAL OPTIONS:` heading, apart from the subcommand's own arguments. Use
+    /// [`Arg::help_heading`] to display them under another heading instead.
     ///
     /// # Examples
     ///
@@ -1480,12 +1775,15 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// assert_eq!(m.subcommand_name(), Some("do-stuff"));
     /// let sub_m = m.subcommand_matches("do-stuff").unwrap();
     /// assert!(sub_m.is_present("verb"));
+    /// assert!(!m.is_present("verb"));
     /// ```
     /// [`SubCommand`]: ./struct.SubCommand.html
     /// [required]: ./struct.Arg.html#method.required
     /// [`ArgMatches`]: ./struct.ArgMatches.html
     /// [`ArgMatches::is_present("flag")`]: ./struct.ArgMatches.html#method.is_present
+    /// [`ArgMatches::value_of_global`]: ./struct.ArgMatches.html#method.value_of_global
     /// [`Arg`]: ./struct.Arg.html
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
     pub fn global(self, g: bool) -> Self {
         if g {
             self.set(ArgSettings::Global)
@@ -1581,6 +1879,193 @@ impl<'a, 'b> Arg<'a, 'b> {
         }
     }
 
+    /// Hides an argument from the help message displayed by `-h`, while still showing it in the
+    /// help message displayed by `--help`. This is useful for less common arguments which would
+    /// clutter the short help, but should still be discoverable. Like [`Arg::hidden`] the
+    /// argument can still be used at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("cache-dir")
+    ///         .long("cache-dir")
+    ///         .takes_value(true)
+    ///         .hidden_short_help(true)
+    ///         .help("Sets the directory used for caching"));
+    ///
+    /// let mut short = Vec::new();
+    /// app.write_help(&mut short).unwrap();
+    /// assert!(!String::from_utf8(short).unwrap().contains("--cache-dir"));
+    ///
+    /// let mut long = Vec::new();
+    /// app.write_long_help(&mut long).unwrap();
+    /// assert!(String::from_utf8(long).unwrap().contains("--cache-dir"));
+    /// ```
+    /// [`Arg::hidden`]: ./struct.Arg.html#method.hidden
+    pub fn hidden_short_help(self, h: bool) -> Self {
+        if h {
+            self.set(ArgSettings::HiddenShortHelp)
+        } else {
+            self.unset(ArgSettings::HiddenShortHelp)
+        }
+    }
+
+    /// Hides an argument from the help message displayed by `--help`, while still showing it in
+    /// the help message displayed by `-h`. Like [`Arg::hidden`] the argument can still be used at
+    /// runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("q")
+    ///         .short("q")
+    ///         .hidden_long_help(true)
+    ///         .help("Quiet mode"));
+    ///
+    /// let mut long = Vec::new();
+    /// app.write_long_help(&mut long).unwrap();
+    /// assert!(!String::from_utf8(long).unwrap().contains("Quiet mode"));
+    /// ```
+    /// [`Arg::hidden`]: ./struct.Arg.html#method.hidden
+    pub fn hidden_long_help(self, h: bool) -> Self {
+        if h {
+            self.set(ArgSettings::HiddenLongHelp)
+        } else {
+            self.unset(ArgSettings::HiddenLongHelp)
+        }
+    }
+
+    /// Specifies that this positional argument only receives the values given after a `--`,
+    /// such as `prog --flag -- extra1 extra2`. This is commonly used to pass arguments through
+    /// to another program.
+    ///
+    /// Once `--` has been passed *all* remaining values go to this argument, and a value given
+    /// before `--` which would otherwise be assigned to it is an error.
+    ///
+    /// **NOTE:** This setting only applies to [positional arguments], and should be used on the
+    /// positional argument with the highest index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("verbose").long("verbose"))
+    ///     .arg(Arg::with_name("extra")
+    ///         .multiple(true)
+    ///         .last(true));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--verbose", "--", "-a", "b"]);
+    /// assert!(m.is_present("verbose"));
+    /// assert_eq!(m.values_of("extra").unwrap().collect::<Vec<_>>(), ["-a", "b"]);
+    ///
+    /// let res = app.get_matches_from_safe(vec!["prog", "b"]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+    /// ```
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    pub fn last(self, l: bool) -> Self {
+        if l {
+            self.set(ArgSettings::Last)
+        } else {
+            self.unset(ArgSettings::Last)
+        }
+    }
+
+    /// Allows this option to accept values which start with a leading hyphen (`-`), such as
+    /// `--sed-expr -n`. Without this setting such a value would be parsed as an argument of its
+    /// own, and most likely fail as an unknown argument.
+    ///
+    /// Unlike [`AppSettings::AllowLeadingHyphen`] this only applies to the values of this one
+    /// option, all other arguments still report stray leading hyphen tokens as errors.
+    ///
+    /// **WARNING:** When combined with [`Arg::multiple`] the option keeps consuming values, even
+    /// ones which look like other arguments, until it has received all the values it needs. Use
+    /// [`Arg::number_of_values`] or [`Arg::max_values`] to limit it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("expr")
+    ///         .long("sed-expr")
+    ///         .takes_value(true)
+    ///         .allow_hyphen_values(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--sed-expr", "-n"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("expr"), Some("-n"));
+    /// ```
+    /// [`AppSettings::AllowLeadingHyphen`]: ./enum.AppSettings.html#variant.AllowLeadingHyphen
+    /// [`Arg::multiple`]: ./struct.Arg.html#method.multiple
+    /// [`Arg::number_of_values`]: ./struct.Arg.html#method.number_of_values
+    /// [`Arg::max_values`]: ./struct.Arg.html#method.max_values
+    pub fn allow_hyphen_values(self, a: bool) -> Self {
+        if a {
+            self.set(ArgSettings::AllowHyphenValues)
+        } else {
+            self.unset(ArgSettings::AllowHyphenValues)
+        }
+    }
+
+    /// Requires that the value of this option is joined to it with an `=`, such as
+    /// `--color=never`. The space separated form `--color never` is an error, which keeps the
+    /// following argument from being mistaken for the option's value.
+    ///
+    /// This is mostly useful for options whose value is optional (i.e. [`Arg::min_values(0)`]),
+    /// where `--color` on its own is then valid and never consumes the next argument.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .takes_value(true)
+    ///         .min_values(0)
+    ///         .require_equals(true))
+    ///     .arg(Arg::with_name("file"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--color=never"]);
+    /// assert_eq!(m.value_of("color"), Some("never"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--color", "file.txt"]);
+    /// assert!(m.is_present("color"));
+    /// assert_eq!(m.value_of("color"), None);
+    /// assert_eq!(m.value_of("file"), Some("file.txt"));
+    /// ```
+    ///
+    /// Without [`Arg::min_values(0)`] a value must be given, and it must use the `=`
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .takes_value(true)
+    ///         .require_equals(true))
+    ///     .get_matches_from_safe(vec!["prog", "--color", "never"]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::NoEquals);
+    /// ```
+    /// [`Arg::min_values(0)`]: ./struct.Arg.html#method.min_values
+    pub fn require_equals(mut self, r: bool) -> Self {
+        if r {
+            self.setb(ArgSettings::TakesValue);
+            self.set(ArgSettings::RequireEquals)
+        } else {
+            self.unset(ArgSettings::RequireEquals)
+        }
+    }
+
     /// Specifies a list of possible values for this argument. At runtime, `clap` verifies that
     /// only one of the specified values was used, or fails with an error message.
     ///
@@ -1851,6 +2336,98 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Only allows whole numbers between `min` and `max` (both inclusive) as values of the
+    /// argument, for example `value_range(1, 65535)` for a port number. Values outside of the
+    /// range, and values which aren't numbers, result in an [`ErrorKind::ValueValidation`]
+    /// error such as `0 is not in range 1..=65535`.
+    ///
+    /// **NOTE:** This is implemented with an [`Arg::validator`], and so replaces any validator
+    /// set previously (and is replaced by any validator set afterwards).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("server")
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_range(1, 65535))
+    ///     .get_matches_from_safe(vec![
+    ///         "server", "--port", "70000"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    pub fn value_range(self, min: i64, max: i64) -> Self {
+        self.validator(move |v| match v.parse::<i64>() {
+            Ok(n) if n < min || n > max => Err(format!("{} is not in range {}..={}", n, min, max)),
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("'{}' is not a number", v)),
+        })
+    }
+
+    /// Converts each value of the argument into a type of your choice while parsing, using the
+    /// provided closure. A value the closure returns an [`Err(String)`] for is reported to the
+    /// user as an [`ErrorKind::ValueValidation`] error right away, just like with
+    /// [`Arg::validator`], and the converted values can later be retrieved with
+    /// [`ArgMatches::value_of_t`] and [`ArgMatches::values_of_t`], which then can't fail.
+    ///
+    /// Default values, and values from the environment, are converted as well. The original
+    /// strings remain available through [`ArgMatches::value_of`] and friends.
+    ///
+    /// **NOTE:** The error message does *not* need to contain the `error:` portion, only the
+    /// message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// fn port(v: &str) -> Result<u16, String> {
+    ///     v.parse().map_err(|_| format!("'{}' isn't a valid port number", v))
+    /// }
+    ///
+    /// let m = App::new("server")
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_parser(port))
+    ///     .get_matches_from(vec![
+    ///         "server", "--port", "8080"
+    ///     ]);
+    /// assert_eq!(m.value_of_t::<u16, _>("port"), Some(&8080));
+    ///
+    /// let res = App::new("server")
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_parser(port))
+    ///     .get_matches_from_safe(vec![
+    ///         "server", "--port", "http"
+    ///     ]);
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`Err(String)`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`ArgMatches::value_of_t`]: ./struct.ArgMatches.html#method.value_of_t
+    /// [`ArgMatches::values_of_t`]: ./struct.ArgMatches.html#method.values_of_t
+    /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
+    pub fn value_parser<F, T>(mut self, f: F) -> Self
+        where F: Fn(&str) -> Result<T, String> + 'static,
+              T: Any + Send + Sync
+    {
+        self.value_parser = Some(Rc::new(move |v: &str| {
+            f(v).map(|t| {
+                let t: Arc<Any + Send + Sync> = Arc::new(t);
+                t
+            })
+        }));
+        self
+    }
+
     /// Specifies the *maximum* number of values are for this argument. For example, if you had a
     /// `-f <file>` argument where you wanted up to 3 'files' you would set `.max_values(3)`, and
     /// this argument would be satisfied if the user provided, 1, 2, or 3 values.
@@ -2343,12 +2920,372 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
     /// [`Arg::takes_value(true)`]: /struct.Arg.html#method.takes_value
     /// [`ArgMatches::is_present`]: /struct.ArgMatches.html#method.is_present
-    pub fn default_value(mut self, val: &'a str) -> Self {
+    pub fn default_value(self, val: &'a str) -> Self {
+        self.default_value_os(OsStr::new(val))
+    }
+
+    /// Provides a default value in the exact same manner as [`Arg::default_value`], only using
+    /// [`OsStr`]s instead. This allows defaults which aren't valid UTF-8, such as paths derived
+    /// from the environment on Unix.
+    ///
+    /// **NOTE:** The default is displayed lossily in the help message, but [`ArgMatches::value_of_os`]
+    /// and [`ArgMatches::values_of_os`] return it unchanged.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ffi::OsStr;
+    /// # use clap::{App, Arg};
+    /// let m = App::new("defvals")
+    ///     .arg(Arg::with_name("dir")
+    ///         .long("dir")
+    ///         .default_value_os(OsStr::new("/tmp")))
+    ///     .get_matches_from(vec![
+    ///         "defvals"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of_os("dir"), Some(OsStr::new("/tmp")));
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+    /// [`ArgMatches::value_of_os`]: ./struct.ArgMatches.html#method.value_of_os
+    /// [`ArgMatches::values_of_os`]: ./struct.ArgMatches.html#method.values_of_os
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn default_value_os(mut self, val: &'a OsStr) -> Self {
         self.setb(ArgSettings::TakesValue);
         self.default_val = Some(val);
         self
     }
 
+    /// Specifies the value of an option when it's used *without* a value at runtime, such as a
+    /// bare `--color` meaning `--color=auto`. This only applies to options whose value is
+    /// optional (i.e. [`Arg::min_values(0)`]), and is usually combined with
+    /// [`Arg::require_equals`] so a bare option never consumes the following argument.
+    ///
+    /// Unlike [`Arg::default_value`], which is used when the option wasn't given at all,
+    /// [`ArgMatches::is_present`] is `true` when the default missing value is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .value_name("WHEN")
+    ///         .min_values(0)
+    ///         .require_equals(true)
+    ///         .default_missing_value("auto"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--color"]);
+    /// assert!(m.is_present("color"));
+    /// assert_eq!(m.value_of("color"), Some("auto"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--color=never"]);
+    /// assert_eq!(m.value_of("color"), Some("never"));
+    ///
+    /// let m = app.get_matches_from(vec!["prog"]);
+    /// assert!(!m.is_present("color"));
+    /// ```
+    /// [`Arg::min_values(0)`]: ./struct.Arg.html#method.min_values
+    /// [`Arg::require_equals`]: ./struct.Arg.html#method.require_equals
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    pub fn default_missing_value(self, val: &'a str) -> Self {
+        self.default_missing_value_os(OsStr::new(val))
+    }
+
+    /// Provides a default missing value in the exact same manner as
+    /// [`Arg::default_missing_value`] only using [`OsStr`]s instead.
+    /// [`Arg::default_missing_value`]: ./struct.Arg.html#method.default_missing_value
+    /// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+    pub fn default_missing_value_os(mut self, val: &'a OsStr) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.default_missing_val = Some(val);
+        self
+    }
+
+    /// Specifies a closure which lazily computes the value of the argument when it's *not*
+    /// specified at runtime. This is useful for defaults which are expensive or time sensitive to
+    /// compute (such as the current directory, or a timestamp), since the closure is only called
+    /// when the default is actually needed.
+    ///
+    /// The computed value is checked against [`Arg::possible_values`] and [`Arg::validator`]
+    /// exactly like a [`Arg::default_value`] would be.
+    ///
+    /// **NOTE:** A value from [`Arg::env`] still takes precedence over the closure, and if
+    /// [`Arg::default_value`] is also set the closure is used instead of the static value.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ffi::{OsStr, OsString};
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("dir")
+    ///         .long("dir")
+    ///         .default_value_fn(|| OsString::from("computed")))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("dir"), Some("computed"));
+    /// assert_eq!(m.occurrences_of("dir"), 0);
+    /// ```
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn default_value_fn<F>(mut self, f: F) -> Self
+        where F: Fn() -> OsString + 'static
+    {
+        self.setb(ArgSettings::TakesValue);
+        self.default_fn = Some(Rc::new(f));
+        self
+    }
+
+    /// Specifies the value of the argument when it's *not* specified at runtime, but only if
+    /// another argument was used, or was used with a specific value. When `val` is `None` the
+    /// condition holds if the other argument was used at all, otherwise it only holds if one of
+    /// the other argument's values is `val`.
+    ///
+    /// Calling this method several times adds more conditions, which are checked in the order
+    /// they were added, the first one to hold providing the default. See
+    /// [`Arg::default_value_ifs`] to add several of them at once.
+    ///
+    /// **NOTE:** Conditions are only checked against the arguments used at runtime, *not* against
+    /// the default or environment values of other arguments.
+    ///
+    /// **NOTE:** A value from [`Arg::env`] takes precedence over conditional defaults, which in
+    /// turn take precedence over [`Arg::default_value_fn`] and [`Arg::default_value`].
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("format")
+    ///         .long("format")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("output")
+    ///         .long("output")
+    ///         .default_value_if("format", Some("json"), "report.json")
+    ///         .default_value("report.txt"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--format", "json"]);
+    /// assert_eq!(m.value_of("output"), Some("report.json"));
+    ///
+    /// let m = app.get_matches_from(vec!["prog", "--format", "csv"]);
+    /// assert_eq!(m.value_of("output"), Some("report.txt"));
+    /// ```
+    /// [`Arg::default_value_ifs`]: ./struct.Arg.html#method.default_value_ifs
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`Arg::default_value_fn`]: ./struct.Arg.html#method.default_value_fn
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn default_value_if(mut self,
+                            arg: &'a str,
+                            val: Option<&'a str>,
+                            default: &'a str)
+                            -> Self {
+        self.setb(ArgSettings::TakesValue);
+        let cond = (arg, val.map(OsStr::new), OsStr::new(default));
+        if let Some(ref mut vec) = self.default_vals_ifs {
+            vec.push(cond);
+        } else {
+            self.default_vals_ifs = Some(vec![cond]);
+        }
+        self
+    }
+
+    /// Specifies several conditional defaults at once, each as a tuple of the other argument's
+    /// name, the value it must have (or `None` if it only needs to be used), and the default. The
+    /// conditions are checked in order, and the first one to hold provides the default. If none
+    /// of them holds, the [`Arg::default_value`] is used, if any.
+    ///
+    /// See [`Arg::default_value_if`] for the details of how conditions are checked.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("quiet")
+    ///         .long("quiet"))
+    ///     .arg(Arg::with_name("format")
+    ///         .long("format")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("output")
+    ///         .long("output")
+    ///         .default_value_ifs(&[
+    ///             ("quiet", None, "stdout"),
+    ///             ("format", Some("json"), "report.json"),
+    ///         ])
+    ///         .default_value("report.txt"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--quiet", "--format", "json"]);
+    /// assert_eq!(m.value_of("output"), Some("stdout"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--format", "json"]);
+    /// assert_eq!(m.value_of("output"), Some("report.json"));
+    ///
+    /// let m = app.get_matches_from(vec!["prog"]);
+    /// assert_eq!(m.value_of("output"), Some("report.txt"));
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn default_value_ifs(mut self, ifs: &[(&'a str, Option<&'a str>, &'a str)]) -> Self {
+        for &(arg, val, default) in ifs {
+            self = self.default_value_if(arg, val, default);
+        }
+        self
+    }
+
+    /// Specifies a closure which computes the values a completion script should offer for the
+    /// argument at the time the user presses `<TAB>`. This is useful for values only known at
+    /// runtime, such as the names of remote hosts or of existing branches.
+    ///
+    /// The scripts generated by [`App::gen_completions`] and [`App::gen_completions_to`] get
+    /// these values by running the program again as
+    /// `<bin> --generate-completion-values [subcommand...] <arg>`. When the
+    /// `--generate-completion-values` flag is the first argument, and the app has an argument
+    /// with a completion hook, the hook is called and nothing else is parsed. A
+    /// [`ErrorKind::CompletionValuesDisplayed`] error is returned, and exiting with it prints
+    /// the values to `stdout`, one per line.
+    ///
+    /// **NOTE:** Completion scripts only call the hook for [options]. The hook of a
+    /// [positional argument] can still be called through the flag.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind, SubCommand};
+    /// let app = App::new("prog")
+    ///     .subcommand(SubCommand::with_name("connect")
+    ///         .arg(Arg::with_name("host")
+    ///             .long("host")
+    ///             .completion(|| vec!["alpha".to_owned(), "beta".to_owned()])));
+    ///
+    /// let err = app.get_matches_from_safe(vec![
+    ///         "prog", "--generate-completion-values", "connect", "host"
+    ///     ])
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.kind, ErrorKind::CompletionValuesDisplayed);
+    /// assert_eq!(err.message, "alpha\nbeta");
+    /// ```
+    /// [`App::gen_completions`]: ./struct.App.html#method.gen_completions
+    /// [`App::gen_completions_to`]: ./struct.App.html#method.gen_completions_to
+    /// [`ErrorKind::CompletionValuesDisplayed`]: ./enum.ErrorKind.html#variant.CompletionValuesDisplayed
+    /// [options]: ./struct.Arg.html#method.takes_value
+    /// [positional argument]: ./struct.Arg.html#method.index
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn completion<F>(mut self, f: F) -> Self
+        where F: Fn() -> Vec<String> + 'static
+    {
+        self.setb(ArgSettings::TakesValue);
+        self.completion_fn = Some(Rc::new(f));
+        self
+    }
+
+    /// Specifies an environment variable to read the value of the argument from when it's *not*
+    /// specified at runtime. An environment variable takes precedence over a
+    /// [`Arg::default_value`], but a value given at runtime always wins. Values read from the
+    /// environment are checked against [`Arg::possible_values`] and [`Arg::validator`] just like
+    /// values given by the user, and an invalid value results in an error naming the variable.
+    ///
+    /// This works the same for the arguments of [`SubCommand`]s as it does at the top level, the
+    /// value simply ends up in the sub-[`ArgMatches`] of the subcommand.
+    ///
+    /// **NOTE:** An environment variable which is set, but empty, is treated as if it weren't
+    /// set at all.
+    ///
+    /// **NOTE:** If the argument uses a value delimiter (see [`Arg::use_delimiter`] and
+    /// [`Arg::value_delimiter`]), the value of the variable is split into multiple values exactly
+    /// like a value given at runtime. This allows `PATH`-like variables such as `a:b:c` to be used
+    /// with [`Arg::value_delimiter(":")`].
+    ///
+    /// **NOTE:** Just like with [`Arg::default_value`], [`ArgMatches::occurrences_of`] will return
+    /// `0` for a value read from the environment.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    /// env::set_var("MY_FLAG", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG"))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("env"));
+    /// assert_eq!(m.occurrences_of("flag"), 0);
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`ArgMatches::occurrences_of`]: ./struct.ArgMatches.html#method.occurrences_of
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::use_delimiter`]: ./struct.Arg.html#method.use_delimiter
+    /// [`Arg::value_delimiter`]: ./struct.Arg.html#method.value_delimiter
+    /// [`Arg::value_delimiter(":")`]: ./struct.Arg.html#method.value_delimiter
+    pub fn env(self, name: &'a str) -> Self {
+        self.env_os(OsStr::new(name))
+    }
+
+    /// Specifies an environment variable to read the value of the argument from, the same as
+    /// [`Arg::env`], but for a variable whose name isn't valid UTF-8. The *value* of the variable
+    /// may never need to be UTF-8 with either method, since it is read as an [`OsString`] and can
+    /// be retrieved with [`ArgMatches::value_of_os`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use std::ffi::OsStr;
+    /// # use clap::{App, Arg};
+    /// env::set_var("MY_PATH", "/usr/lib");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("path")
+    ///         .long("path")
+    ///         .env_os(OsStr::new("MY_PATH")))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of_os("path"), Some(OsStr::new("/usr/lib")));
+    /// ```
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`OsString`]: https://doc.rust-lang.org/std/ffi/struct.OsString.html
+    /// [`ArgMatches::value_of_os`]: ./struct.ArgMatches.html#method.value_of_os
+    pub fn env_os(mut self, name: &'a OsStr) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.env = Some(name);
+        self
+    }
+
     /// When set to `true` the help string will be displayed on the line after the argument and
     /// indented once. This can be helpful for arguments with very long or complex help messages.
     /// This can also be helpful for arguments with very long flag names, or many/long value names.
@@ -2414,6 +3351,10 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// **NOTE:** This setting is ignored for [positional arguments] which are always displayed in
     /// [index] order.
     ///
+    /// **NOTE:** When used along with [`Arg::help_heading`] the ordering only applies *within*
+    /// each heading. The headings themselves are always displayed in the order they were first
+    /// used.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -2458,11 +3399,118 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// ```
     /// [positional arguments]: ./struct.Arg.html#method.index
     /// [index]: ./struct.Arg.html#method.index
+    /// [`Arg::help_heading`]: ./struct.Arg.html#method.help_heading
     pub fn display_order(mut self, ord: usize) -> Self {
         self.disp_ord = ord;
         self
     }
 
+    /// Displays this argument in its own section of the help message under the given heading,
+    /// instead of under `FLAGS:` or `OPTIONS:`.
+    ///
+    /// Headings are displayed after the `FLAGS:` and `OPTIONS:` sections in the order they were
+    /// first used by an argument. Within a heading, arguments are sorted by their
+    /// [`Arg::display_order`] and then alphabetically, exactly as they would be in the default
+    /// sections.
+    ///
+    /// **NOTE:** This setting is ignored for [positional arguments].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("host")
+    ///         .long("host")
+    ///         .takes_value(true)
+    ///         .help_heading("NETWORK")
+    ///         .help("The host to connect to"))
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .help_heading("NETWORK")
+    ///         .display_order(1)
+    ///         .help("The port to connect to"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--help"
+    ///     ]);
+    /// ```
+    ///
+    /// The above example displays the following help message
+    ///
+    /// ```notrust
+    /// prog
+    ///
+    /// USAGE:
+    ///     prog [FLAGS] [OPTIONS]
+    ///
+    /// FLAGS:
+    ///     -h, --help       Prints help information
+    ///     -V, --version    Prints version information
+    ///
+    /// NETWORK:
+    ///         --port <port>    The port to connect to
+    ///         --host <host>    The host to connect to
+    /// ```
+    /// [`Arg::display_order`]: ./struct.Arg.html#method.display_order
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    pub fn help_heading(mut self, heading: &'b str) -> Self {
+        self.help_heading = Some(heading);
+        self
+    }
+
+    /// Get the name of the argument
+    pub fn get_name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Get the short version of the argument, without the preceding `-`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// let arg = Arg::with_name("config").short("c");
+    ///
+    /// assert_eq!(arg.get_short(), Some('c'));
+    /// assert_eq!(Arg::with_name("input").get_short(), None);
+    /// ```
+    pub fn get_short(&self) -> Option<char> {
+        self.short
+    }
+
+    /// Get the long version of the argument, without the preceding `--`
+    pub fn get_long(&self) -> Option<&'b str> {
+        self.long
+    }
+
+    /// Get the help message of the argument, as set by [`Arg::help`]
+    /// [`Arg::help`]: ./struct.Arg.html#method.help
+    pub fn get_help(&self) -> Option<&'b str> {
+        self.help
+    }
+
+    /// Get the names of the argument's values, as set by [`Arg::value_names`] or
+    /// [`Arg::value_name`]
+    /// [`Arg::value_names`]: ./struct.Arg.html#method.value_names
+    /// [`Arg::value_name`]: ./struct.Arg.html#method.value_name
+    pub fn get_value_names(&self) -> Option<Vec<&'b str>> {
+        self.val_names.as_ref().map(|vn| vn.values().cloned().collect())
+    }
+
+    /// Checks if the argument is required, as set by [`Arg::required`]
+    /// [`Arg::required`]: ./struct.Arg.html#method.required
+    pub fn is_required(&self) -> bool {
+        self.is_set(ArgSettings::Required)
+    }
+
+    /// Checks if the argument may be used, or take values, multiple times, as set by
+    /// [`Arg::multiple`]
+    /// [`Arg::multiple`]: ./struct.Arg.html#method.multiple
+    pub fn is_multiple(&self) -> bool {
+        self.is_set(ArgSettings::Multiple)
+    }
+
     /// Checks if one of the [`ArgSettings`] settings is set for the argument
     /// [`ArgSettings`]: ./enum.ArgSettings.html
     pub fn is_set(&self, s: ArgSettings) -> bool {
@@ -2502,6 +3550,8 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for Arg<'a, 'b> {
             long: a.long,
             aliases: a.aliases.clone(),
             help: a.help,
+            long_help: a.long_help,
+            max_occurs: a.max_occurs,
             index: a.index,
             possible_vals: a.possible_vals.clone(),
             blacklist: a.blacklist.clone(),
@@ -2512,12 +3562,20 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for Arg<'a, 'b> {
             val_names: a.val_names.clone(),
             group: a.group.clone(),
             validator: a.validator.clone(),
+            value_parser: a.value_parser.clone(),
             overrides: a.overrides.clone(),
             settings: a.settings,
             val_delim: a.val_delim,
             default_val: a.default_val,
+            default_missing_val: a.default_missing_val,
             disp_ord: a.disp_ord,
+            help_heading: a.help_heading,
             r_unless: a.r_unless.clone(),
+            r_ifs: a.r_ifs.clone(),
+            env: a.env,
+            default_fn: a.default_fn.clone(),
+            default_vals_ifs: a.default_vals_ifs.clone(),
+            completion_fn: a.completion_fn.clone(),
         }
     }
 }
@@ -2530,6 +3588,8 @@ impl<'a, 'b> Clone for Arg<'a, 'b> {
             long: self.long,
             aliases: self.aliases.clone(),
             help: self.help,
+            long_help: self.long_help,
+            max_occurs: self.max_occurs,
             index: self.index,
             possible_vals: self.possible_vals.clone(),
             blacklist: self.blacklist.clone(),
@@ -2540,12 +3600,20 @@ impl<'a, 'b> Clone for Arg<'a, 'b> {
             val_names: self.val_names.clone(),
             group: self.group.clone(),
             validator: self.validator.clone(),
+            value_parser: self.value_parser.clone(),
             overrides: self.overrides.clone(),
             settings: self.settings,
             val_delim: self.val_delim,
             default_val: self.default_val,
+            default_missing_val: self.default_missing_val,
             disp_ord: self.disp_ord,
+            help_heading: self.help_heading,
             r_unless: self.r_unless.clone(),
+            r_ifs: self.r_ifs.clone(),
+            env: self.env,
+            default_fn: self.default_fn.clone(),
+            default_vals_ifs: self.default_vals_ifs.clone(),
+            completion_fn: self.completion_fn.clone(),
         }
     }
 }
@@ -1,5 +1,6 @@
 // Std
 use std::convert::From;
+use std::ffi::OsStr;
 use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
 use std::result::Result as StdResult;
@@ -9,7 +10,7 @@ use vec_map::VecMap;
 
 // Internal
 use Arg;
-use args::{AnyArg, DispOrder};
+use args::{AnyArg, DispOrder, ValueParser};
 use args::settings::{ArgFlags, ArgSettings};
 
 #[derive(Debug)]
@@ -19,12 +20,15 @@ pub struct FlagBuilder<'n, 'e> {
     pub long: Option<&'e str>,
     pub aliases: Option<Vec<(&'e str, bool)>>,
     pub help: Option<&'e str>,
+    pub long_help: Option<&'e str>,
+    pub max_occurs: Option<u64>,
     pub blacklist: Option<Vec<&'e str>>,
     pub requires: Option<Vec<&'e str>>,
     pub short: Option<char>,
     pub overrides: Option<Vec<&'e str>>,
     pub settings: ArgFlags,
     pub disp_ord: usize,
+    pub help_heading: Option<&'e str>,
     pub unified_ord: usize,
 }
 
@@ -35,12 +39,15 @@ impl<'n, 'e> Default for FlagBuilder<'n, 'e> {
             long: None,
             aliases: None,
             help: None,
+            long_help: None,
+            max_occurs: None,
             blacklist: None,
             requires: None,
             short: None,
             overrides: None,
             settings: ArgFlags::new(),
             disp_ord: 999,
+            help_heading: None,
             unified_ord: 999,
         }
     }
@@ -58,6 +65,14 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for FlagBuilder<'a, 'b> {
                 format!("The argument '{}' has a validator set, yet was parsed as a flag. Ensure \
                 .takes_value(true) or .index(u64) is set.",
                         a.name));
+        assert!(a.value_parser.is_none(),
+                "The argument '{}' has a value parser set, yet was parsed as a flag. Ensure \
+                .takes_value(true) or .index(u64) is set.",
+                a.name);
+        assert!(a.completion_fn.is_none(),
+                "The argument '{}' has a completion hook set, yet was parsed as a flag. Ensure \
+                .takes_value(true) or .index(u64) is set.",
+                a.name);
         assert!(a.possible_vals.is_none(),
                 format!("The argument '{}' cannot have a specific value set because it doesn't \
                 have takes_value(true) set",
@@ -74,11 +89,14 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for FlagBuilder<'a, 'b> {
             long: a.long,
             aliases: a.aliases.clone(),
             help: a.help,
+            long_help: a.long_help,
+            max_occurs: a.max_occurs,
             blacklist: a.blacklist.clone(),
             overrides: a.overrides.clone(),
             requires: a.requires.clone(),
             settings: a.settings,
             disp_ord: a.disp_ord,
+            help_heading: a.help_heading,
             ..Default::default()
         }
     }
@@ -104,11 +122,14 @@ impl<'n, 'e> Clone for FlagBuilder<'n, 'e> {
             long: self.long,
             aliases: self.aliases.clone(),
             help: self.help,
+            long_help: self.long_help,
+            max_occurs: self.max_occurs,
             blacklist: self.blacklist.clone(),
             overrides: self.overrides.clone(),
             requires: self.requires.clone(),
             settings: self.settings,
             disp_ord: self.disp_ord,
+            help_heading: self.help_heading,
             unified_ord: self.unified_ord,
         }
     }
@@ -157,6 +178,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for FlagBuilder<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         None
     }
+    fn value_parser(&self) -> Option<&ValueParser> {
+        None
+    }
     fn min_vals(&self) -> Option<u64> {
         None
     }
@@ -172,7 +196,10 @@ impl<'n, 'e> AnyArg<'n, 'e> for FlagBuilder<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.help
     }
-    fn default_val(&self) -> Option<&'n str> {
+    fn long_help(&self) -> Option<&'e str> {
+        self.long_help
+    }
+    fn default_val(&self) -> Option<&'n OsStr> {
         None
     }
     fn longest_filter(&self) -> bool {
@@ -1,4 +1,5 @@
 // Std
+use std::ffi::{OsStr, OsString};
 use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
 use std::result::Result as StdResult;
@@ -7,7 +8,7 @@ use std::result::Result as StdResult;
 use vec_map::VecMap;
 
 // Internal
-use args::{AnyArg, Arg, DispOrder};
+use args::{AnyArg, Arg, DispOrder, ValueParser};
 use args::settings::{ArgFlags, ArgSettings};
 
 #[allow(missing_debug_implementations)]
@@ -18,6 +19,8 @@ pub struct OptBuilder<'n, 'e> {
     pub long: Option<&'e str>,
     pub aliases: Option<Vec<(&'e str, bool)>>,
     pub help: Option<&'e str>,
+    pub long_help: Option<&'e str>,
+    pub max_occurs: Option<u64>,
     pub blacklist: Option<Vec<&'e str>>,
     pub possible_vals: Option<Vec<&'e str>>,
     pub requires: Option<Vec<&'e str>>,
@@ -26,13 +29,21 @@ pub struct OptBuilder<'n, 'e> {
     pub max_vals: Option<u64>,
     pub val_names: Option<VecMap<&'e str>>,
     pub validator: Option<Rc<Fn(String) -> StdResult<(), String>>>,
+    pub value_parser: Option<ValueParser>,
     pub overrides: Option<Vec<&'e str>>,
     pub settings: ArgFlags,
     pub val_delim: Option<char>,
-    pub default_val: Option<&'n str>,
+    pub default_val: Option<&'n OsStr>,
+    pub default_missing_val: Option<&'n OsStr>,
     pub disp_ord: usize,
+    pub help_heading: Option<&'e str>,
     pub unified_ord: usize,
     pub r_unless: Option<Vec<&'e str>>,
+    pub r_ifs: Option<Vec<(&'e str, &'e str)>>,
+    pub env: Option<&'n OsStr>,
+    pub default_fn: Option<Rc<Fn() -> OsString>>,
+    pub default_vals_ifs: Option<Vec<(&'n str, Option<&'n OsStr>, &'n OsStr)>>,
+    pub completion_fn: Option<Rc<Fn() -> Vec<String>>>,
 }
 
 impl<'n, 'e> Default for OptBuilder<'n, 'e> {
@@ -43,6 +54,8 @@ impl<'n, 'e> Default for OptBuilder<'n, 'e> {
             long: None,
             aliases: None,
             help: None,
+            long_help: None,
+            max_occurs: None,
             blacklist: None,
             possible_vals: None,
             requires: None,
@@ -51,13 +64,21 @@ impl<'n, 'e> Default for OptBuilder<'n, 'e> {
             max_vals: None,
             val_names: None,
             validator: None,
+            value_parser: None,
             overrides: None,
             settings: ArgFlags::new(),
             val_delim: Some(','),
             default_val: None,
+            default_missing_val: None,
             disp_ord: 999,
+            help_heading: None,
             unified_ord: 999,
             r_unless: None,
+            r_ifs: None,
+            env: None,
+            default_fn: None,
+            default_vals_ifs: None,
+            completion_fn: None,
         }
     }
 }
@@ -80,6 +101,8 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
             long: a.long,
             aliases: a.aliases.clone(),
             help: a.help,
+            long_help: a.long_help,
+            max_occurs: a.max_occurs,
             num_vals: a.num_vals,
             min_vals: a.min_vals,
             max_vals: a.max_vals,
@@ -91,8 +114,15 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
             possible_vals: a.possible_vals.clone(),
             settings: a.settings,
             default_val: a.default_val,
+            default_missing_val: a.default_missing_val,
             disp_ord: a.disp_ord,
+            help_heading: a.help_heading,
             r_unless: a.r_unless.clone(),
+            r_ifs: a.r_ifs.clone(),
+            env: a.env,
+            default_fn: a.default_fn.clone(),
+            default_vals_ifs: a.default_vals_ifs.clone(),
+            completion_fn: a.completion_fn.clone(),
             ..Default::default()
         };
         if let Some(ref vec) = ob.val_names {
@@ -100,14 +130,12 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
                 ob.num_vals = Some(vec.len() as u64);
             }
         }
-        if let Some(ref vec) = ob.val_names {
-            if vec.len() > 1 {
-                ob.num_vals = Some(vec.len() as u64);
-            }
-        }
         if let Some(ref p) = a.validator {
             ob.validator = Some(p.clone());
         }
+        if let Some(ref p) = a.value_parser {
+            ob.value_parser = Some(p.clone());
+        }
         // If the arg is required, add all it's requirements to master required list
         if a.is_set(ArgSettings::Required) {
             if let Some(ref areqs) = a.requires {
@@ -123,11 +151,12 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
 impl<'n, 'e> Display for OptBuilder<'n, 'e> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         debugln!("fn=fmt");
-        // Write the name such --long or -l
+        // Write the name such --long or -l, followed by what separates it from the values
+        let sep = if self.is_set(ArgSettings::RequireEquals) { "=" } else { " " };
         if let Some(l) = self.long {
-            try!(write!(f, "--{} ", l));
+            try!(write!(f, "--{}{}", l, sep));
         } else {
-            try!(write!(f, "-{} ", self.short.unwrap()));
+            try!(write!(f, "-{}{}", self.short.unwrap(), sep));
         }
 
         // Write the values such as <name1> <name2>
@@ -174,11 +203,14 @@ impl<'n, 'e> Clone for OptBuilder<'n, 'e> {
             long: self.long,
             aliases: self.aliases.clone(),
             help: self.help,
+            long_help: self.long_help,
+            max_occurs: self.max_occurs,
             blacklist: self.blacklist.clone(),
             overrides: self.overrides.clone(),
             requires: self.requires.clone(),
             settings: self.settings,
             disp_ord: self.disp_ord,
+            help_heading: self.help_heading,
             unified_ord: self.unified_ord,
             num_vals: self.num_vals,
             min_vals: self.min_vals,
@@ -187,8 +219,15 @@ impl<'n, 'e> Clone for OptBuilder<'n, 'e> {
             val_delim: self.val_delim,
             possible_vals: self.possible_vals.clone(),
             default_val: self.default_val,
+            default_missing_val: self.default_missing_val,
             validator: self.validator.clone(),
+            value_parser: self.value_parser.clone(),
             r_unless: self.r_unless.clone(),
+            r_ifs: self.r_ifs.clone(),
+            env: self.env,
+            default_fn: self.default_fn.clone(),
+            default_vals_ifs: self.default_vals_ifs.clone(),
+            completion_fn: self.completion_fn.clone(),
         }
     }
 }
@@ -233,6 +272,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for OptBuilder<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         self.validator.as_ref()
     }
+    fn value_parser(&self) -> Option<&ValueParser> {
+        self.value_parser.as_ref()
+    }
     fn min_vals(&self) -> Option<u64> {
         self.min_vals
     }
@@ -251,7 +293,10 @@ impl<'n, 'e> AnyArg<'n, 'e> for OptBuilder<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.help
     }
-    fn default_val(&self) -> Option<&'n str> {
+    fn long_help(&self) -> Option<&'e str> {
+        self.long_help
+    }
+    fn default_val(&self) -> Option<&'n OsStr> {
         self.default_val
     }
     fn longest_filter(&self) -> bool {
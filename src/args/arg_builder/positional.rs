@@ -1,5 +1,6 @@
 // Std
 use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
 use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
 use std::result::Result as StdResult;
@@ -9,7 +10,7 @@ use vec_map::VecMap;
 
 // Internal
 use Arg;
-use args::{AnyArg, DispOrder};
+use args::{AnyArg, DispOrder, ValueParser};
 use args::settings::{ArgFlags, ArgSettings};
 
 #[allow(missing_debug_implementations)]
@@ -17,6 +18,8 @@ use args::settings::{ArgFlags, ArgSettings};
 pub struct PosBuilder<'n, 'e> {
     pub name: &'n str,
     pub help: Option<&'e str>,
+    pub long_help: Option<&'e str>,
+    pub max_occurs: Option<u64>,
     pub requires: Option<Vec<&'e str>>,
     pub blacklist: Option<Vec<&'e str>>,
     pub possible_vals: Option<Vec<&'e str>>,
@@ -26,12 +29,18 @@ pub struct PosBuilder<'n, 'e> {
     pub min_vals: Option<u64>,
     pub val_names: Option<VecMap<&'e str>>,
     pub validator: Option<Rc<Fn(String) -> StdResult<(), String>>>,
+    pub value_parser: Option<ValueParser>,
     pub overrides: Option<Vec<&'e str>>,
     pub settings: ArgFlags,
     pub val_delim: Option<char>,
-    pub default_val: Option<&'n str>,
+    pub default_val: Option<&'n OsStr>,
     pub disp_ord: usize,
     pub r_unless: Option<Vec<&'e str>>,
+    pub r_ifs: Option<Vec<(&'e str, &'e str)>>,
+    pub env: Option<&'n OsStr>,
+    pub default_fn: Option<Rc<Fn() -> OsString>>,
+    pub default_vals_ifs: Option<Vec<(&'n str, Option<&'n OsStr>, &'n OsStr)>>,
+    pub completion_fn: Option<Rc<Fn() -> Vec<String>>>,
 }
 
 impl<'n, 'e> Default for PosBuilder<'n, 'e> {
@@ -39,6 +48,8 @@ impl<'n, 'e> Default for PosBuilder<'n, 'e> {
         PosBuilder {
             name: "",
             help: None,
+            long_help: None,
+            max_occurs: None,
             requires: None,
             blacklist: None,
             possible_vals: None,
@@ -48,12 +59,18 @@ impl<'n, 'e> Default for PosBuilder<'n, 'e> {
             max_vals: None,
             val_names: None,
             validator: None,
+            value_parser: None,
             overrides: None,
             settings: ArgFlags::new(),
             val_delim: Some(','),
             default_val: None,
             disp_ord: 999,
             r_unless: None,
+            r_ifs: None,
+            env: None,
+            default_fn: None,
+            default_vals_ifs: None,
+            completion_fn: None,
         }
     }
 }
@@ -88,11 +105,18 @@ impl<'n, 'e> PosBuilder<'n, 'e> {
             requires: a.requires.clone(),
             possible_vals: a.possible_vals.clone(),
             help: a.help,
+            long_help: a.long_help,
+            max_occurs: a.max_occurs,
             val_delim: a.val_delim,
             settings: a.settings,
             default_val: a.default_val,
             disp_ord: a.disp_ord,
             r_unless: a.r_unless.clone(),
+            r_ifs: a.r_ifs.clone(),
+            env: a.env,
+            default_fn: a.default_fn.clone(),
+            default_vals_ifs: a.default_vals_ifs.clone(),
+            completion_fn: a.completion_fn.clone(),
             ..Default::default()
         };
         if a.max_vals.is_some() || a.min_vals.is_some() ||
@@ -102,6 +126,9 @@ impl<'n, 'e> PosBuilder<'n, 'e> {
         if let Some(ref p) = a.validator {
             pb.validator = Some(p.clone());
         }
+        if let Some(ref p) = a.value_parser {
+            pb.value_parser = Some(p.clone());
+        }
         // If the arg is required, add all it's requirements to master required list
         if a.is_set(ArgSettings::Required) {
             if let Some(ref areqs) = a.requires {
@@ -158,6 +185,8 @@ impl<'n, 'e> Clone for PosBuilder<'n, 'e> {
         PosBuilder {
             name: self.name,
             help: self.help,
+            long_help: self.long_help,
+            max_occurs: self.max_occurs,
             blacklist: self.blacklist.clone(),
             overrides: self.overrides.clone(),
             requires: self.requires.clone(),
@@ -171,8 +200,14 @@ impl<'n, 'e> Clone for PosBuilder<'n, 'e> {
             possible_vals: self.possible_vals.clone(),
             default_val: self.default_val,
             validator: self.validator.clone(),
+            value_parser: self.value_parser.clone(),
             r_unless: self.r_unless.clone(),
             index: self.index,
+            r_ifs: self.r_ifs.clone(),
+            env: self.env,
+            default_fn: self.default_fn.clone(),
+            default_vals_ifs: self.default_vals_ifs.clone(),
+            completion_fn: self.completion_fn.clone(),
         }
     }
 }
@@ -217,6 +252,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for PosBuilder<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         self.validator.as_ref()
     }
+    fn value_parser(&self) -> Option<&ValueParser> {
+        self.value_parser.as_ref()
+    }
     fn min_vals(&self) -> Option<u64> {
         self.min_vals
     }
@@ -235,7 +273,10 @@ impl<'n, 'e> AnyArg<'n, 'e> for PosBuilder<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.help
     }
-    fn default_val(&self) -> Option<&'n str> {
+    fn long_help(&self) -> Option<&'e str> {
+        self.long_help
+    }
+    fn default_val(&self) -> Option<&'n OsStr> {
         self.default_val
     }
     fn longest_filter(&self) -> bool {
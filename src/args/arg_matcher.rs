@@ -1,15 +1,20 @@
 // Std
+use std::any::Any;
+use std::collections::HashMap;
 use std::collections::hash_map::{Entry, Iter};
 use std::ffi::OsStr;
+use std::mem;
 use std::ops::Deref;
+use std::sync::Arc;
 
 // Third Party
 use vec_map::VecMap;
 
 // Internal
-use args::{ArgMatches, MatchedArg, SubCommand};
+use args::{ArgMatches, MatchedArg, SubCommand, ValueSource};
 use args::AnyArg;
 use args::settings::ArgSettings;
+use fmt::ColorWhen;
 
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
@@ -60,6 +65,10 @@ impl<'a> ArgMatcher<'a> {
         self.0.usage = Some(usage);
     }
 
+    pub fn color(&mut self, color: ColorWhen) {
+        self.0.color = color;
+    }
+
     pub fn arg_names(&'a self) -> Vec<&'a str> {
         self.0.args.keys().map(Deref::deref).collect()
     }
@@ -68,6 +77,35 @@ impl<'a> ArgMatcher<'a> {
         self.0.args.entry(arg)
     }
 
+    // Passes the values of global args down the subcommand chain, but never up. A value which was
+    // actually used in a subcommand wins over the one of its parent, otherwise the parent's value
+    // is used.
+    pub fn propagate_globals(&mut self, global_args: &[&'a str]) {
+        debugln!("fn=propagate_globals;global_args={:?}", global_args);
+        self.fill_in_global_values(global_args, HashMap::new());
+    }
+
+    fn fill_in_global_values(&mut self,
+                             global_args: &[&'a str],
+                             mut vals: HashMap<&'a str, MatchedArg>) {
+        for &name in global_args {
+            if let Some(ma) = self.get(name) {
+                let keep_parent = vals.get(name).map_or(false, |p| p.occurs > 0 && ma.occurs == 0);
+                if !keep_parent {
+                    vals.insert(name, ma.clone());
+                }
+            }
+        }
+        for (&name, ma) in vals.iter() {
+            self.0.args.insert(name, ma.clone());
+        }
+        if let Some(ref mut sc) = self.0.subcommand {
+            let mut am = ArgMatcher(mem::replace(&mut sc.matches, ArgMatches::new()));
+            am.fill_in_global_values(global_args, vals);
+            mem::swap(&mut am.0, &mut sc.matches);
+        }
+    }
+
     pub fn subcommand(&mut self, sc: SubCommand<'a>) {
         self.0.subcommand = Some(Box::new(sc));
     }
@@ -99,11 +137,51 @@ impl<'a> ArgMatcher<'a> {
         let ma = self.entry(arg).or_insert(MatchedArg {
             occurs: 0,
             vals: VecMap::new(),
+            raw_vals: VecMap::new(),
+            typed_vals: None,
+            source: ValueSource::CommandLine,
         });
         let len = ma.vals.len() + 1;
         ma.vals.insert(len, val.to_owned());
     }
 
+    pub fn add_raw_val_to(&mut self, arg: &'a str, val: &OsStr) {
+        let ma = self.entry(arg).or_insert(MatchedArg {
+            occurs: 0,
+            vals: VecMap::new(),
+            raw_vals: VecMap::new(),
+            typed_vals: None,
+            source: ValueSource::CommandLine,
+        });
+        let len = ma.raw_vals.len() + 1;
+        ma.raw_vals.insert(len, val.to_owned());
+    }
+
+    pub fn set_source(&mut self, arg: &str, source: ValueSource) {
+        if let Some(ma) = self.get_mut(arg) {
+            ma.source = source;
+        }
+    }
+
+    pub fn init_typed_vals_of(&mut self, arg: &str) {
+        if let Some(ma) = self.get_mut(arg) {
+            if ma.typed_vals.is_none() {
+                ma.typed_vals = Some(vec![]);
+            }
+        }
+    }
+
+    pub fn add_typed_val_to(&mut self, arg: &'a str, val: Arc<Any + Send + Sync>) {
+        if let Some(ma) = self.get_mut(arg) {
+            if ma.typed_vals.is_none() {
+                ma.typed_vals = Some(vec![]);
+            }
+            if let Some(ref mut vals) = ma.typed_vals {
+                vals.push(val);
+            }
+        }
+    }
+
     pub fn needs_more_vals<'b, A>(&self, o: &A) -> bool
         where A: AnyArg<'a, 'b>
     {
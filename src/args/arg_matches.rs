@@ -1,17 +1,22 @@
 // Std
+use std::any::Any;
 use std::borrow::Cow;
+use std::cmp;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
 use std::iter::Map;
 use std::slice;
+use std::u8;
 
 // Third Party
 use vec_map;
 
 // Internal
 use INVALID_UTF8;
-use args::MatchedArg;
+use args::{MatchedArg, ValueSource};
 use args::SubCommand;
+use errors::{Error, Result as ClapResult};
+use fmt::ColorWhen;
 
 /// Used to get information about the arguments that where supplied to the program at runtime by
 /// the user. New instances of this struct are obtained by using the [`App::get_matches`] family of
@@ -68,6 +73,14 @@ pub struct ArgMatches<'a> {
     pub subcommand: Option<Box<SubCommand<'a>>>,
     #[doc(hidden)]
     pub usage: Option<String>,
+    #[doc(hidden)]
+    pub color: ColorWhen,
+    #[doc(hidden)]
+    pub reqs_satisfied: Vec<(String, String)>,
+    #[doc(hidden)]
+    pub groups: HashMap<&'a str, Vec<&'a str>>,
+    #[doc(hidden)]
+    pub possible_vals: HashMap<&'a str, Vec<String>>,
 }
 
 impl<'a> Default for ArgMatches<'a> {
@@ -76,6 +89,10 @@ impl<'a> Default for ArgMatches<'a> {
             args: HashMap::new(),
             subcommand: None,
             usage: None,
+            color: ColorWhen::Auto,
+            reqs_satisfied: vec![],
+            groups: HashMap::new(),
+            possible_vals: HashMap::new(),
         }
     }
 }
@@ -122,6 +139,65 @@ impl<'a> ArgMatches<'a> {
         None
     }
 
+    /// Gets the value of an argument which the program's own logic requires, or a
+    /// [`MissingRequiredArgument`] error formatted just like the errors `clap` produces itself.
+    /// This is useful when an argument is only required in situations `clap` can't express
+    /// declaratively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("output")
+    ///         .takes_value(true)
+    ///         .long("output"))
+    ///     .get_matches_from(vec!["myprog"]);
+    ///
+    /// let err = m.value_of_or_err("output").unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`MissingRequiredArgument`]: ./enum.ErrorKind.html#variant.MissingRequiredArgument
+    pub fn value_of_or_err<S: AsRef<str>>(&self, name: S) -> ClapResult<&str> {
+        let name = name.as_ref();
+        self.value_of(name).ok_or_else(|| {
+            let mut err = Error::missing_required_argument(format!("\n    {}", name),
+                                                           self.usage(),
+                                                           self.color);
+            err.info = Some(vec![name.to_owned()]);
+            err
+        })
+    }
+
+    /// Gets the value of an argument which the program's own logic requires, or prints a usage
+    /// error and exits the process (with status `2`) if it wasn't used. This is the same as
+    /// calling [`Error::exit`] on the error from [`ArgMatches::value_of_or_err`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("mode")
+    ///         .long("mode")
+    ///         .possible_values(&["copy", "move"])
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("dest")
+    ///         .long("dest")
+    ///         .takes_value(true))
+    ///     .get_matches();
+    ///
+    /// if m.value_of("mode") == Some("move") {
+    ///     let dest = m.value_of_or_exit("dest");
+    ///     println!("Moving to {}", dest);
+    /// }
+    /// ```
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
+    /// [`ArgMatches::value_of_or_err`]: ./struct.ArgMatches.html#method.value_of_or_err
+    pub fn value_of_or_exit<S: AsRef<str>>(&self, name: S) -> &str {
+        self.value_of_or_err(name).unwrap_or_else(|e| e.exit())
+    }
+
     /// Gets the lossy value of a specific argument. If the argument wasn't present at runtime
     /// it returns `None`. A lossy value is one which contains invalid UTF-8 code points, those
     /// invalid points will be replaced with `\u{FFFD}`
@@ -299,6 +375,124 @@ impl<'a> ArgMatches<'a> {
         None
     }
 
+    /// Gets a [`OsValues`] struct which implements [`Iterator`] for the values of a specific
+    /// argument exactly as they were given at runtime. Unlike [`ArgMatches::values_of_os`] these
+    /// are not split on [`Arg::value_delimiter`], nor changed by
+    /// [`Arg::normalize_path_separators`], so they can be passed on unchanged to another program.
+    /// If the argument wasn't present at runtime it returns `None`.
+    ///
+    /// **NOTE:** Values from [`Arg::default_value`] are returned just as they were defined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use std::ffi::OsStr;
+    ///
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("features")
+    ///         .long("features")
+    ///         .takes_value(true)
+    ///         .use_delimiter(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--features", "a,b"
+    ///     ]);
+    ///
+    /// assert_eq!(m.values_of("features").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    /// assert_eq!(m.get_raw("features").unwrap().collect::<Vec<_>>(), [OsStr::new("a,b")]);
+    /// ```
+    /// [`OsValues`]: ./struct.OsValues.html
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`ArgMatches::values_of_os`]: ./struct.ArgMatches.html#method.values_of_os
+    /// [`Arg::value_delimiter`]: ./struct.Arg.html#method.value_delimiter
+    /// [`Arg::normalize_path_separators`]: ./struct.Arg.html#method.normalize_path_separators
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    pub fn get_raw<S: AsRef<str>>(&'a self, name: S) -> Option<OsValues<'a>> {
+        fn to_str_slice(o: &OsString) -> &OsStr {
+            &*o
+        }
+        let to_str_slice: fn(&'a OsString) -> &'a OsStr = to_str_slice; // coerce to fn pointer
+        if let Some(arg) = self.args.get(name.as_ref()) {
+            return Some(OsValues { iter: arg.raw_vals.values().map(to_str_slice) });
+        }
+        None
+    }
+
+    /// Gets the value of a specific argument as converted by its [`Arg::value_parser`]. If the
+    /// argument wasn't present at runtime it returns `None`. If the argument was used multiple
+    /// times, the first value is returned.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the argument has no [`Arg::value_parser`], or if `T` isn't the
+    /// type its value parser produces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use std::path::PathBuf;
+    ///
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("output")
+    ///         .takes_value(true)
+    ///         .value_parser(|v| Ok(PathBuf::from(v))))
+    ///     .get_matches_from(vec!["myapp", "out.txt"]);
+    ///
+    /// assert_eq!(m.value_of_t::<PathBuf, _>("output"), Some(&PathBuf::from("out.txt")));
+    /// ```
+    /// [`Arg::value_parser`]: ./struct.Arg.html#method.value_parser
+    pub fn value_of_t<T: Any, S: AsRef<str>>(&self, name: S) -> Option<&T> {
+        self.values_of_t(name).and_then(|vals| vals.into_iter().next())
+    }
+
+    /// Gets all values of a specific argument as converted by its [`Arg::value_parser`]. If the
+    /// argument wasn't present at runtime it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the argument has no [`Arg::value_parser`], or if `T` isn't the
+    /// type its value parser produces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("nums")
+    ///         .multiple(true)
+    ///         .value_parser(|v| v.parse::<i32>().map_err(|e| e.to_string())))
+    ///     .get_matches_from(vec!["myapp", "1", "2", "3"]);
+    ///
+    /// let nums: Vec<i32> = m.values_of_t::<i32, _>("nums")
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .cloned()
+    ///     .collect();
+    /// assert_eq!(nums, [1, 2, 3]);
+    /// ```
+    /// [`Arg::value_parser`]: ./struct.Arg.html#method.value_parser
+    pub fn values_of_t<T: Any, S: AsRef<str>>(&self, name: S) -> Option<Vec<&T>> {
+        let name = name.as_ref();
+        if let Some(arg) = self.args.get(name) {
+            let typed_vals = arg.typed_vals.as_ref().unwrap_or_else(|| {
+                panic!("ArgMatches::values_of_t used on '{}' which has no value_parser", name)
+            });
+            return Some(typed_vals.iter()
+                .map(|v| {
+                    let v: &Any = &**v;
+                    v.downcast_ref::<T>()
+                        .unwrap_or_else(|| {
+                            panic!("ArgMatches::values_of_t used on '{}' with a type other than \
+                                    the one its value_parser produces",
+                                   name)
+                        })
+                })
+                .collect());
+        }
+        None
+    }
+
     /// Returns `true` if an argument was present at runtime, otherwise `false`.
     ///
     /// # Examples
@@ -323,6 +517,208 @@ impl<'a> ArgMatches<'a> {
         self.args.contains_key(name.as_ref())
     }
 
+    /// Returns `true` if *any* of the given arguments were present at runtime, otherwise `false`.
+    /// Checking stops at the first argument which was present.
+    ///
+    /// **NOTE:** Unlike [`ArgMatches::is_present`] only argument names are considered, *not*
+    /// the name of a [`SubCommand`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("debug").short("d"))
+    ///     .arg(Arg::with_name("verbose").short("v"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-v"
+    ///     ]);
+    ///
+    /// assert!(m.is_present_any(&["debug", "verbose"]));
+    /// assert!(!m.is_present_any(&["debug"]));
+    /// ```
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn is_present_any<S: AsRef<str>>(&self, names: &[S]) -> bool {
+        names.iter().any(|n| self.args.contains_key(n.as_ref()))
+    }
+
+    /// Returns `true` if *all* of the given arguments were present at runtime, otherwise `false`.
+    /// Checking stops at the first argument which wasn't present.
+    ///
+    /// **NOTE:** Unlike [`ArgMatches::is_present`] only argument names are considered, *not*
+    /// the name of a [`SubCommand`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("debug").short("d"))
+    ///     .arg(Arg::with_name("verbose").short("v"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-v", "-d"
+    ///     ]);
+    ///
+    /// assert!(m.is_present_all(&["debug", "verbose"]));
+    /// ```
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn is_present_all<S: AsRef<str>>(&self, names: &[S]) -> bool {
+        names.iter().all(|n| self.args.contains_key(n.as_ref()))
+    }
+
+    /// Returns `true` if a flag was present at runtime, otherwise `false`. This is the same as
+    /// [`ArgMatches::is_present`], but reads more clearly at call sites where an argument is
+    /// only ever on or off.
+    ///
+    /// **NOTE:** This is meant for flags, i.e. arguments which don't take a value. Using it on an
+    /// argument which received values is a bug in the calling code, and will `panic!` in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("debug")
+    ///         .short("d"))
+    ///     .arg(Arg::with_name("quiet")
+    ///         .short("q"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-d"
+    ///     ]);
+    ///
+    /// assert!(m.get_flag("debug"));
+    /// assert!(!m.get_flag("quiet"));
+    /// ```
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    pub fn get_flag<S: AsRef<str>>(&self, name: S) -> bool {
+        let name = name.as_ref();
+        debug_assert!(self.args.get(name).map_or(true, |a| a.vals.is_empty()),
+                      "ArgMatches::get_flag used on '{}' which takes values, use \
+                       ArgMatches::value_of instead",
+                      name);
+        self.is_present(name)
+    }
+
+    /// Returns the name of the member of an [`ArgGroup`] which was present at runtime, or `None`
+    /// if no member of the group was used (or no such group exists). This collapses checking each
+    /// member with [`ArgMatches::is_present`] into a single call.
+    ///
+    /// **NOTE:** If the group allows [`ArgGroup::multiple`] members, the first one present in the
+    /// order they were added to the group is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("json")
+    ///         .long("json"))
+    ///     .arg(Arg::with_name("yaml")
+    ///         .long("yaml"))
+    ///     .group(ArgGroup::with_name("format")
+    ///         .args(&["json", "yaml"]))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--yaml"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of_group("format"), Some("yaml"));
+    /// ```
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`ArgGroup::multiple`]: ./struct.ArgGroup.html#method.multiple
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    pub fn value_of_group<S: AsRef<str>>(&self, group: S) -> Option<&str> {
+        self.groups
+            .get(group.as_ref())
+            .and_then(|args| args.iter().find(|a| self.args.contains_key(*a)))
+            .map(|a| *a)
+    }
+
+    /// Gets the value of an argument from these matches, or from the matches of any
+    /// [`SubCommand`] which was used below them, so callers don't need to know at which level of
+    /// the subcommand chain a [global] argument was given.
+    ///
+    /// The chain is searched from the deepest [`SubCommand`] (the leaf) up to these matches, and
+    /// the first value which was actually used at runtime is returned. If the argument was never
+    /// used, the first value found in the same order (i.e. a [default value]) is returned instead.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if the value contains invalid UTF-8 code points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("config")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .global(true))
+    ///     .subcommand(SubCommand::with_name("remote")
+    ///         .subcommand(SubCommand::with_name("add")
+    ///             .arg(Arg::with_name("name"))))
+    ///     .get_matches_from(vec!["myprog", "remote", "add", "--config", "alt.toml", "origin"]);
+    ///
+    /// assert_eq!(m.value_of_global("config"), Some("alt.toml"));
+    /// assert_eq!(m.value_of_global("name"), Some("origin"));
+    /// assert_eq!(m.value_of("name"), None);
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [global]: ./struct.Arg.html#method.global
+    /// [default value]: ./struct.Arg.html#method.default_value
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn value_of_global<S: AsRef<str>>(&self, name: S) -> Option<&str> {
+        let name = name.as_ref();
+        let mut chain = vec![self];
+        let mut cur = self;
+        while let Some(ref sc) = cur.subcommand {
+            cur = &sc.matches;
+            chain.push(cur);
+        }
+        chain.iter()
+            .rev()
+            .find(|m| m.occurrences_of(name) > 0 && m.value_of(name).is_some())
+            .or_else(|| chain.iter().rev().find(|m| m.value_of(name).is_some()))
+            .map(|m| *m)
+            .and_then(|m| m.value_of(name))
+    }
+
+    /// Returns the [possible values] which were declared for an argument, or `None` if the
+    /// argument accepts free-form values or isn't a valid argument. This is useful for front-ends
+    /// which want to present the allowed values without declaring them a second time.
+    ///
+    /// **NOTE:** The declared values are returned whether or not the argument was used at
+    /// runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("mode")
+    ///         .long("mode")
+    ///         .takes_value(true)
+    ///         .possible_values(&["fast", "slow"]))
+    ///     .arg(Arg::with_name("output")
+    ///         .long("output")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--output", "out.txt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.possible_values_of("mode"), Some(vec!["fast", "slow"]));
+    /// assert_eq!(m.possible_values_of("output"), None);
+    /// ```
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    pub fn possible_values_of<S: AsRef<str>>(&self, name: S) -> Option<Vec<&str>> {
+        self.possible_vals
+            .get(name.as_ref())
+            .map(|vals| vals.iter().map(|v| &v[..]).collect())
+    }
+
     /// Returns the number of times an argument was used at runtime. If an argument isn't present
     /// it will return `0`.
     ///
@@ -330,6 +726,9 @@ impl<'a> ArgMatches<'a> {
     /// values. For example, `-o val1 val2 val3 -o val4` would return `2` (2 occurrences, but 4
     /// values).
     ///
+    /// **NOTE:** The count is a `u64`, so it doesn't overflow no matter how often an argument is
+    /// repeated. See [`ArgMatches::flag_count`] for a saturating `u8` version.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -362,10 +761,36 @@ impl<'a> ArgMatches<'a> {
     /// assert_eq!(m.occurrences_of("debug"), 3);
     /// assert_eq!(m.occurrences_of("flag"), 1);
     /// ```
+    /// [`ArgMatches::flag_count`]: ./struct.ArgMatches.html#method.flag_count
     pub fn occurrences_of<S: AsRef<str>>(&self, name: S) -> u64 {
         self.args.get(name.as_ref()).map_or(0, |a| a.occurs)
     }
 
+    /// Returns the number of times an argument was used at runtime as a `u8`, which is typically
+    /// what a verbosity level is stored as. Unlike casting [`ArgMatches::occurrences_of`] with
+    /// `as u8`, which silently wraps around, the count saturates at `u8::MAX` (i.e. `255`) when
+    /// the argument was used more often than that. If an argument isn't present it will return
+    /// `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-vvv"
+    ///     ]);
+    ///
+    /// assert_eq!(m.flag_count("verbose"), 3);
+    /// ```
+    /// [`ArgMatches::occurrences_of`]: ./struct.ArgMatches.html#method.occurrences_of
+    pub fn flag_count<S: AsRef<str>>(&self, name: S) -> u8 {
+        cmp::min(self.occurrences_of(name), u8::MAX as u64) as u8
+    }
+
     /// Because [`Subcommand`]s are essentially "sub-[`App`]s" they have their own [`ArgMatches`]
     /// as well. This method returns the [`ArgMatches`] for a particular subcommand or `None` if
     /// the subcommand wasn't present at runtime.
@@ -519,6 +944,73 @@ impl<'a> ArgMatches<'a> {
         self.subcommand.as_ref().map_or(("", None), |sc| (&sc.name[..], Some(&sc.matches)))
     }
 
+    /// Looks up the name of the [`SubCommand`] used at runtime in a table of `(name, handler)`
+    /// pairs, and calls the matching handler with the sub-[`ArgMatches`], returning its result.
+    /// This is a convenience for table driven CLIs which would otherwise need a large `match`
+    /// on [`ArgMatches::subcommand_name`].
+    ///
+    /// Returns `None` if no subcommand was used, or if the table doesn't contain an entry for
+    /// the subcommand that was used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ArgMatches, SubCommand};
+    /// fn clone(_: &ArgMatches) -> &'static str { "cloning" }
+    /// fn push(_: &ArgMatches) -> &'static str { "pushing" }
+    ///
+    /// let app_m = App::new("git")
+    ///     .subcommand(SubCommand::with_name("clone"))
+    ///     .subcommand(SubCommand::with_name("push"))
+    ///     .get_matches_from(vec!["git", "push"]);
+    ///
+    /// let table: &[(&str, fn(&ArgMatches) -> &'static str)] = &[("clone", clone),
+    ///                                                          ("push", push)];
+    /// assert_eq!(app_m.dispatch(table), Some("pushing"));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`ArgMatches::subcommand_name`]: ./struct.ArgMatches.html#method.subcommand_name
+    pub fn dispatch<T, F>(&self, table: &[(&str, F)]) -> Option<T>
+        where F: Fn(&ArgMatches<'a>) -> T
+    {
+        self.subcommand.as_ref().and_then(|sc| {
+            table.iter()
+                .find(|&&(name, _)| name == sc.name)
+                .map(|&(_, ref handler)| handler(&sc.matches))
+        })
+    }
+
+    /// Returns how deep the chain of [`SubCommand`]s used at runtime goes, i.e. `0` if no
+    /// subcommand was used, `1` if only a direct subcommand was used, `2` if that subcommand was
+    /// itself given a subcommand, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, SubCommand};
+    /// let app = App::new("git")
+    ///     .subcommand(SubCommand::with_name("remote")
+    ///         .subcommand(SubCommand::with_name("add")));
+    ///
+    /// let app_m = app.clone().get_matches_from(vec!["git"]);
+    /// assert_eq!(app_m.subcommand_depth(), 0);
+    ///
+    /// let app_m = app.get_matches_from(vec!["git", "remote", "add"]);
+    /// assert_eq!(app_m.subcommand_depth(), 2);
+    /// assert_eq!(app_m.subcommand_matches("remote").unwrap().subcommand_depth(), 1);
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn subcommand_depth(&self) -> usize {
+        let mut depth = 0;
+        let mut sc = self.subcommand.as_ref();
+        while let Some(s) = sc {
+            depth += 1;
+            sc = s.matches.subcommand.as_ref();
+        }
+        depth
+    }
+
     /// Returns a string slice of the usage statement for the [`App`] or [`SubCommand`]
     ///
     /// # Examples
@@ -536,6 +1028,199 @@ impl<'a> ArgMatches<'a> {
     pub fn usage(&self) -> &str {
         self.usage.as_ref().map_or("", |u| &u[..])
     }
+
+    /// Returns every argument which ended up with a value (or was present, in the case of flags)
+    /// along with its final values, sorted by argument name. This is the configuration `clap`
+    /// actually settled on after applying defaults, environment variables and overrides, which
+    /// is useful for debugging or for passing the full configuration on to a child process.
+    ///
+    /// **NOTE:** Flags are included with an empty list of values, and [`ArgGroup`]s are not
+    /// included at all since they aren't arguments of their own.
+    ///
+    /// **NOTE:** Only the arguments of *this* [`App`] or [`SubCommand`] are included, use
+    /// [`ArgMatches::subcommand_matches`] to get those of a particular subcommand.
+    ///
+    /// Use [`ArgMatches::value_source`] to tell where the values of each argument came from.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ffi::OsString;
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("level")
+    ///         .long("level")
+    ///         .default_value("info"))
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-v"
+    ///     ]);
+    ///
+    /// assert_eq!(m.resolved(),
+    ///            vec![("level".to_owned(), vec![OsString::from("info")]),
+    ///                 ("verbose".to_owned(), vec![])]);
+    /// ```
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`App`]: ./struct.App.html
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ArgMatches::subcommand_matches`]: ./struct.ArgMatches.html#method.subcommand_matches
+    /// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
+    pub fn resolved(&self) -> Vec<(String, Vec<OsString>)> {
+        let mut resolved: Vec<_> = self.args
+            .iter()
+            .filter(|&(name, _)| !self.groups.contains_key(name))
+            .map(|(name, ma)| (name.to_string(), ma.vals.values().cloned().collect()))
+            .collect();
+        resolved.sort_by(|a, b| a.0.cmp(&b.0));
+        resolved
+    }
+
+    /// Returns where the values of an argument came from, i.e. whether it was used on the command
+    /// line, read from its [`Arg::env`] variable or is its default. If the argument wasn't present
+    /// at all it returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueSource};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("level")
+    ///         .long("level")
+    ///         .default_value("info"))
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-v"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_source("level"), Some(ValueSource::DefaultValue));
+    /// assert_eq!(m.value_source("verbose"), Some(ValueSource::CommandLine));
+    /// assert_eq!(m.value_source("missing"), None);
+    /// ```
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    pub fn value_source<S: AsRef<str>>(&self, name: S) -> Option<ValueSource> {
+        self.args.get(name.as_ref()).map(|ma| ma.source)
+    }
+
+    /// Returns the [`Arg::requires`] relationships which were satisfied at runtime, as pairs of
+    /// `(argument, required argument)`. This is mostly useful for logging or debugging *why* a
+    /// given combination of arguments was accepted, when there are many relationships between
+    /// them.
+    ///
+    /// **NOTE:** Only the relationships of *this* [`App`] or [`SubCommand`] are included, use
+    /// [`ArgMatches::subcommand_matches`] to get those of a particular subcommand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .requires("input"))
+    ///     .arg(Arg::with_name("input"))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--config", "my.cfg", "file.txt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.requirements_satisfied(), [("cfg", "input")]);
+    /// ```
+    /// [`Arg::requires`]: ./struct.Arg.html#method.requires
+    /// [`App`]: ./struct.App.html
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`ArgMatches::subcommand_matches`]: ./struct.ArgMatches.html#method.subcommand_matches
+    pub fn requirements_satisfied(&self) -> Vec<(&str, &str)> {
+        self.reqs_satisfied.iter().map(|&(ref a, ref r)| (&a[..], &r[..])).collect()
+    }
+
+    /// Fills in every argument which wasn't used in these matches with its value (and number of
+    /// occurrences) from `other`, such as matches built from a configuration file or from a set of
+    /// defaults. Arguments which were used in these matches always win, and their values are
+    /// never combined with those from `other`, so calling this once per layer builds a
+    /// "command line, then config file, then defaults" configuration.
+    ///
+    /// Only arguments which were used on the command line count as used here, values which came
+    /// from an [`Arg::default_value`] or an [`Arg::env`] variable are replaced by those of
+    /// `other`, if it has any.
+    ///
+    /// If an [`ArgGroup`] was used in these matches, none of its arguments are taken from `other`,
+    /// so a group choice made on the command line is never mixed with another one from a lower
+    /// layer.
+    ///
+    /// If `other` used a [`SubCommand`] and these matches didn't use any, it is copied over.
+    /// If both used the same [`SubCommand`] its matches are overlaid in the same way, and if they
+    /// used different ones the [`SubCommand`] of these matches is kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("myprog")
+    ///     .arg(Arg::with_name("host")
+    ///         .long("host")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true));
+    ///
+    /// let mut cli = app.clone().get_matches_from(vec![
+    ///         "myprog", "--port", "8080"
+    ///     ]);
+    /// let config = app.get_matches_from(vec![
+    ///         "myprog", "--host", "example.com", "--port", "80"
+    ///     ]);
+    /// cli.overlay(&config);
+    ///
+    /// assert_eq!(cli.value_of("host"), Some("example.com"));
+    /// assert_eq!(cli.value_of("port"), Some("8080"));
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`ArgGroup`]: ./struct.ArgGroup.html
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    pub fn overlay(&mut self, other: &ArgMatches<'a>) {
+        let used: Vec<&'a str> = self.args
+            .iter()
+            .filter(|&(_, ma)| ma.source == ValueSource::CommandLine)
+            .map(|(name, _)| *name)
+            .collect();
+        let used_groups: Vec<&'a str> = self.groups
+            .iter()
+            .filter(|&(_, args)| args.iter().any(|a| used.contains(a)))
+            .map(|(g, _)| *g)
+            .collect();
+        for (name, ma) in &other.args {
+            if used.contains(name) {
+                continue;
+            }
+            let in_used_group = used_groups.iter()
+                .any(|g| self.groups.get(g).map_or(false, |args| args.contains(name)));
+            if !in_used_group {
+                self.args.insert(*name, ma.clone());
+            }
+        }
+        for (name, args) in &other.groups {
+            self.groups.entry(*name).or_insert_with(|| args.clone());
+        }
+        for (name, vals) in &other.possible_vals {
+            self.possible_vals.entry(*name).or_insert_with(|| vals.clone());
+        }
+
+        if let Some(ref osc) = other.subcommand {
+            let same_name = self.subcommand.as_ref().map(|sc| sc.name == osc.name);
+            match same_name {
+                Some(true) => {
+                    if let Some(ref mut sc) = self.subcommand {
+                        sc.matches.overlay(&osc.matches);
+                    }
+                }
+                Some(false) => (),
+                None => self.subcommand = Some(osc.clone()),
+            }
+        }
+    }
 }
 
 
@@ -565,6 +1250,29 @@ pub struct Values<'a> {
     iter: Map<vec_map::Values<'a, OsString>, fn(&'a OsString) -> &'a str>,
 }
 
+impl<'a> Values<'a> {
+    /// Returns the value [`Iterator::next`] would return, without advancing the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("cmd")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "push", "origin"]);
+    ///
+    /// let mut vals = m.values_of("cmd").unwrap();
+    /// assert_eq!(vals.peek(), Some("push"));
+    /// assert_eq!(vals.next(), Some("push"));
+    /// assert_eq!(vals.peek(), Some("origin"));
+    /// ```
+    /// [`Iterator::next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
+    pub fn peek(&self) -> Option<&'a str> {
+        self.iter.clone().next()
+    }
+}
+
 impl<'a> Iterator for Values<'a> {
     type Item = &'a str;
 
@@ -656,6 +1364,31 @@ pub struct OsValues<'a> {
     iter: Map<vec_map::Values<'a, OsString>, fn(&'a OsString) -> &'a OsStr>,
 }
 
+impl<'a> OsValues<'a> {
+    /// Returns the value [`Iterator::next`] would return, without advancing the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use std::ffi::OsStr;
+    ///
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("cmd")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "push", "origin"]);
+    ///
+    /// let mut vals = m.values_of_os("cmd").unwrap();
+    /// assert_eq!(vals.peek(), Some(OsStr::new("push")));
+    /// assert_eq!(vals.next(), Some(OsStr::new("push")));
+    /// assert_eq!(vals.peek(), Some(OsStr::new("origin")));
+    /// ```
+    /// [`Iterator::next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
+    pub fn peek(&self) -> Option<&'a OsStr> {
+        self.iter.clone().next()
+    }
+}
+
 impl<'a> Iterator for OsValues<'a> {
     type Item = &'a OsStr;
 
@@ -0,0 +1,144 @@
+// Std
+use std::str::FromStr;
+
+// Internal
+use args::ArgMatches;
+use errors::{Error, Result as ClapResult};
+
+/// Builds a type, usually a program's own configuration struct, from the [`ArgMatches`] of a
+/// parse. Implementing this trait gives libraries a common way to accept a program's parsed
+/// arguments, and keeps the mapping from arguments to fields in one place.
+///
+/// The [`parse_field`], [`parse_field_opt`] and [`parse_fields`] functions convert single
+/// arguments into any type implementing [`std::str::FromStr`], with the same errors as the
+/// [`value_t!`] and [`values_t!`] macros.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ArgMatches, FromArgMatches, parse_field};
+/// struct Config {
+///     name: String,
+///     jobs: u32,
+///     verbose: bool,
+/// }
+///
+/// impl FromArgMatches for Config {
+///     fn from_arg_matches(m: &ArgMatches) -> clap::Result<Self> {
+///         Ok(Config {
+///             name: try!(parse_field(m, "name")),
+///             jobs: try!(parse_field(m, "jobs")),
+///             verbose: m.is_present("verbose"),
+///         })
+///     }
+/// }
+///
+/// let m = App::new("myprog")
+///     .arg(Arg::with_name("name").required(true))
+///     .arg(Arg::with_name("jobs").long("jobs").takes_value(true).default_value("1"))
+///     .arg(Arg::with_name("verbose").short("v"))
+///     .get_matches_from(vec![
+///         "myprog", "build", "--jobs", "4"
+///     ]);
+///
+/// let cfg = Config::from_arg_matches(&m).unwrap();
+/// assert_eq!(cfg.name, "build");
+/// assert_eq!(cfg.jobs, 4);
+/// assert!(!cfg.verbose);
+/// ```
+/// [`ArgMatches`]: ./struct.ArgMatches.html
+/// [`parse_field`]: ./fn.parse_field.html
+/// [`parse_field_opt`]: ./fn.parse_field_opt.html
+/// [`parse_fields`]: ./fn.parse_fields.html
+/// [`std::str::FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+/// [`value_t!`]: ./macro.value_t!.html
+/// [`values_t!`]: ./macro.values_t!.html
+pub trait FromArgMatches: Sized {
+    /// Builds `Self` from the matches, or returns an [`Error`] describing why it couldn't be.
+    /// [`Error`]: ./struct.Error.html
+    fn from_arg_matches(m: &ArgMatches) -> ClapResult<Self>;
+}
+
+/// Parses the value of an argument as `T`, like the [`value_t!`] macro. Returns an
+/// [`ErrorKind::ArgumentNotFound`] error if the argument wasn't used, or an
+/// [`ErrorKind::ValueValidation`] error if its value couldn't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ErrorKind, parse_field};
+/// let m = App::new("myprog")
+///     .arg(Arg::with_name("len").long("len").takes_value(true))
+///     .get_matches_from(vec![
+///         "myprog", "--len", "20"
+///     ]);
+///
+/// assert_eq!(parse_field::<u32>(&m, "len").unwrap(), 20);
+/// assert_eq!(parse_field::<u32>(&m, "width").unwrap_err().kind, ErrorKind::ArgumentNotFound);
+/// ```
+/// [`value_t!`]: ./macro.value_t!.html
+/// [`ErrorKind::ArgumentNotFound`]: ./enum.ErrorKind.html#variant.ArgumentNotFound
+/// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+pub fn parse_field<T: FromStr>(m: &ArgMatches, name: &str) -> ClapResult<T> {
+    match try!(parse_field_opt(m, name)) {
+        Some(val) => Ok(val),
+        None => Err(Error::argument_not_found_auto(name)),
+    }
+}
+
+/// Parses the value of an optional argument as `T`, returning `Ok(None)` if the argument wasn't
+/// used. Returns an [`ErrorKind::ValueValidation`] error if its value couldn't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, parse_field_opt};
+/// let m = App::new("myprog")
+///     .arg(Arg::with_name("len").long("len").takes_value(true))
+///     .get_matches_from(vec![
+///         "myprog"
+///     ]);
+///
+/// assert_eq!(parse_field_opt::<u32>(&m, "len").unwrap(), None);
+/// ```
+/// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+pub fn parse_field_opt<T: FromStr>(m: &ArgMatches, name: &str) -> ClapResult<Option<T>> {
+    match m.value_of(name) {
+        Some(v) => parse_val(v).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Parses every value of an argument as `T`, like the [`values_t!`] macro. Returns an empty
+/// `Vec` if the argument wasn't used, or an [`ErrorKind::ValueValidation`] error if any of its
+/// values couldn't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, parse_fields};
+/// let m = App::new("myprog")
+///     .arg(Arg::with_name("nums").multiple(true))
+///     .get_matches_from(vec![
+///         "myprog", "1", "2", "3"
+///     ]);
+///
+/// assert_eq!(parse_fields::<u32>(&m, "nums").unwrap(), [1, 2, 3]);
+/// ```
+/// [`values_t!`]: ./macro.values_t!.html
+/// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+pub fn parse_fields<T: FromStr>(m: &ArgMatches, name: &str) -> ClapResult<Vec<T>> {
+    let mut vals = vec![];
+    if let Some(vs) = m.values_of(name) {
+        for v in vs {
+            vals.push(try!(parse_val(v)));
+        }
+    }
+    Ok(vals)
+}
+
+fn parse_val<T: FromStr>(v: &str) -> ClapResult<T> {
+    v.parse::<T>().map_err(|_| {
+        Error::value_validation_auto(format!("The argument '{}' isn't a valid value", v))
+    })
+}
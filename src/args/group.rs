@@ -70,7 +70,8 @@ use yaml_rust::Yaml;
 /// let matches = result.unwrap();
 /// // We may not know which of the args was used, so we can test for the group...
 /// assert!(matches.is_present("vers"));
-/// // we could also alternatively check each arg individually (not shown here)
+/// // ...or ask which of the args it was
+/// assert_eq!(matches.value_of_group("vers"), Some("major"));
 /// ```
 /// [`ArgGroup::multiple(true)`]: ./struct.ArgGroup.html#method.multiple
 /// [arguments]: ./struct.Arg.html
@@ -90,6 +91,8 @@ pub struct ArgGroup<'a> {
     pub conflicts: Option<Vec<&'a str>>,
     #[doc(hidden)]
     pub multiple: bool,
+    #[doc(hidden)]
+    pub count: Option<(usize, usize)>,
 }
 
 impl<'a> ArgGroup<'a> {
@@ -111,6 +114,7 @@ impl<'a> ArgGroup<'a> {
             requires: None,
             conflicts: None,
             multiple: false,
+            count: None,
         }
     }
 
@@ -233,6 +237,54 @@ impl<'a> ArgGroup<'a> {
         self
     }
 
+    /// Requires that between `min` and `max` (inclusive) of the [`Arg`]s in this group are used
+    /// at runtime. This is a generalization of [`ArgGroup::required`] and
+    /// [`ArgGroup::multiple`], i.e. `required_count(1, 1)` is the same as `required(true)` with
+    /// the default `multiple(false)`.
+    ///
+    /// Using fewer than `min` arguments results in an [`ErrorKind::MissingRequiredArgument`]
+    /// error, while using more than `max` results in an [`ErrorKind::ArgumentConflict`] error.
+    /// Both errors state the bounds, and which arguments were supplied.
+    ///
+    /// # Panics
+    ///
+    /// This method will [`panic!`] if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::from_usage("-x [x] 'x coordinate'"))
+    ///     .arg(Arg::from_usage("-y [y] 'y coordinate'"))
+    ///     .arg(Arg::from_usage("-z [z] 'z coordinate'"))
+    ///     .group(ArgGroup::with_name("coords")
+    ///         .args(&["x", "y", "z"])
+    ///         .required_count(2, 3))
+    ///     .get_matches_from_safe(vec!["myprog", "-x", "1"]);
+    /// // Because we only used one of the args in the group, it's an error
+    /// assert!(result.is_err());
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg`]: ./struct.Arg.html
+    /// [`ArgGroup::required`]: ./struct.ArgGroup.html#method.required
+    /// [`ArgGroup::multiple`]: ./struct.ArgGroup.html#method.multiple
+    /// [`ErrorKind::MissingRequiredArgument`]: ./enum.ErrorKind.html#variant.MissingRequiredArgument
+    /// [`ErrorKind::ArgumentConflict`]: ./enum.ErrorKind.html#variant.ArgumentConflict
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn required_count(mut self, min: usize, max: usize) -> Self {
+        assert!(min <= max,
+                "ArgGroup '{}' requires at least {} args, but no more than {}",
+                &*self.name,
+                min,
+                max);
+        self.required = min > 0;
+        self.multiple = max > 1;
+        self.count = Some((min, max));
+        self
+    }
+
     /// Sets the group as required or not. A required group will be displayed in the usage string
     /// of the application in the format `<arg|arg2|arg3>`. A required `ArgGroup` simply states
     /// that one argument from this group *must* be present at runtime (unless
@@ -451,6 +503,7 @@ impl<'a, 'z> From<&'z ArgGroup<'a>> for ArgGroup<'a> {
             requires: g.requires.clone(),
             conflicts: g.conflicts.clone(),
             multiple: g.multiple,
+            count: g.count,
         }
     }
 }
@@ -628,6 +681,7 @@ impl<'a> Clone for ArgGroup<'a> {
             requires: self.requires.clone(),
             conflicts: self.conflicts.clone(),
             multiple: self.multiple,
+            count: self.count,
         }
     }
 }
@@ -1,16 +1,44 @@
 // Std
+use std::any::Any;
 use std::ffi::OsString;
+use std::fmt;
+use std::sync::Arc;
 
 // Third Party
 use vec_map::VecMap;
 
+/// Where the values of an argument came from, as returned by [`ArgMatches::value_source`].
+///
+/// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ValueSource {
+    /// The argument was used on the command line.
+    CommandLine,
+    /// The values were read from the argument's [`Arg::env`] variable.
+    ///
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    EnvVariable,
+    /// The values are the argument's default, such as an [`Arg::default_value`].
+    ///
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    DefaultValue,
+}
+
 #[doc(hidden)]
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct MatchedArg {
     #[doc(hidden)]
     pub occurs: u64,
     #[doc(hidden)]
     pub vals: VecMap<OsString>,
+    // Each value exactly as it was given, before delimiters were split or values were normalized
+    #[doc(hidden)]
+    pub raw_vals: VecMap<OsString>,
+    // The values converted by the argument's `value_parser`, `None` if it doesn't have one
+    #[doc(hidden)]
+    pub typed_vals: Option<Vec<Arc<Any + Send + Sync>>>,
+    #[doc(hidden)]
+    pub source: ValueSource,
 }
 
 impl Default for MatchedArg {
@@ -18,10 +46,25 @@ impl Default for MatchedArg {
         MatchedArg {
             occurs: 1,
             vals: VecMap::new(),
+            raw_vals: VecMap::new(),
+            typed_vals: None,
+            source: ValueSource::CommandLine,
         }
     }
 }
 
+impl fmt::Debug for MatchedArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MatchedArg")
+            .field("occurs", &self.occurs)
+            .field("vals", &self.vals)
+            .field("raw_vals", &self.raw_vals)
+            .field("typed_vals", &self.typed_vals.as_ref().map(|v| v.len()))
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
 impl MatchedArg {
     pub fn new() -> Self {
         MatchedArg::default()
@@ -1,10 +1,11 @@
-pub use self::any_arg::{AnyArg, DispOrder};
+pub use self::any_arg::{AnyArg, DispOrder, ValueParser};
 pub use self::arg::Arg;
 pub use self::arg_builder::{FlagBuilder, OptBuilder, PosBuilder};
 pub use self::arg_matcher::ArgMatcher;
 pub use self::arg_matches::{Values, OsValues, ArgMatches};
+pub use self::from_arg_matches::{FromArgMatches, parse_field, parse_field_opt, parse_fields};
 pub use self::group::ArgGroup;
-pub use self::matched_arg::MatchedArg;
+pub use self::matched_arg::{MatchedArg, ValueSource};
 pub use self::settings::ArgSettings;
 pub use self::subcommand::SubCommand;
 
@@ -13,6 +14,7 @@ mod macros;
 mod arg;
 pub mod any_arg;
 mod arg_matches;
+mod from_arg_matches;
 mod arg_matcher;
 mod subcommand;
 mod arg_builder;
@@ -3,7 +3,7 @@ use std::ascii::AsciiExt;
 use std::str::FromStr;
 
 bitflags! {
-    flags Flags: u16 {
+    flags Flags: u32 {
         const REQUIRED       = 0b000000000001,
         const MULTIPLE       = 0b000000000010,
         const EMPTY_VALS     = 0b000000000100,
@@ -16,6 +16,13 @@ bitflags! {
         const REQ_DELIM      = 0b001000000000,
         const DELIM_NOT_SET  = 0b010000000000,
         const HIDE_POS_VALS  = 0b100000000000,
+        const NORM_PATH_SEP  = 0b1000000000000,
+        const HIDDEN_SHORT_H = 0b10000000000000,
+        const HIDDEN_LONG_H  = 0b100000000000000,
+        const LAST           = 0b1000000000000000,
+        const HIDE_DEFAULT_VAL = 0b10000000000000000,
+        const ALLOW_TAC_VALS = 0b100000000000000000,
+        const REQUIRE_EQUALS = 0b1000000000000000000,
     }
 }
 
@@ -40,7 +47,14 @@ impl ArgFlags {
         RequiredUnlessAll => R_UNLESS_ALL,
         RequireDelimiter => REQ_DELIM,
         ValueDelimiterNotSet => DELIM_NOT_SET,
-        HidePossibleValues => HIDE_POS_VALS
+        HidePossibleValues => HIDE_POS_VALS,
+        NormalizePathSeparators => NORM_PATH_SEP,
+        HiddenShortHelp => HIDDEN_SHORT_H,
+        HiddenLongHelp => HIDDEN_LONG_H,
+        Last => LAST,
+        HideDefaultValue => HIDE_DEFAULT_VAL,
+        AllowHyphenValues => ALLOW_TAC_VALS,
+        RequireEquals => REQUIRE_EQUALS
     }
 }
 
@@ -78,6 +92,20 @@ pub enum ArgSettings {
     RequireDelimiter,
     /// Hides the possible values from the help string
     HidePossibleValues,
+    /// Hides the default value from the help string
+    HideDefaultValue,
+    /// Converts both `/` and `\` in values to the platform's path separator
+    NormalizePathSeparators,
+    /// The argument should **not** be shown in the help text displayed by `-h`
+    HiddenShortHelp,
+    /// The argument should **not** be shown in the help text displayed by `--help`
+    HiddenLongHelp,
+    /// The positional argument only receives values given after a `--`
+    Last,
+    /// The option accepts values which start with a leading hyphen, such as `--expr -n`
+    AllowHyphenValues,
+    /// The option only accepts values joined to it with an `=`, such as `--option=value`
+    RequireEquals,
     #[doc(hidden)]
     RequiredUnlessAll,
     #[doc(hidden)]
@@ -100,6 +128,13 @@ impl FromStr for ArgSettings {
             "requiredelimiter" => Ok(ArgSettings::RequireDelimiter),
             "valuedelimiternotset" => Ok(ArgSettings::ValueDelimiterNotSet),
             "hidepossiblevalues" => Ok(ArgSettings::HidePossibleValues),
+            "hidedefaultvalue" => Ok(ArgSettings::HideDefaultValue),
+            "normalizepathseparators" => Ok(ArgSettings::NormalizePathSeparators),
+            "hiddenshorthelp" => Ok(ArgSettings::HiddenShortHelp),
+            "hiddenlonghelp" => Ok(ArgSettings::HiddenLongHelp),
+            "last" => Ok(ArgSettings::Last),
+            "allowhyphenvalues" => Ok(ArgSettings::AllowHyphenValues),
+            "requireequals" => Ok(ArgSettings::RequireEquals),
             _ => Err("unknown ArgSetting, cannot convert from str".to_owned()),
         }
     }
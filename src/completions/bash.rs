@@ -150,7 +150,7 @@ complete -F _{name} {name}
                     ;;",
                                opts,
                                l,
-                               self.vals_for(o));
+                               self.vals_for(o, path));
             }
             if let Some(s) = o.short {
                 opts = format!("{}
@@ -160,17 +160,24 @@ complete -F _{name} {name}
                     ;;",
                                opts,
                                s,
-                               self.vals_for(o));
+                               self.vals_for(o, path));
             }
         }
         opts
     }
 
-    fn vals_for(&self, o: &OptBuilder) -> String {
+    fn vals_for(&self, o: &OptBuilder, path: &str) -> String {
         use args::AnyArg;
         let mut ret = String::new();
         let mut needs_quotes = true;
-        if let Some(vals) = o.possible_vals() {
+        if o.completion_fn.is_some() {
+            needs_quotes = false;
+            let mut cmd = vec![&**self.p.meta.bin_name.as_ref().unwrap(),
+                               completions::COMPLETION_VALUES_FLAG];
+            cmd.extend(path.split('_').skip(1));
+            cmd.push(o.name());
+            ret = format!("$(compgen -W \"$({})\" -- ${{cur}})", cmd.join(" "));
+        } else if let Some(vals) = o.possible_vals() {
             needs_quotes = false;
             ret = format!("$(compgen -W \"{}\" -- ${{cur}})", vals.join(" "));
         } else if let Some(vec) = o.val_names() {
@@ -1,9 +1,9 @@
-
 // Std
 use std::io::Write;
 
 // Internal
 use app::parser::Parser;
+use completions::COMPLETION_VALUES_FLAG;
 
 pub struct FishGen<'a, 'b>
     where 'a: 'b
@@ -18,51 +18,60 @@ impl<'a, 'b> FishGen<'a, 'b> {
 
     pub fn generate_to<W: Write>(&self, buf: &mut W) {
         let command = self.p.meta.bin_name.as_ref().unwrap();
-
-        // function to detect subcommand
-        let detect_subcommand_function =
-r#"function __fish_using_command
-    set cmd (commandline -opc)
-    if [ (count $cmd) -eq (count $argv) ]
-        for i in (seq (count $argv))
-            if [ $cmd[$i] != $argv[$i] ]
-                return 1
-            end
-        end
-        return 0
-    end
-    return 1
-end
-
-"#.to_string();
-
-        let mut buffer = detect_subcommand_function;
-        gen_fish_inner(command, self, &command.to_string(), &mut buffer);
+        let mut buffer = String::new();
+        gen_fish_inner(command, self, &[], &mut buffer);
         w!(buf, buffer.as_bytes());
     }
 }
 
+// Escapes a string for use inside single quotes in fish
+fn escape_string(string: &str) -> String {
+    string.replace("\\", "\\\\").replace("'", "\\'")
+}
+
 fn gen_fish_inner(root_command: &str,
-                comp_gen: &FishGen,
-                parent_cmds: &str,
-                buffer: &mut String) {
+                  comp_gen: &FishGen,
+                  parent_cmds: &[&str],
+                  buffer: &mut String) {
     // example :
     //
     // complete
     //      -c {command}
-    //      -d "{description}"
+    //      -d '{description}'
     //      -s {short}
     //      -l {long}
-    //      -a "{possible_arguments}"
+    //      -a '{possible_arguments}'
     //      -r # if require parameter
     //      -f # don't use file completion
-    //      -n "__fish_using_command myprog subcmd1" # complete for command "myprog subcmd1"
+    //      -n '__fish_use_subcommand' # complete only while no subcommand has been given
+    //      -n '__fish_seen_subcommand_from subcmd1' # complete for command "myprog subcmd1"
+
+    let p = comp_gen.p;
+    let subcmd_names: Vec<&str> = p.subcommands.iter().map(|s| &*s.p.meta.name).collect();
 
-    let basic_template = format!("complete -c {} -n \"__fish_using_command {}\"",
-                                root_command,
-                                parent_cmds);
+    // Everything at this level is only completed once its parent subcommands have been given, and
+    // before any of its own subcommands has been
+    let mut conditions = vec![];
+    if parent_cmds.is_empty() {
+        if !subcmd_names.is_empty() {
+            conditions.push("__fish_use_subcommand".to_owned());
+        }
+    } else {
+        for cmd in parent_cmds {
+            conditions.push(format!("__fish_seen_subcommand_from {}", cmd));
+        }
+        if !subcmd_names.is_empty() {
+            conditions.push(format!("not __fish_seen_subcommand_from {}",
+                                    subcmd_names.join(" ")));
+        }
+    }
+    let basic_template = if conditions.is_empty() {
+        format!("complete -c {}", root_command)
+    } else {
+        format!("complete -c {} -n '{}'", root_command, conditions.join("; and "))
+    };
 
-    for option in &comp_gen.p.opts {
+    for option in &p.opts {
         let mut template = basic_template.clone();
         if let Some(data) = option.short {
             template.push_str(format!(" -s {}", data).as_str());
@@ -71,16 +80,23 @@ fn gen_fish_inner(root_command: &str,
             template.push_str(format!(" -l {}", data).as_str());
         }
         if let Some(data) = option.help {
-            template.push_str(format!(" -d \"{}\"", data).as_str());
+            template.push_str(format!(" -d '{}'", escape_string(data)).as_str());
         }
-        if let Some(ref data) = option.possible_vals {
-            template.push_str(format!(" -r -f -a \"{}\"", data.join(" ")).as_str());
+        template.push_str(" -r");
+        if option.completion_fn.is_some() {
+            // fish runs the command substitution each time the values are completed
+            let mut cmd = vec![root_command, COMPLETION_VALUES_FLAG];
+            cmd.extend(parent_cmds);
+            cmd.push(option.name);
+            template.push_str(format!(" -f -a '({})'", escape_string(&cmd.join(" "))).as_str());
+        } else if let Some(ref data) = option.possible_vals {
+            template.push_str(format!(" -f -a '{}'", escape_string(&data.join(" "))).as_str());
         }
         buffer.push_str(template.as_str());
         buffer.push_str("\n");
     }
 
-    for flag in &comp_gen.p.flags {
+    for flag in &p.flags {
         let mut template = basic_template.clone();
         if let Some(data) = flag.short {
             template.push_str(format!(" -s {}", data).as_str());
@@ -89,32 +105,28 @@ fn gen_fish_inner(root_command: &str,
             template.push_str(format!(" -l {}", data).as_str());
         }
         if let Some(data) = flag.help {
-            template.push_str(format!(" -d \"{}\"", data).as_str());
+            template.push_str(format!(" -d '{}'", escape_string(data)).as_str());
         }
         buffer.push_str(template.as_str());
         buffer.push_str("\n");
     }
 
-    for subcommand in &comp_gen.p.subcommands {
+    for subcommand in &p.subcommands {
         let mut template = basic_template.clone();
         template.push_str(" -f");
-        template.push_str(format!(" -a \"{}\"", &subcommand.p.meta.name).as_str());
+        template.push_str(format!(" -a '{}'", &subcommand.p.meta.name).as_str());
+        if let Some(data) = subcommand.p.meta.about {
+            template.push_str(format!(" -d '{}'", escape_string(data)).as_str());
+        }
         buffer.push_str(template.as_str());
         buffer.push_str("\n");
     }
 
     // generate options of subcommands
-    for subcommand in &comp_gen.p.subcommands {
+    for subcommand in &p.subcommands {
         let sub_comp_gen = FishGen::new(&subcommand.p);
-        // make new "parent_cmds" for different subcommands
-        let mut sub_parent_cmds = parent_cmds.to_string();
-        if !sub_parent_cmds.is_empty() {
-            sub_parent_cmds.push_str(" ");
-        }
-        sub_parent_cmds.push_str(&subcommand.p.meta.name);
-        gen_fish_inner(root_command,
-                    &sub_comp_gen,
-                    &sub_parent_cmds,
-                    buffer);
+        let mut sub_parent_cmds = parent_cmds.to_vec();
+        sub_parent_cmds.push(&*subcommand.p.meta.name);
+        gen_fish_inner(root_command, &sub_comp_gen, &sub_parent_cmds, buffer);
     }
-}
\ No newline at end of file
+}
@@ -17,6 +17,10 @@ use self::zsh::ZshGen;
 use self::powershell::PowerShellGen;
 pub use self::shell::Shell;
 
+// The hidden flag completion scripts use to get the values of an argument from its
+// `Arg::completion` hook, see `Parser::completion_values`
+pub const COMPLETION_VALUES_FLAG: &'static str = "--generate-completion-values";
+
 pub struct ComplGen<'a, 'b>
     where 'a: 'b
 {
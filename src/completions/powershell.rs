@@ -1,9 +1,9 @@
-
 // Std
 use std::io::Write;
 
 // Internal
 use app::parser::Parser;
+use completions::COMPLETION_VALUES_FLAG;
 
 pub struct PowerShellGen<'a, 'b>
     where 'a: 'b
@@ -19,7 +19,16 @@ impl<'a, 'b> PowerShellGen<'a, 'b> {
     pub fn generate_to<W: Write>(&self, buf: &mut W) {
         let bin_name = self.p.meta.bin_name.as_ref().unwrap();
 
-        let (subcommands_detection_cases, subcommands_cases) = generate_inner(&self.p, "");
+        let mut subcommands_detection_cases = String::new();
+        let mut option_values_cases = String::new();
+        let mut subcommands_cases = String::new();
+        generate_inner(self.p,
+                       bin_name,
+                       &[],
+                       &format!("_{}", bin_name),
+                       &mut subcommands_detection_cases,
+                       &mut option_values_cases,
+                       &mut subcommands_cases);
 
         let mut bin_names = vec![
             bin_name.to_string(),
@@ -32,84 +41,189 @@ impl<'a, 'b> PowerShellGen<'a, 'b> {
             bin_names.push(format!(r"./{0}.exe", bin_name));
         }
 
-        let bin_names = bin_names.iter().fold(String::new(), |previous, current| format!("{0}, '{1}'", previous, current));
+        let bin_names = bin_names.iter().fold(String::new(), |previous, current| format!("{0}, '{1}'", previous, escape_string(current)));
         let bin_names = bin_names.trim_left_matches(", ");
 
+        // PowerShell doesn't allow a switch without any cases
+        let subcommands_detection = if subcommands_detection_cases.is_empty() {
+            String::new()
+        } else {
+            format!(r#"        foreach ($element in $elements) {{
+            switch ($command + ';' + $element) {{
+{}
+            }}
+        }}
+"#,
+                    subcommands_detection_cases.trim_right_matches('\n'))
+        };
+        let option_values = if option_values_cases.is_empty() {
+            String::new()
+        } else {
+            format!(r#"        $completions = @(switch ($command + ';' + $previous) {{
+{}
+        }})
+"#,
+                    option_values_cases.trim_right_matches('\n'))
+        };
+
         let result = format!(r#"
 @({bin_names}) | %{{
     Register-ArgumentCompleter -Native -CommandName $_ -ScriptBlock {{
         param($wordToComplete, $commandAst, $cursorPosition)
 
-        $command = '_{bin_name}'
-        $commandAst.CommandElements |
+        $elements = @($commandAst.CommandElements |
             Select-Object -Skip 1 |
-            %{{
-                switch ($_.ToString()) {{
-{subcommands_detection_cases}
-                }}
-            }}
+            ?{{ $_.Extent.EndOffset -lt $cursorPosition }} |
+            %{{ $_.ToString() }})
+
+        $command = '{root_command}'
+{subcommands_detection}
+        $previous = ''
+        if ($elements.Count -gt 0) {{
+            $previous = $elements[-1]
+        }}
 
         $completions = @()
-
-        switch ($command) {{
+{option_values}        if ($completions.Count -eq 0) {{
+            $completions = @(switch ($command) {{
 {subcommands_cases}
+            }})
         }}
 
         $completions |
-            ?{{ $_ -like "$wordToComplete*" }} |
-            Sort-Object |
-            %{{ New-Object System.Management.Automation.CompletionResult $_, $_, 'ParameterValue', $_ }}
+            ?{{ $_.CompletionText -like "$wordToComplete*" }} |
+            Sort-Object -Property ListItemText
     }}
 }}
 "#,
             bin_names = bin_names,
-            bin_name = bin_name,
-            subcommands_detection_cases = subcommands_detection_cases,
-            subcommands_cases = subcommands_cases
+            root_command = escape_string(&format!("_{}", bin_name)),
+            subcommands_detection = subcommands_detection,
+            option_values = option_values,
+            subcommands_cases = subcommands_cases.trim_right_matches('\n')
         );
 
         w!(buf, result.as_bytes());
     }
 }
 
-fn generate_inner<'a, 'b>(p: &Parser<'a, 'b>, previous_command_name: &str) -> (String, String) {
-    let command_name = format!("{}_{}", previous_command_name, &p.meta.name);
+// Escapes a string for use inside single quotes in PowerShell
+fn escape_string(string: &str) -> String {
+    string.replace("'", "''")
+}
 
-    let mut subcommands_detection_cases =
-        if previous_command_name == "" {
-            String::new()
+// A CompletionResult whose tooltip is the help of what's being completed, or its text if there is
+// no help since PowerShell doesn't allow an empty tooltip
+fn completion_result(text: &str, kind: &str, tooltip: Option<&str>) -> String {
+    format!("New-Object System.Management.Automation.CompletionResult '{0}', '{0}', '{1}', '{2}'",
+            escape_string(text),
+            kind,
+            escape_string(tooltip.unwrap_or(text)))
+}
+
+fn generate_inner<'a, 'b>(p: &Parser<'a, 'b>,
+                          bin_name: &str,
+                          path: &[&str],
+                          command_name: &str,
+                          subcommands_detection_cases: &mut String,
+                          option_values_cases: &mut String,
+                          subcommands_cases: &mut String) {
+    for subcommand in &p.subcommands {
+        let sub_command_name = format!("{}_{}", command_name, &subcommand.p.meta.name);
+        let mut names = vec![&*subcommand.p.meta.name];
+        if let Some(ref aliases) = subcommand.p.meta.aliases {
+            names.extend(aliases.iter().map(|&(n, _)| n));
         }
-        else {
-            format!(r"
-                    '{0}' {{
-                        $command += '_{0}'
-                        break
-                    }}
-", &p.meta.name)
-        };
+        for name in names {
+            subcommands_detection_cases.push_str(&format!(r"                '{};{}' {{
+                    $command = '{}'
+                    break
+                }}
+",
+                                                          escape_string(command_name),
+                                                          escape_string(name),
+                                                          escape_string(&sub_command_name)));
+        }
+    }
 
     let mut completions = String::new();
-    for subcommand in &p.subcommands {
-        completions.push_str(&format!("'{}', ", &subcommand.p.meta.name));
+    for option in &p.opts {
+        let mut switches = vec![];
+        if let Some(s) = option.short {
+            switches.push(format!("-{}", s));
+        }
+        if let Some(l) = option.long {
+            switches.push(format!("--{}", l));
+        }
+        for switch in &switches {
+            completions.push_str(&format!("\n                {}",
+                                          completion_result(switch, "ParameterName", option.help)));
+        }
+        if option.completion_fn.is_some() {
+            // The values are computed by running the program itself each time they're completed
+            let mut cmd = vec![bin_name, COMPLETION_VALUES_FLAG];
+            cmd.extend(path);
+            cmd.push(option.name);
+            let cmd = cmd.iter()
+                .map(|arg| format!("'{}'", escape_string(arg)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            for switch in &switches {
+                option_values_cases.push_str(&format!("            '{};{}' {{
+                & {} | %{{ {} }}
+            }}
+",
+                                                      escape_string(command_name),
+                                                      escape_string(switch),
+                                                      cmd,
+                                                      "New-Object System.Management.Automation.CompletionResult $_, $_, 'ParameterValue', $_"));
+            }
+        } else if let Some(ref vals) = option.possible_vals {
+            for switch in &switches {
+                option_values_cases.push_str(&format!("            '{};{}' {{",
+                                                      escape_string(command_name),
+                                                      escape_string(switch)));
+                for val in vals {
+                    option_values_cases.push_str(&format!("\n                {}",
+                                                          completion_result(val, "ParameterValue", None)));
+                }
+                option_values_cases.push_str("\n            }\n");
+            }
+        }
     }
-    for short in &p.short_list {
-        completions.push_str(&format!("'-{}', ", short));
+    for flag in &p.flags {
+        if let Some(s) = flag.short {
+            completions.push_str(&format!("\n                {}",
+                                          completion_result(&format!("-{}", s), "ParameterName", flag.help)));
+        }
+        if let Some(l) = flag.long {
+            completions.push_str(&format!("\n                {}",
+                                          completion_result(&format!("--{}", l), "ParameterName", flag.help)));
+        }
     }
-    for long in &p.long_list {
-        completions.push_str(&format!("'--{}', ", long));
+    for subcommand in &p.subcommands {
+        completions.push_str(&format!("\n                {}",
+                                      completion_result(&subcommand.p.meta.name,
+                                                        "ParameterValue",
+                                                        subcommand.p.meta.about)));
     }
 
-    let mut subcommands_cases = format!(r"
-            '{}' {{
-                $completions = @({})
+    subcommands_cases.push_str(&format!(r"            '{}' {{{}
             }}
-", &command_name, completions.trim_right_matches(", "));
+",
+                                        escape_string(command_name),
+                                        completions));
 
     for subcommand in &p.subcommands {
-        let (subcommand_subcommands_detection_cases, subcommand_subcommands_cases) = generate_inner(&subcommand.p, &command_name);
-        subcommands_detection_cases.push_str(&subcommand_subcommands_detection_cases);
-        subcommands_cases.push_str(&subcommand_subcommands_cases);
+        let sub_command_name = format!("{}_{}", command_name, &subcommand.p.meta.name);
+        let mut sub_path = path.to_vec();
+        sub_path.push(&*subcommand.p.meta.name);
+        generate_inner(&subcommand.p,
+                       bin_name,
+                       &sub_path,
+                       &sub_command_name,
+                       subcommands_detection_cases,
+                       option_values_cases,
+                       subcommands_cases);
     }
-
-    (subcommands_detection_cases, subcommands_cases)
 }
@@ -303,7 +303,13 @@ fn write_opts_of(p: &Parser) -> String {
         conflicts = if conflicts.is_empty() { String::new() } else { format!("({})", conflicts) };
 
         let multiple = if o.is_set(ArgSettings::Multiple) { "*" } else { "" };
-        let pv = if let Some(pv_vec) = o.possible_vals() {
+        let pv = if o.completion_fn.is_some() {
+            // zsh runs the command substitution each time the values are completed
+            let mut cmd: Vec<&str> = p.meta.bin_name.as_ref().unwrap().split(' ').collect();
+            cmd.insert(1, completions::COMPLETION_VALUES_FLAG);
+            cmd.push(o.name());
+            format!(": :($({}))", cmd.join(" "))
+        } else if let Some(pv_vec) = o.possible_vals() {
                 format!(": :({})", pv_vec.join(" "))
         } else {
             String::new()
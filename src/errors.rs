@@ -16,8 +16,38 @@ use suggestions;
 /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
 pub type Result<T> = StdResult<T, Error>;
 
-/// Command line argument parser kind of error
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// Command line argument parser kind of error, available as [`Error::kind`] so callers can
+/// branch on the cause of a failed parse.
+///
+/// [`ErrorKind::HelpDisplayed`] and [`ErrorKind::VersionDisplayed`] aren't failures, but the
+/// requested help or version message. They're returned as errors because parsing stops there,
+/// and should usually be treated as success (see [`Error::use_stderr`]).
+///
+/// **NOTE:** New kinds may be added in future releases, so matches on `ErrorKind` should always
+/// include a wildcard arm.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ErrorKind};
+/// let res = App::new("myprog")
+///     .arg(Arg::with_name("input").required(true))
+///     .get_matches_from_safe(vec!["myprog"]);
+///
+/// let code = match res {
+///     Ok(_) => 0,
+///     Err(ref e) if e.kind == ErrorKind::HelpDisplayed ||
+///                   e.kind == ErrorKind::VersionDisplayed => 0,
+///     Err(ref e) if e.kind == ErrorKind::MissingRequiredArgument => 2,
+///     Err(_) => 1,
+/// };
+/// assert_eq!(code, 2);
+/// ```
+/// [`Error::kind`]: ./struct.Error.html#structfield.kind
+/// [`Error::use_stderr`]: ./struct.Error.html#method.use_stderr
+/// [`ErrorKind::HelpDisplayed`]: ./enum.ErrorKind.html#variant.HelpDisplayed
+/// [`ErrorKind::VersionDisplayed`]: ./enum.ErrorKind.html#variant.VersionDisplayed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ErrorKind {
     /// Occurs when an [`Arg`] has a set of possible values,
     /// and the user provides a value which isn't in that set.
@@ -119,6 +149,25 @@ pub enum ErrorKind {
     /// ```
     EmptyValue,
 
+    /// Occurs when the user provides a value for an option which [requires equals] without
+    /// joining the two with an `=`, such as `--color never` instead of `--color=never`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .arg(Arg::with_name("color")
+    ///          .long("color")
+    ///          .takes_value(true)
+    ///          .require_equals(true))
+    ///     .get_matches_from_safe(vec!["myprog", "--color", "never"]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::NoEquals);
+    /// ```
+    /// [requires equals]: ./struct.Arg.html#method.require_equals
+    NoEquals,
+
     /// Occurs when the user provides a value for an argument with a custom validation and the
     /// value fails that validation.
     ///
@@ -291,6 +340,24 @@ pub enum ErrorKind {
     /// ```
     UnexpectedMultipleUsage,
 
+    /// Occurs when the user uses an argument more often than allowed by
+    /// [`Arg::max_occurrences`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::with_name("verbose")
+    ///         .short("v")
+    ///         .max_occurrences(2))
+    ///     .get_matches_from_safe(vec!["myprog", "-vvv"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind, ErrorKind::TooManyOccurrences);
+    /// ```
+    /// [`Arg::max_occurrences`]: ./struct.Arg.html#method.max_occurrences
+    TooManyOccurrences,
+
     /// Occurs when the user provides a value containing invalid UTF-8 for an argument and
     /// [`AppSettings::StrictUtf8`] is set.
     ///
@@ -320,7 +387,7 @@ pub enum ErrorKind {
     InvalidUtf8,
 
     /// Not a true "error" as it means `--help` or similar was used.
-    /// The help message will be sent to `stdout`.
+    /// The help message is the [`Error::message`], and [`Error::exit`] sends it to `stdout`.
     ///
     /// **Note**: If the help is displayed due to an error (such as missing subcommands) it will
     /// be sent to `stderr` instead of `stdout`.
@@ -334,22 +401,53 @@ pub enum ErrorKind {
     /// assert!(result.is_err());
     /// assert_eq!(result.unwrap_err().kind, ErrorKind::HelpDisplayed);
     /// ```
+    /// [`Error::message`]: ./struct.Error.html#structfield.message
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
     HelpDisplayed,
 
     /// Not a true "error" as it means `--version` or similar was used.
-    /// The message will be sent to `stdout`.
+    /// The version information is the [`Error::message`], and [`Error::exit`] sends it to
+    /// `stdout`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use clap::{App, Arg, ErrorKind};
     /// let result = App::new("myprog")
+    ///     .version("1.0")
     ///     .get_matches_from_safe(vec!["myprog", "--version"]);
     /// assert!(result.is_err());
-    /// assert_eq!(result.unwrap_err().kind, ErrorKind::VersionDisplayed);
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::VersionDisplayed);
+    /// assert_eq!(err.message, "myprog 1.0");
     /// ```
+    /// [`Error::message`]: ./struct.Error.html#structfield.message
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
     VersionDisplayed,
 
+    /// Not a true "error" as it means a completion script asked for the values of an argument
+    /// with an [`Arg::completion`] hook. The values, one per line, are the [`Error::message`],
+    /// and [`Error::exit`] sends them to `stdout`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::with_name("host")
+    ///         .long("host")
+    ///         .completion(|| vec!["alpha".to_owned(), "beta".to_owned()]))
+    ///     .get_matches_from_safe(vec!["myprog", "--generate-completion-values", "host"]);
+    /// assert!(result.is_err());
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::CompletionValuesDisplayed);
+    /// assert_eq!(err.message, "alpha\nbeta");
+    /// ```
+    /// [`Arg::completion`]: ./struct.Arg.html#method.completion
+    /// [`Error::message`]: ./struct.Error.html#structfield.message
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
+    CompletionValuesDisplayed,
+
     /// Occurs when using the [`value_t!`] and [`values_t!`] macros to convert an argument value
     /// into type `T`, but the argument you requested wasn't used. I.e. you asked for an argument
     /// with name `config` to be converted, but `config` wasn't used by the user.
@@ -358,7 +456,9 @@ pub enum ErrorKind {
     ArgumentNotFound,
 
     /// Represents an [I/O error].
-    /// Can occur when writing to `stderr` or `stdout` or reading a configuration file.
+    /// Can occur when writing to `stderr` or `stdout`, reading a configuration file, or reading a
+    /// response file used with [`AppSettings::ResponseFiles`].
+    /// [`AppSettings::ResponseFiles`]: ./enum.AppSettings.html#variant.ResponseFiles
     /// [I/O error]: https://doc.rust-lang.org/std/io/struct.Error.html
     Io,
 
@@ -385,20 +485,64 @@ impl Error {
     pub fn use_stderr(&self) -> bool {
         match self.kind {
             ErrorKind::HelpDisplayed |
-            ErrorKind::VersionDisplayed => false,
+            ErrorKind::VersionDisplayed |
+            ErrorKind::CompletionValuesDisplayed => false,
             _ => true,
         }
     }
 
-    /// Prints the error to `stderr` and exits with a status of `1`
+    /// The status [`Error::exit`] exits with, following the GNU convention: `0` when help,
+    /// version information or completion values were displayed, `2` for usage errors (i.e. the
+    /// user supplied invalid arguments), and `1` for any other failure such as an I/O error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let err = App::new("myprog")
+    ///     .arg(Arg::with_name("input").required(true))
+    ///     .get_matches_from_safe(vec!["myprog"])
+    ///     .unwrap_err();
+    /// assert_eq!(err.exit_code(), 2);
+    ///
+    /// let err = App::new("myprog")
+    ///     .get_matches_from_safe(vec!["myprog", "--help"])
+    ///     .unwrap_err();
+    /// assert_eq!(err.exit_code(), 0);
+    /// ```
+    /// [`Error::exit`]: ./struct.Error.html#method.exit
+    pub fn exit_code(&self) -> i32 {
+        match self.kind {
+            ErrorKind::HelpDisplayed |
+            ErrorKind::VersionDisplayed |
+            ErrorKind::CompletionValuesDisplayed => 0,
+            ErrorKind::Io |
+            ErrorKind::Format => 1,
+            _ => 2,
+        }
+    }
+
+    /// Prints the error to `stderr`, or help and version information to `stdout`, and exits with
+    /// the status given by [`Error::exit_code`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("input").required(true))
+    ///     .get_matches_safe()
+    ///     .unwrap_or_else(|e| e.exit());
+    /// ```
+    /// [`Error::exit_code`]: ./struct.Error.html#method.exit_code
     pub fn exit(&self) -> ! {
         if self.use_stderr() {
             wlnerr!("{}", self.message);
-            process::exit(1);
+            process::exit(self.exit_code());
         }
         let out = io::stdout();
         writeln!(&mut out.lock(), "{}", self.message).expect("Error writing Error to stdout");
-        process::exit(0);
+        process::exit(self.exit_code());
     }
 
     #[doc(hidden)]
@@ -406,6 +550,36 @@ impl Error {
         write!(w, "{}", self.message)
     }
 
+    /// Formats the error as a single line JSON object for tools which consume errors
+    /// structurally, such as IDEs or wrapper scripts. The object has a `kind` holding the
+    /// [`ErrorKind`] variant name, an `arg` holding the name of the argument which caused the
+    /// error (or `null`), and the `message` with any color codes removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let err = App::new("myprog")
+    ///     .arg(Arg::with_name("speed")
+    ///         .possible_values(&["fast", "slow"]))
+    ///     .get_matches_from_safe(vec!["myprog", "medium"])
+    ///     .unwrap_err();
+    ///
+    /// let json = err.to_json();
+    /// assert!(json.starts_with(r#"{"kind":"InvalidValue","arg":"speed","message":"#));
+    /// ```
+    /// [`ErrorKind`]: ./enum.ErrorKind.html
+    pub fn to_json(&self) -> String {
+        let arg = match self.info.as_ref().and_then(|i| i.first()) {
+            Some(a) => format!("\"{}\"", json_escape(a)),
+            None => "null".to_owned(),
+        };
+        format!("{{\"kind\":\"{:?}\",\"arg\":{},\"message\":\"{}\"}}",
+                self.kind,
+                arg,
+                json_escape(&*strip_ansi(&*self.message)))
+    }
+
     #[doc(hidden)]
     pub fn argument_conflict<'a, 'b, A, O, U>(arg: &A,
                                               other: Option<O>,
@@ -468,6 +642,29 @@ impl Error {
         }
     }
 
+    #[doc(hidden)]
+    pub fn no_equals<'a, 'b, A, U>(arg: &A, usage: U, color: fmt::ColorWhen) -> Self
+        where A: AnyArg<'a, 'b> + Display,
+              U: Display
+    {
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        Error {
+            message: format!("{} Equal sign is needed when assigning values to '{}'\
+                            \n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(arg.to_string()),
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::NoEquals,
+            info: Some(vec![arg.name().to_owned()]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn invalid_value<'a, 'b, B, G, A, U>(bad_val: B,
                                              good_vals: &[G],
@@ -487,7 +684,8 @@ impl Error {
         let suffix =
             suggestions::did_you_mean_suffix(bad_val.as_ref(),
                                              good_vals.iter(),
-                                             suggestions::DidYouMeanMessageStyle::EnumValue);
+                                             suggestions::DidYouMeanMessageStyle::EnumValue,
+                                             color);
 
         let mut sorted = vec![];
         for v in good_vals {
@@ -551,6 +749,61 @@ impl Error {
         }
     }
 
+    #[doc(hidden)]
+    pub fn ambiguous_subcommand<S, U>(subcmd: S,
+                                      candidates: &[&str],
+                                      usage: U,
+                                      color: fmt::ColorWhen)
+                                      -> Self
+        where S: Into<String>,
+              U: Display
+    {
+        let s = subcmd.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let names = candidates.iter()
+            .map(|n| format!("'{}'", c.good(n)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Error {
+            message: format!("{} The subcommand '{}' is ambiguous\n\t\
+                            It could match any of: {}\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*s),
+                             names,
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::InvalidSubcommand,
+            info: Some(vec![s]),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unknown_help_topic<T>(topic: T, topics: &[&str], color: fmt::ColorWhen) -> Self
+        where T: Into<String>
+    {
+        let t = topic.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let mut sorted = topics.iter().map(|n| format!("{}", c.good(n))).collect::<Vec<_>>();
+        sorted.sort();
+        Error {
+            message: format!("{} '{}' isn't a known help topic\n\t\
+                            [topics: {}]",
+                             c.error("error:"),
+                             c.warning(&*t),
+                             sorted.join(", ")),
+            kind: ErrorKind::InvalidValue,
+            info: Some(vec![t]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn unrecognized_subcommand<S, N>(subcmd: S, name: N, color: fmt::ColorWhen) -> Self
         where S: Into<String>,
@@ -598,6 +851,80 @@ impl Error {
         }
     }
 
+    #[doc(hidden)]
+    pub fn missing_required_if<R, A, V, U>(required: R,
+                                           arg: A,
+                                           val: V,
+                                           usage: U,
+                                           color: fmt::ColorWhen)
+                                           -> Self
+        where R: Into<String>,
+              A: Display,
+              V: Display,
+              U: Display
+    {
+        let r = required.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        Error {
+            message: format!("{} The argument '{}' is required when '{}' is used with the value \
+                            '{}'\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*r),
+                             c.warning(arg.to_string()),
+                             c.warning(val.to_string()),
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::MissingRequiredArgument,
+            info: Some(vec![r]),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn wrong_number_of_group_args<G, U>(group: G,
+                                            min: usize,
+                                            max: usize,
+                                            used: &[String],
+                                            usage: U,
+                                            color: fmt::ColorWhen)
+                                            -> Self
+        where G: Into<String>,
+              U: Display
+    {
+        let g = group.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        Error {
+            message: format!("{} The group '{}' requires between {} and {} of its arguments, \
+                            but {} {} supplied{}\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*g),
+                             c.warning(min.to_string()),
+                             c.warning(max.to_string()),
+                             c.warning(used.len().to_string()),
+                             if used.len() == 1 { "was" } else { "were" },
+                             used.iter().fold(String::new(), |acc, u| {
+                                 acc + &format!("\n    {}", c.warning(&**u))[..]
+                             }),
+                             usage,
+                             c.good("--help")),
+            kind: if used.len() < min {
+                ErrorKind::MissingRequiredArgument
+            } else {
+                ErrorKind::ArgumentConflict
+            },
+            info: Some(vec![g]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn missing_subcommand<N, U>(name: N, usage: U, color: fmt::ColorWhen) -> Self
         where N: AsRef<str> + Display,
@@ -720,6 +1047,35 @@ impl Error {
         Error::value_validation(err, fmt::ColorWhen::Auto)
     }
 
+    #[doc(hidden)]
+    pub fn invalid_injected_value<'a, 'b, V, S, A, R>(val: V,
+                                                      source: S,
+                                                      arg: &A,
+                                                      reason: R,
+                                                      kind: ErrorKind,
+                                                      color: fmt::ColorWhen)
+                                                      -> Self
+        where V: AsRef<str>,
+              S: Display,
+              A: AnyArg<'a, 'b> + Display,
+              R: Display
+    {
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        Error {
+            message: format!("{} Invalid value '{}' from {} for '{}': {}",
+                             c.error("error:"),
+                             c.warning(val.as_ref()),
+                             source,
+                             c.warning(arg.to_string()),
+                             reason),
+            kind: kind,
+            info: Some(vec![arg.name().to_owned()]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn wrong_number_of_values<'a, 'b, A, S, U>(arg: &A,
                                                    num_vals: u64,
@@ -776,6 +1132,36 @@ impl Error {
         }
     }
 
+    #[doc(hidden)]
+    pub fn too_many_occurrences<'a, 'b, A, U>(arg: &A,
+                                              max_occurs: u64,
+                                              curr_occurs: u64,
+                                              usage: U,
+                                              color: fmt::ColorWhen)
+                                              -> Self
+        where A: AnyArg<'a, 'b> + Display,
+              U: Display
+    {
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        Error {
+            message: format!("{} The argument '{}' may be used at most {} times, but was used \
+                            {} times\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(arg.to_string()),
+                             c.warning(max_occurs.to_string()),
+                             c.warning(curr_occurs.to_string()),
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::TooManyOccurrences,
+            info: Some(vec![arg.name().to_owned()]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn unknown_argument<A, U>(arg: A,
                                   did_you_mean: &str,
@@ -809,6 +1195,39 @@ impl Error {
         }
     }
 
+    #[doc(hidden)]
+    pub fn ambiguous_argument<A, U>(arg: A,
+                                    candidates: &[&str],
+                                    usage: U,
+                                    color: fmt::ColorWhen)
+                                    -> Self
+        where A: Into<String>,
+              U: Display
+    {
+        let a = arg.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let names = candidates.iter()
+            .map(|n| format!("'{}{}'", c.good("--"), c.good(n)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Error {
+            message: format!("{} The argument '{}' is ambiguous\n\t\
+                            It could match any of: {}\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*a),
+                             names,
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::UnknownArgument,
+            info: Some(vec![a]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn io_error(e: &Error, color: fmt::ColorWhen) -> Self {
         let c = fmt::Colorizer {
@@ -822,6 +1241,26 @@ impl Error {
         }
     }
 
+    #[doc(hidden)]
+    pub fn response_file<P, R>(path: P, reason: R, color: fmt::ColorWhen) -> Self
+        where P: Into<String>,
+              R: Display
+    {
+        let p = path.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        Error {
+            message: format!("{} Failed to read arguments from file '{}': {}",
+                             c.error("error:"),
+                             c.warning(&*p),
+                             reason),
+            kind: ErrorKind::Io,
+            info: Some(vec![p]),
+        }
+    }
+
     #[doc(hidden)]
     pub fn argument_not_found_auto<A>(arg: A) -> Self
         where A: Into<String>
@@ -880,3 +1319,35 @@ impl From<std_fmt::Error> for Error {
         Error::with_description(e.description(), ErrorKind::Format)
     }
 }
+
+// Removes the ANSI escape sequences used for colored output
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_escape = false;
+    for c in s.chars() {
+        if in_escape {
+            in_escape = !c.is_alphabetic();
+        } else if c == '\x1b' {
+            in_escape = true;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&*format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
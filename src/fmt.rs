@@ -6,6 +6,8 @@ use ansi_term::Colour::{Green, Red, Yellow};
 
 #[cfg(feature = "color")]
 use libc;
+use std::env;
+use std::ffi::OsString;
 use std::fmt;
 
 #[cfg(all(feature = "color", not(target_os = "windows")))]
@@ -26,6 +28,20 @@ pub enum ColorWhen {
     Never,
 }
 
+/// Represents when help and error messages should be colored, as set with [`App::color`].
+///
+/// [`App::color`]: ./struct.App.html#method.color
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColorChoice {
+    /// Colors the output only when it goes to a terminal/TTY, honoring the `NO_COLOR`,
+    /// `CLICOLOR` and `CLICOLOR_FORCE` environment variables. This is the default.
+    Auto,
+    /// Always colors the output, even if it is redirected to a file or pipe.
+    Always,
+    /// Never colors the output.
+    Never,
+}
+
 #[cfg(feature = "color")]
 pub fn is_a_tty(stderr: bool) -> bool {
     debugln!("fn=is_a_tty;");
@@ -40,6 +56,34 @@ pub fn is_a_tty(_: bool) -> bool {
     false
 }
 
+// Whether `ColorWhen::Auto` should color output. See https://no-color.org for `NO_COLOR`, and
+// https://bixense.com/clicolors for `CLICOLOR` and `CLICOLOR_FORCE`
+pub fn should_color(stderr: bool) -> bool {
+    auto_color(is_a_tty(stderr),
+               env::var_os("NO_COLOR"),
+               env::var_os("CLICOLOR"),
+               env::var_os("CLICOLOR_FORCE"))
+}
+
+// In order of precedence: a non-empty `NO_COLOR` disables color, a `CLICOLOR_FORCE` which is
+// non-empty and not `0` forces color, `CLICOLOR=0` disables color, and otherwise only a TTY is
+// colored
+fn auto_color(tty: bool,
+              no_color: Option<OsString>,
+              clicolor: Option<OsString>,
+              clicolor_force: Option<OsString>)
+              -> bool {
+    if no_color.map_or(false, |v| !v.is_empty()) {
+        false
+    } else if clicolor_force.map_or(false, |v| !v.is_empty() && v.to_str() != Some("0")) {
+        true
+    } else if clicolor.map_or(false, |v| v.to_str() == Some("0")) {
+        false
+    } else {
+        tty
+    }
+}
+
 #[doc(hidden)]
 pub struct Colorizer {
     pub use_stderr: bool,
@@ -49,7 +93,7 @@ pub struct Colorizer {
 macro_rules! color {
     ($_self:ident, $c:ident, $m:expr) => {
         match $_self.when {
-            ColorWhen::Auto => if is_a_tty($_self.use_stderr) {
+            ColorWhen::Auto => if should_color($_self.use_stderr) {
                 Format::$c($m)
             } else {
                 Format::None($m)
@@ -154,6 +198,61 @@ impl<T: fmt::Display> fmt::Display for Format<T> {
     }
 }
 
+#[cfg(test)]
+mod auto_test {
+    use std::ffi::OsString;
+    use super::auto_color;
+
+    fn os(s: &str) -> Option<OsString> {
+        Some(OsString::from(s))
+    }
+
+    #[test]
+    fn auto_colors_tty() {
+        assert!(auto_color(true, None, None, None));
+    }
+
+    #[test]
+    fn auto_redirected_not_colored() {
+        assert!(!auto_color(false, None, None, None));
+    }
+
+    #[test]
+    fn auto_no_color_set() {
+        assert!(!auto_color(true, os("1"), None, None));
+    }
+
+    #[test]
+    fn auto_no_color_empty() {
+        assert!(auto_color(true, os(""), None, None));
+    }
+
+    #[test]
+    fn auto_clicolor_zero() {
+        assert!(!auto_color(true, None, os("0"), None));
+        assert!(auto_color(true, None, os("1"), None));
+        assert!(!auto_color(false, None, os("1"), None));
+    }
+
+    #[test]
+    fn auto_clicolor_force() {
+        assert!(auto_color(false, None, None, os("1")));
+        assert!(!auto_color(false, None, None, os("0")));
+        assert!(!auto_color(false, None, None, os("")));
+    }
+
+    #[test]
+    fn auto_clicolor_force_beats_clicolor() {
+        assert!(auto_color(false, None, os("0"), os("1")));
+    }
+
+    #[test]
+    fn auto_no_color_beats_clicolor_force() {
+        assert!(!auto_color(true, os("1"), None, os("1")));
+        assert!(auto_color(false, os(""), None, os("1")));
+    }
+}
+
 #[cfg(all(test, feature = "color", not(target_os = "windows")))]
 mod test {
     use ansi_term::ANSIString;
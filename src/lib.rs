@@ -424,9 +424,10 @@ extern crate unicode_segmentation;
 
 #[cfg(feature = "yaml")]
 pub use yaml_rust::YamlLoader;
-pub use args::{Arg, ArgGroup, ArgMatches, ArgSettings, SubCommand, Values, OsValues};
+pub use args::{Arg, ArgGroup, ArgMatches, ArgSettings, SubCommand, Values, OsValues, ValueSource};
+pub use args::{FromArgMatches, parse_field, parse_field_opt, parse_fields};
 pub use app::{App, AppSettings};
-pub use fmt::Format;
+pub use fmt::{ColorChoice, Format};
 pub use errors::{Error, ErrorKind, Result};
 pub use completions::Shell;
 
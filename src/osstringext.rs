@@ -1,6 +1,6 @@
 #[cfg(target_os = "windows")]
 use INVALID_UTF8;
-use std::ffi::OsStr;
+use std::ffi::{OsStr, OsString};
 #[cfg(not(target_os = "windows"))]
 use std::os::unix::ffi::OsStrExt;
 
@@ -142,3 +142,27 @@ impl<'a> DoubleEndedIterator for OsSplit<'a> {
         Some(OsStr::from_bytes(&self.val[..start]))
     }
 }
+
+/// Replaces both `/` and `\` with the path separator of the current platform
+#[cfg(not(target_os = "windows"))]
+pub fn normalize_path_separators(val: &OsStr) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    use std::path::MAIN_SEPARATOR;
+    let sep = MAIN_SEPARATOR as u8;
+    OsString::from_vec(val.as_bytes()
+        .iter()
+        .map(|&b| if b == b'/' || b == b'\\' { sep } else { b })
+        .collect())
+}
+
+/// Replaces both `/` and `\` with the path separator of the current platform
+#[cfg(target_os = "windows")]
+pub fn normalize_path_separators(val: &OsStr) -> OsString {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+    use std::path::MAIN_SEPARATOR;
+    let sep = MAIN_SEPARATOR as u16;
+    let wide: Vec<u16> = val.encode_wide()
+        .map(|c| if c == '/' as u16 || c == '\\' as u16 { sep } else { c })
+        .collect();
+    OsString::from_wide(&wide)
+}
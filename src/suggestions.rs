@@ -3,7 +3,7 @@
 use strsim;
 
 // Internal
-use fmt::Format;
+use fmt::{ColorWhen, Colorizer};
 
 /// Produces a string from a given list of possible values which is similar to
 /// the passed in value `v` with a certain confidence.
@@ -42,21 +42,24 @@ pub fn did_you_mean<'a, T, I>(_: &str, _: I) -> Option<&'a str>
 #[cfg_attr(feature = "lints", allow(needless_lifetimes))]
 pub fn did_you_mean_suffix<'z, T, I>(arg: &str,
                                      values: I,
-                                     style: DidYouMeanMessageStyle)
+                                     style: DidYouMeanMessageStyle,
+                                     color: ColorWhen)
                                      -> (String, Option<&'z str>)
     where T: AsRef<str> + 'z,
           I: IntoIterator<Item = &'z T>
 {
+    let c = Colorizer {
+        use_stderr: true,
+        when: color,
+    };
     match did_you_mean(arg, values) {
         Some(candidate) => {
             let mut suffix = "\n\tDid you mean ".to_owned();
             match style {
-                DidYouMeanMessageStyle::LongFlag => {
-                    suffix.push_str(&Format::Good("--").to_string())
-                }
+                DidYouMeanMessageStyle::LongFlag => suffix.push_str(&c.good("--").to_string()),
                 DidYouMeanMessageStyle::EnumValue => suffix.push('\''),
             }
-            suffix.push_str(&Format::Good(candidate).to_string()[..]);
+            suffix.push_str(&c.good(candidate).to_string()[..]);
             if let DidYouMeanMessageStyle::EnumValue = style {
                 suffix.push('\'');
             }
@@ -75,7 +78,7 @@ pub enum DidYouMeanMessageStyle {
     EnumValue,
 }
 
-#[cfg(all(test, features = "suggestions"))]
+#[cfg(all(test, feature = "suggestions"))]
 mod test {
     use super::*;
 
@@ -91,19 +94,41 @@ mod test {
         assert!(did_you_mean("hahaahahah", p_vals.iter()).is_none());
     }
 
+    #[test]
+    fn possible_values_best_match() {
+        let p_vals = ["colour", "color", "column"];
+        assert_eq!(did_you_mean("colr", p_vals.iter()), Some("color"));
+    }
+
     #[test]
     fn suffix_long() {
         let p_vals = ["test", "possible", "values"];
-        let suffix = "\n\tDid you mean \'--test\'?";
-        assert_eq!(did_you_mean_suffix("tst", p_vals.iter(), DidYouMeanMessageStyle::LongFlag),
-                   (suffix, Some("test")));
+        let (suffix, candidate) = did_you_mean_suffix("tst",
+                                                      p_vals.iter(),
+                                                      DidYouMeanMessageStyle::LongFlag,
+                                                      ColorWhen::Never);
+        assert_eq!(suffix, "\n\tDid you mean --test?");
+        assert_eq!(candidate, Some("test"));
     }
 
     #[test]
     fn suffix_enum() {
         let p_vals = ["test", "possible", "values"];
-        let suffix = "\n\tDid you mean \'test\'?";
-        assert_eq!(did_you_mean_suffix("tst", p_vals.iter(), DidYouMeanMessageStyle::EnumValue),
-                   (suffix, Some("test")));
+        let (suffix, candidate) = did_you_mean_suffix("tst",
+                                                      p_vals.iter(),
+                                                      DidYouMeanMessageStyle::EnumValue,
+                                                      ColorWhen::Never);
+        assert_eq!(suffix, "\n\tDid you mean 'test'?");
+        assert_eq!(candidate, Some("test"));
+    }
+
+    #[test]
+    fn suffix_nomatch() {
+        let p_vals = ["test", "possible", "values"];
+        assert_eq!(did_you_mean_suffix("hahaahahah",
+                                       p_vals.iter(),
+                                       DidYouMeanMessageStyle::LongFlag,
+                                       ColorWhen::Never),
+                   (String::new(), None));
     }
 }
@@ -1,3 +1,6 @@
+// Std
+use std::ffi::OsStr;
+
 // Third Party
 use vec_map::VecMap;
 
@@ -82,7 +85,15 @@ impl<'a> UsageParser<'a> {
         }
         self.pos += 1;
         self.stop_at(name_end);
-        let name = &self.usage[self.start..self.pos];
+        let mut name = &self.usage[self.start..self.pos];
+        // "[NAME=default]"
+        if let Some(i) = name.find('=') {
+            let default = &name[i + 1..];
+            debugln!("setting default value: {}", default);
+            arg.default_val = Some(OsStr::new(default));
+            arg.setb(ArgSettings::TakesValue);
+            name = &name[..i];
+        }
         if self.prev == UsageToken::Unknown {
             debugln!("setting name: {}", name);
             arg.name = name;
@@ -213,6 +224,8 @@ fn help_start(b: u8) -> bool {
 
 #[cfg(test)]
 mod test {
+    use std::ffi::OsStr;
+
     use args::Arg;
     use args::ArgSettings;
 
@@ -1177,6 +1190,57 @@ mod test {
         assert!(c.num_vals.is_none());
     }
 
+    #[test]
+    fn opt_default_value() {
+        let a = Arg::from_usage("--level [LEVEL=info] 'sets the level'");
+        assert_eq!(a.name, "level");
+        assert_eq!(a.long, Some("level"));
+        assert!(!a.is_set(ArgSettings::Required));
+        assert!(a.is_set(ArgSettings::TakesValue));
+        assert_eq!(a.val_names.unwrap().values().collect::<Vec<_>>(), [&"LEVEL"]);
+        assert_eq!(a.default_val, Some(OsStr::new("info")));
+        assert_eq!(a.help, Some("sets the level"));
+    }
+
+    #[test]
+    fn opt_default_value_eq() {
+        let a = Arg::from_usage("-l, --level=[LEVEL=info]");
+        assert_eq!(a.name, "level");
+        assert_eq!(a.short, Some('l'));
+        assert_eq!(a.val_names.unwrap().values().collect::<Vec<_>>(), [&"LEVEL"]);
+        assert_eq!(a.default_val, Some(OsStr::new("info")));
+    }
+
+    #[test]
+    fn opt_val_name_no_default() {
+        let a = Arg::from_usage("--output [FILE] 'output file'");
+        assert_eq!(a.name, "output");
+        assert!(!a.is_set(ArgSettings::Required));
+        assert_eq!(a.val_names.unwrap().values().collect::<Vec<_>>(), [&"FILE"]);
+        assert!(a.default_val.is_none());
+
+        let b = Arg::from_usage("--output <FILE> 'output file'");
+        assert!(b.is_set(ArgSettings::Required));
+        assert_eq!(b.val_names.unwrap().values().collect::<Vec<_>>(), [&"FILE"]);
+    }
+
+    #[test]
+    fn opt_default_value_empty() {
+        let a = Arg::from_usage("--sep [SEP=] 'separator'");
+        assert_eq!(a.val_names.unwrap().values().collect::<Vec<_>>(), [&"SEP"]);
+        assert_eq!(a.default_val, Some(OsStr::new("")));
+    }
+
+    #[test]
+    fn pos_default_value() {
+        let a = Arg::from_usage("[input=in.txt] 'the input'");
+        assert_eq!(a.name, "input");
+        assert!(!a.is_set(ArgSettings::Required));
+        assert!(a.val_names.is_none());
+        assert_eq!(a.default_val, Some(OsStr::new("in.txt")));
+        assert_eq!(a.help, Some("the input"));
+    }
+
     #[test]
     fn nonascii() {
         let a = Arg::from_usage("<ASCII> 'üñíčöĐ€'");
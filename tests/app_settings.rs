@@ -371,3 +371,470 @@ fn test_unset_settings() {
     assert!(!m.p.is_set(AppSettings::AllowInvalidUtf8));
     assert!(!m.p.is_set(AppSettings::ColorAuto));
 }
+
+#[test]
+fn sub_command_precedence_over_arg() {
+    let m = App::new("sc_prec")
+        .setting(AppSettings::SubcommandPrecedenceOverArg)
+        .arg(Arg::with_name("files").multiple(true))
+        .subcommand(SubCommand::with_name("sync"))
+        .get_matches_from(vec!["sc_prec", "a.txt", "b.txt", "sync"]);
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), &["a.txt", "b.txt"]);
+    assert_eq!(m.subcommand_name(), Some("sync"));
+}
+
+#[test]
+fn sub_command_no_precedence_over_arg() {
+    let m = App::new("sc_prec")
+        .arg(Arg::with_name("files").multiple(true))
+        .subcommand(SubCommand::with_name("sync"))
+        .get_matches_from(vec!["sc_prec", "a.txt", "b.txt", "sync"]);
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), &["a.txt", "b.txt", "sync"]);
+    assert!(m.subcommand_name().is_none());
+}
+
+#[test]
+fn sub_command_precedence_first_arg() {
+    let m = App::new("sc_prec")
+        .arg(Arg::with_name("files").multiple(true))
+        .subcommand(SubCommand::with_name("sync"))
+        .get_matches_from(vec!["sc_prec", "sync"]);
+    assert!(!m.is_present("files"));
+    assert_eq!(m.subcommand_name(), Some("sync"));
+}
+
+#[test]
+fn allow_negative_numbers_opt_val() {
+    let res = App::new("negnum")
+        .setting(AppSettings::AllowNegativeNumbers)
+        .arg(Arg::with_name("offset")
+            .long("offset")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["negnum", "--offset", "-5"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind);
+    assert_eq!(res.unwrap().value_of("offset").unwrap(), "-5");
+}
+
+#[test]
+fn allow_negative_numbers_opt_float_val() {
+    let res = App::new("negnum")
+        .setting(AppSettings::AllowNegativeNumbers)
+        .arg(Arg::with_name("offset")
+            .long("offset")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["negnum", "--offset", "-5.5"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind);
+    assert_eq!(res.unwrap().value_of("offset").unwrap(), "-5.5");
+}
+
+#[test]
+fn allow_negative_numbers_opt_non_numeric_val() {
+    let res = App::new("negnum")
+        .setting(AppSettings::AllowNegativeNumbers)
+        .arg(Arg::with_name("offset")
+            .long("offset")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["negnum", "--offset", "-notaflag"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn allow_negative_numbers_opt_flag_not_swallowed() {
+    let res = App::new("negnum")
+        .setting(AppSettings::AllowNegativeNumbers)
+        .arg(Arg::with_name("offset")
+            .long("offset")
+            .takes_value(true)
+            .min_values(0))
+        .arg(Arg::with_name("verbose")
+            .short("v"))
+        .get_matches_from_safe(vec!["negnum", "--offset", "-v"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind);
+    let m = res.unwrap();
+    assert!(m.is_present("verbose"));
+    assert!(m.value_of("offset").is_none());
+}
+
+#[test]
+fn infer_long_args_unambiguous_prefix() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("verify").long("verify"))
+        .arg(Arg::with_name("color").long("color").alias("colour").takes_value(true))
+        .arg(Arg::with_name("col").long("col"))
+        .get_matches_from(vec!["prog", "--verb", "--colo", "never"]);
+
+    assert!(m.is_present("verbose"));
+    assert!(!m.is_present("verify"));
+    assert_eq!(m.value_of("color"), Some("never"));
+}
+
+#[test]
+fn infer_long_args_prefix_with_equals() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("verify").long("verify"))
+        .arg(Arg::with_name("color").long("color").alias("colour").takes_value(true))
+        .arg(Arg::with_name("col").long("col"))
+        .get_matches_from(vec!["prog", "--colou=always"]);
+
+    assert_eq!(m.value_of("color"), Some("always"));
+}
+
+#[test]
+fn infer_long_args_ambiguous_prefix() {
+    let err = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("verify").long("verify"))
+        .arg(Arg::with_name("color").long("color").alias("colour").takes_value(true))
+        .arg(Arg::with_name("col").long("col"))
+        .get_matches_from_safe(vec!["prog", "--ver"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert!(err.message.contains("The argument '--ver' is ambiguous"));
+    assert!(err.message.contains("'--verbose', '--verify'"));
+    // the automatically generated --version flag is a candidate too
+    assert!(err.message.contains("'--version'"));
+}
+
+#[test]
+fn infer_long_args_exact_match_not_ambiguous() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("verify").long("verify"))
+        .arg(Arg::with_name("color").long("color").alias("colour").takes_value(true))
+        .arg(Arg::with_name("col").long("col"))
+        .get_matches_from(vec!["prog", "--col"]);
+
+    assert!(m.is_present("col"));
+    assert!(!m.is_present("color"));
+}
+
+#[test]
+fn infer_long_args_not_set() {
+    let res = App::new("prog")
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .get_matches_from_safe(vec!["prog", "--verb"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn trailing_var_arg_captures_flags_verbatim() {
+    let m = App::new("wrapper")
+        .setting(AppSettings::TrailingVarArg)
+        .arg(Arg::with_name("x").short("x"))
+        .arg(Arg::with_name("y").long("y"))
+        .arg(Arg::with_name("cmd").required(true))
+        .arg(Arg::with_name("args").multiple(true))
+        .get_matches_from(vec!["", "-x", "time", "run", "-x", "--y", "--", "z"]);
+
+    assert!(m.is_present("x"));
+    assert_eq!(m.occurrences_of("x"), 1);
+    assert!(!m.is_present("y"));
+    assert_eq!(m.value_of("cmd"), Some("time"));
+    assert_eq!(m.values_of("args").unwrap().collect::<Vec<_>>(),
+               &["run", "-x", "--y", "--", "z"]);
+}
+
+#[test]
+fn trailing_var_arg_flags_before_trailing_still_parsed() {
+    let m = App::new("wrapper")
+        .setting(AppSettings::TrailingVarArg)
+        .arg(Arg::with_name("x").short("x"))
+        .arg(Arg::with_name("y").long("y"))
+        .arg(Arg::with_name("cmd").required(true))
+        .arg(Arg::with_name("args").multiple(true))
+        .get_matches_from(vec!["", "time", "--y", "run", "-x"]);
+
+    assert!(m.is_present("y"));
+    assert!(!m.is_present("x"));
+    assert_eq!(m.values_of("args").unwrap().collect::<Vec<_>>(), &["run", "-x"]);
+}
+
+#[test]
+fn trailing_var_arg_not_set() {
+    let m = App::new("wrapper")
+        .arg(Arg::with_name("x").short("x"))
+        .arg(Arg::with_name("args").multiple(true))
+        .get_matches_from(vec!["", "run", "-x"]);
+
+    assert!(m.is_present("x"));
+    assert_eq!(m.values_of("args").unwrap().collect::<Vec<_>>(), &["run"]);
+}
+
+#[test]
+fn app_is_set() {
+    let app = App::new("test")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .settings(&[AppSettings::NextLineHelp, AppSettings::TrailingVarArg])
+        .global_setting(AppSettings::ColoredHelp);
+
+    assert!(app.is_set(AppSettings::ArgRequiredElseHelp));
+    assert!(app.is_set(AppSettings::NextLineHelp));
+    assert!(app.is_set(AppSettings::TrailingVarArg));
+    assert!(app.is_set(AppSettings::ColoredHelp));
+    assert!(!app.is_set(AppSettings::SubcommandRequired));
+
+    let app = app.unset_setting(AppSettings::NextLineHelp);
+    assert!(!app.is_set(AppSettings::NextLineHelp));
+    assert!(app.is_set(AppSettings::TrailingVarArg));
+}
+
+#[test]
+fn app_is_set_global_setting_propagated() {
+    let mut app = App::new("test")
+        .global_setting(AppSettings::ColoredHelp)
+        .subcommand(SubCommand::with_name("sub"));
+    assert!(!app.p.subcommands[0].is_set(AppSettings::ColoredHelp));
+
+    app.p.propogate_settings();
+    assert!(app.is_set(AppSettings::ColoredHelp));
+    assert!(app.p.subcommands[0].is_set(AppSettings::ColoredHelp));
+}
+
+#[test]
+fn global_setting_reaches_nested_subcommands() {
+    let mut app = App::new("test")
+        .version("1.0")
+        .global_setting(AppSettings::DisableVersion)
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")
+                .subcommand(SubCommand::with_name("innermost"))));
+    app.p.propogate_settings();
+
+    let outer = &app.p.subcommands[0];
+    let inner = &outer.p.subcommands[0];
+    let innermost = &inner.p.subcommands[0];
+    assert!(outer.is_set(AppSettings::DisableVersion));
+    assert!(inner.is_set(AppSettings::DisableVersion));
+    assert!(innermost.is_set(AppSettings::DisableVersion));
+}
+
+#[test]
+fn global_setting_applies_when_parsing_nested_subcommands() {
+    let res = App::new("test")
+        .version("1.0")
+        .global_setting(AppSettings::DisableVersion)
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")
+                .subcommand(SubCommand::with_name("innermost"))))
+        .get_matches_from_safe(vec!["test", "outer", "inner", "innermost", "-V"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn sub_command_required_else_help_shows_help() {
+    let err = App::new("tool")
+        .setting(AppSettings::ColorNever)
+        .subcommand_required_else_help(true)
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("build").about("Builds things"))
+        .get_matches_from_safe(vec!["tool"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingArgumentOrSubcommand);
+    // printed to stderr, which also means a non-zero exit status
+    assert!(err.use_stderr());
+    assert!(err.message.contains("USAGE:"));
+    assert!(err.message.contains("Builds things"));
+}
+
+#[test]
+fn sub_command_required_else_help_with_only_args() {
+    let err = App::new("tool")
+        .setting(AppSettings::ColorNever)
+        .subcommand_required_else_help(true)
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("build").about("Builds things"))
+        .get_matches_from_safe(vec!["tool", "-v"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingArgumentOrSubcommand);
+}
+
+#[test]
+fn sub_command_required_else_help_with_subcommand() {
+    let m = App::new("tool")
+        .setting(AppSettings::ColorNever)
+        .subcommand_required_else_help(true)
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("build").about("Builds things"))
+        .get_matches_from(vec!["tool", "-v", "build"]);
+
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.subcommand_name(), Some("build"));
+}
+
+#[test]
+fn sub_command_required_else_help_unset() {
+    let app = App::new("tool")
+        .setting(AppSettings::ColorNever)
+        .subcommand_required_else_help(true)
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("build").about("Builds things"))
+        .subcommand_required_else_help(false);
+    assert!(!app.is_set(AppSettings::SubcommandRequiredElseHelp));
+
+    let m = app.get_matches_from(vec!["tool"]);
+    assert!(m.subcommand_name().is_none());
+}
+
+#[test]
+fn arg_required_else_help_bare_invocation() {
+    let err = App::new("leaf")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("input").help("The input file"))
+        .arg(Arg::with_name("level").long("level").default_value("1"))
+        .get_matches_from_safe(vec!["leaf"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingArgumentOrSubcommand);
+    assert!(err.use_stderr());
+    assert!(err.message.contains("USAGE:"));
+    assert!(err.message.contains("The input file"));
+}
+
+#[test]
+fn arg_required_else_help_any_arg_proceeds() {
+    let app = App::new("leaf")
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("input").help("The input file"))
+        .arg(Arg::with_name("level").long("level").default_value("1"));
+    let m = app.clone().get_matches_from(vec!["leaf", "--level", "2"]);
+    assert_eq!(m.value_of("level"), Some("2"));
+    assert!(!m.is_present("input"));
+
+    let m = app.get_matches_from(vec!["leaf", "in.txt"]);
+    assert_eq!(m.value_of("input"), Some("in.txt"));
+    assert_eq!(m.value_of("level"), Some("1"));
+}
+
+#[test]
+fn no_bin_name_first_token_is_parsed() {
+    let m = App::new("repl")
+        .setting(AppSettings::NoBinaryName)
+        .arg(Arg::with_name("flag").long("flag").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("set").arg(Arg::with_name("key")))
+        .get_matches_from(vec!["--flag", "value"]);
+
+    assert_eq!(m.value_of("flag"), Some("value"));
+    assert!(!m.is_present("verbose"));
+}
+
+#[test]
+fn no_bin_name_split_line() {
+    let m = App::new("repl")
+        .setting(AppSettings::NoBinaryName)
+        .arg(Arg::with_name("flag").long("flag").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("set").arg(Arg::with_name("key")))
+        .get_matches_from("-v set name".split_whitespace());
+
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.subcommand_matches("set").unwrap().value_of("key"), Some("name"));
+}
+
+#[test]
+fn no_bin_name_empty_line() {
+    let m = App::new("repl")
+        .setting(AppSettings::NoBinaryName)
+        .arg(Arg::with_name("flag").long("flag").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .subcommand(SubCommand::with_name("set").arg(Arg::with_name("key")))
+        .get_matches_from(Vec::<&str>::new());
+
+    assert!(!m.is_present("flag"));
+    assert!(m.subcommand_name().is_none());
+}
+
+#[test]
+fn bin_name_swallows_first_token_without_setting() {
+    let res = App::new("repl")
+        .arg(Arg::with_name("flag").long("flag").takes_value(true))
+        .get_matches_from_safe(vec!["--flag", "value"]);
+
+    // "--flag" is taken as the binary name, leaving "value" unexpected
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn disable_help_flags() {
+    let app = App::new("prog").setting(AppSettings::DisableHelpFlags);
+
+    let res = app.clone().get_matches_from_safe(vec!["prog", "--help"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+
+    let res = app.get_matches_from_safe(vec!["prog", "-h"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn disable_help_flags_not_in_help() {
+    let mut help = vec![];
+    App::new("prog")
+        .setting(AppSettings::DisableHelpFlags)
+        .arg(Arg::with_name("verbose").short("v").help("be verbose"))
+        .write_help(&mut help)
+        .unwrap();
+    let help = String::from_utf8(help).unwrap();
+
+    assert!(help.contains("-v"));
+    assert!(!help.contains("--help"));
+    assert!(help.contains("-V, --version"));
+}
+
+#[test]
+fn disable_help_flags_user_defined() {
+    let app = App::new("prog")
+        .setting(AppSettings::DisableHelpFlags)
+        .help_short("H")
+        .arg(Arg::with_name("host").short("h").takes_value(true))
+        .arg(Arg::with_name("hold").short("H"));
+
+    let m = app.get_matches_from(vec!["prog", "-h", "localhost", "-H"]);
+    assert_eq!(m.value_of("host"), Some("localhost"));
+    assert!(m.is_present("hold"));
+}
+
+#[test]
+fn disable_help_flags_subcommand_unaffected() {
+    let res = App::new("prog")
+        .setting(AppSettings::DisableHelpFlags)
+        .subcommand(SubCommand::with_name("sub"))
+        .get_matches_from_safe(vec!["prog", "sub", "--help"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::HelpDisplayed);
+}
+
+#[test]
+fn disable_version_user_defined_short() {
+    let m = App::new("prog")
+        .version("1.0")
+        .setting(AppSettings::DisableVersion)
+        .arg(Arg::with_name("verbose").short("V").multiple(true))
+        .get_matches_from(vec!["prog", "-VV"]);
+    assert_eq!(m.occurrences_of("verbose"), 2);
+
+    let res = App::new("prog")
+        .version("1.0")
+        .setting(AppSettings::DisableVersion)
+        .get_matches_from_safe(vec!["prog", "--version"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
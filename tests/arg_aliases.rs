@@ -12,7 +12,9 @@ USAGE:
     test [FLAGS] [OPTIONS]
 
 FLAGS:
-    -f, --flag     [aliases: v_flg, flag2, flg3]
+    -f, --flag        [aliases: v_flg, flag2, flg3]
+    -h, --help       Prints help information
+    -V, --version    Prints version information
 
 OPTIONS:
     -o, --opt <opt>     [aliases: visible]";
@@ -24,7 +26,9 @@ USAGE:
     test [FLAGS] [OPTIONS]
 
 FLAGS:
-    -f, --flag    
+    -f, --flag       
+    -h, --help       Prints help information
+    -V, --version    Prints version information
 
 OPTIONS:
     -o, --opt <opt>    ";
@@ -192,3 +196,70 @@ fn visible_arg_aliases_help_output() {
                 .visible_aliases(&["v_flg", "flag2", "flg3"])));
     test::check_subcommand_help(app, "test", SC_VISIBLE_ALIAS_HELP);
 }
+
+#[test]
+fn option_value_by_each_alias() {
+    for spelling in &["--color", "--colour", "--colr"] {
+        let m = App::new("test")
+            .arg(Arg::with_name("color")
+                .long("color")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .visible_alias("colour")
+                .alias("colr"))
+            .arg(Arg::with_name("verbose")
+                .long("verbose")
+                .multiple(true)
+                .alias("chatty"))
+            .get_matches_from_safe(vec!["test", spelling, "auto"]).unwrap();
+
+        assert!(m.is_present("color"));
+        assert_eq!(m.value_of("color"), Some("auto"));
+    }
+}
+
+#[test]
+fn option_alias_equals_value() {
+    let m = App::new("test")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .visible_alias("colour")
+            .alias("colr"))
+        .arg(Arg::with_name("verbose")
+            .long("verbose")
+            .multiple(true)
+            .alias("chatty"))
+        .get_matches_from_safe(vec!["test", "--colour=never"]).unwrap();
+
+    assert_eq!(m.value_of("color"), Some("never"));
+}
+
+#[test]
+fn alias_occurrences_counted_together() {
+    let m = App::new("test")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .visible_alias("colour")
+            .alias("colr"))
+        .arg(Arg::with_name("verbose")
+            .long("verbose")
+            .multiple(true)
+            .alias("chatty"))
+        .get_matches_from_safe(vec!["test", "--color", "auto", "--colour", "always", "--colr",
+                                    "never", "--verbose", "--chatty"])
+        .unwrap();
+
+    assert_eq!(m.occurrences_of("color"), 3);
+    assert_eq!(m.values_of("color").unwrap().collect::<Vec<_>>(),
+               ["auto", "always", "never"]);
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert!(!m.is_present("colour"));
+    assert!(!m.is_present("chatty"));
+}
@@ -0,0 +1,55 @@
+#![cfg(all(feature = "color", not(target_os = "windows")))]
+
+extern crate clap;
+
+use std::env;
+
+use clap::{App, Arg, ColorChoice};
+
+fn error_has_ansi(choice: ColorChoice) -> bool {
+    let err = App::new("prog")
+        .color(choice)
+        .arg(Arg::with_name("input").required(true))
+        .get_matches_from_safe(vec!["prog"])
+        .unwrap_err();
+    err.message.contains("\x1b[")
+}
+
+fn set(no_color: Option<&str>, clicolor: Option<&str>, clicolor_force: Option<&str>) {
+    for &(name, val) in &[("NO_COLOR", no_color),
+                          ("CLICOLOR", clicolor),
+                          ("CLICOLOR_FORCE", clicolor_force)] {
+        match val {
+            Some(v) => env::set_var(name, v),
+            None => env::remove_var(name),
+        }
+    }
+}
+
+// The environment is shared by the whole test binary, so every combination is checked in a
+// single test rather than in parallel. Whether stderr is a TTY depends on how the tests are run,
+// so only combinations which don't fall back to TTY detection are checked here, the others are
+// covered by the unit tests of `auto_color`
+#[test]
+fn color_env_precedence() {
+    set(None, None, Some("1"));
+    assert!(error_has_ansi(ColorChoice::Auto));
+
+    set(None, Some("0"), Some("1"));
+    assert!(error_has_ansi(ColorChoice::Auto));
+
+    set(Some("1"), None, Some("1"));
+    assert!(!error_has_ansi(ColorChoice::Auto));
+
+    set(Some(""), None, Some("1"));
+    assert!(error_has_ansi(ColorChoice::Auto));
+
+    // an explicit choice ignores the environment
+    set(None, None, Some("1"));
+    assert!(!error_has_ansi(ColorChoice::Never));
+
+    set(Some("1"), Some("0"), None);
+    assert!(error_has_ansi(ColorChoice::Always));
+
+    set(None, None, None);
+}
@@ -1,6 +1,9 @@
+#[macro_use]
 extern crate clap;
 
-use clap::{App, Arg, SubCommand, Shell};
+use clap::{App, AppSettings, Arg, ErrorKind, SubCommand, Shell};
+
+static FISH_NESTED: &'static str = include_str!("completions_nested.fish");
 
 #[test]
 fn test_generation() {
@@ -23,3 +26,327 @@ fn test_generation() {
     assert_eq!(first_line, "_myapp() {");
     assert_eq!(last_line, "complete -F _myapp myapp");
 }
+
+#[test]
+fn completion_subcommand_shell() {
+    let mut app = App::new("myapp")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("file")
+            .help("some input file"))
+        .subcommand(App::completion_subcommand());
+    let m = app.clone().get_matches_from(vec!["myapp", "completions", "bash"]);
+    let sub_m = m.subcommand_matches("completions").unwrap();
+
+    assert_eq!(sub_m.value_of("shell"), Some("bash"));
+    let shell = value_t!(sub_m, "shell", Shell).unwrap();
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", shell, &mut buf);
+    assert!(String::from_utf8(buf).unwrap().starts_with("_myapp() {"));
+}
+
+#[test]
+fn completion_subcommand_all_shells() {
+    for shell in &Shell::variants() {
+        let m = App::new("myapp")
+            .setting(AppSettings::ColorNever)
+            .arg(Arg::with_name("file")
+                .help("some input file"))
+            .subcommand(App::completion_subcommand())
+            .get_matches_from(vec!["myapp", "completions", shell]);
+        let sub_m = m.subcommand_matches("completions").unwrap();
+        assert!(value_t!(sub_m, "shell", Shell).is_ok());
+    }
+}
+
+#[test]
+fn completion_subcommand_unknown_shell() {
+    let err = App::new("myapp")
+        .global_setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("file")
+            .help("some input file"))
+        .subcommand(App::completion_subcommand())
+        .get_matches_from_safe(vec!["myapp", "completions", "bsh"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+    assert!(err.message.contains("[values: bash, fish, powershell, zsh]"));
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn completion_subcommand_unknown_shell_suggestion() {
+    let err = App::new("myapp")
+        .global_setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("file")
+            .help("some input file"))
+        .subcommand(App::completion_subcommand())
+        .get_matches_from_safe(vec!["myapp", "completions", "bsh"])
+        .unwrap_err();
+
+    assert!(err.message.contains("Did you mean 'bash'?"));
+}
+
+#[test]
+fn completion_subcommand_requires_shell() {
+    let err = App::new("myapp")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("file")
+            .help("some input file"))
+        .subcommand(App::completion_subcommand())
+        .get_matches_from_safe(vec!["myapp", "completions"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn fish_nested() {
+    let mut app = App::new("myapp")
+        .about("Tests completions")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .possible_values(&["auto", "always", "never"])
+            .help("When to use color"))
+        .subcommand(SubCommand::with_name("build")
+            .about("Builds the project")
+            .arg(Arg::with_name("jobs")
+                .short("j")
+                .long("jobs")
+                .takes_value(true)
+                .help("Number of jobs"))
+            .subcommand(SubCommand::with_name("docs")
+                .about("Builds the project's docs")
+                .arg(Arg::with_name("open")
+                    .long("open")
+                    .help("Opens the docs in a browser"))));
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", Shell::Fish, &mut buf);
+
+    assert_eq!(String::from_utf8(buf).unwrap(), FISH_NESTED);
+}
+
+#[test]
+fn powershell_nested() {
+    let mut app = App::new("myapp")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .possible_values(&["auto", "always", "never"])
+            .help("When to use color"))
+        .subcommand(SubCommand::with_name("build")
+            .about("Builds the project")
+            .subcommand(SubCommand::with_name("docs")
+                .about("Builds the project's docs")
+                .arg(Arg::with_name("open")
+                    .long("open"))));
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", Shell::PowerShell, &mut buf);
+    let string = String::from_utf8(buf).unwrap();
+
+    assert!(string.contains("Register-ArgumentCompleter -Native -CommandName $_"));
+    for line in &["'-v', '-v', 'ParameterName', 'Uses verbose output'",
+                  "'--verbose', '--verbose', 'ParameterName', 'Uses verbose output'",
+                  "'--color', '--color', 'ParameterName', 'When to use color'",
+                  "'--help', '--help', 'ParameterName', 'Prints help information'",
+                  "'build', 'build', 'ParameterValue', 'Builds the project'",
+                  "'docs', 'docs', 'ParameterValue', 'Builds the project''s docs'",
+                  "'--open', '--open', 'ParameterName', '--open'",
+                  "'always', 'always', 'ParameterValue', 'always'"] {
+        assert!(string.contains(line), "missing {}", line);
+    }
+    assert!(string.contains("'_myapp;build' {\n                    $command = '_myapp_build'"));
+    assert!(string.contains("'_myapp_build;docs' {\n                    $command = '_myapp_build_docs'"));
+    assert!(string.contains("'_myapp;--color' {"));
+    assert!(string.contains("'_myapp_build_docs' {"));
+}
+
+// Set when this test binary re-runs itself to exercise the real exit path
+const HOOK_CHILD_ENV: &'static str = "CLAP_COMPLETION_HOOK_CHILD";
+
+fn hosts() -> Vec<String> {
+    vec!["alpha".to_owned(), "beta".to_owned(), "gamma".to_owned()]
+}
+
+#[test]
+fn completion_hook_values() {
+    let err = App::new("myapp")
+        .arg(Arg::with_name("host")
+            .long("host")
+            .completion(hosts))
+        .subcommand(SubCommand::with_name("connect")
+            .alias("c")
+            .arg(Arg::with_name("remote")
+                .long("remote")
+                .completion(|| vec!["origin".to_owned(), "upstream".to_owned()]))
+            .arg(Arg::with_name("target")
+                .completion(hosts)))
+        .get_matches_from_safe(vec!["myapp", "--generate-completion-values", "host"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::CompletionValuesDisplayed);
+    assert_eq!(err.message, "alpha\nbeta\ngamma");
+    assert!(!err.use_stderr());
+    assert_eq!(err.exit_code(), 0);
+}
+
+#[test]
+fn completion_hook_subcommand_values() {
+    let app = App::new("myapp")
+        .arg(Arg::with_name("host")
+            .long("host")
+            .completion(hosts))
+        .subcommand(SubCommand::with_name("connect")
+            .alias("c")
+            .arg(Arg::with_name("remote")
+                .long("remote")
+                .completion(|| vec!["origin".to_owned(), "upstream".to_owned()]))
+            .arg(Arg::with_name("target")
+                .completion(hosts)));
+    let err = app.clone()
+        .get_matches_from_safe(vec!["myapp", "--generate-completion-values", "connect", "remote"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::CompletionValuesDisplayed);
+    assert_eq!(err.message, "origin\nupstream");
+
+    let err = app
+        .get_matches_from_safe(vec!["myapp", "--generate-completion-values", "c", "target"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::CompletionValuesDisplayed);
+    assert_eq!(err.message, "alpha\nbeta\ngamma");
+}
+
+#[test]
+fn completion_hook_unknown_arg() {
+    let app = App::new("myapp")
+        .arg(Arg::with_name("host")
+            .long("host")
+            .completion(hosts))
+        .subcommand(SubCommand::with_name("connect")
+            .alias("c")
+            .arg(Arg::with_name("remote")
+                .long("remote")
+                .completion(|| vec!["origin".to_owned(), "upstream".to_owned()]))
+            .arg(Arg::with_name("target")
+                .completion(hosts)));
+    let err = app.clone()
+        .get_matches_from_safe(vec!["myapp", "--generate-completion-values", "port"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentNotFound);
+
+    let err = app
+        .setting(AppSettings::ColorNever)
+        .get_matches_from_safe(vec!["myapp", "--generate-completion-values", "nope", "remote"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnrecognizedSubcommand);
+}
+
+#[test]
+fn completion_hook_not_called_when_parsing() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let m = App::new("myapp")
+        .arg(Arg::with_name("host")
+            .long("host")
+            .completion(move || {
+                counter.set(counter.get() + 1);
+                vec![]
+            }))
+        .get_matches_from(vec!["myapp", "--host", "alpha"]);
+
+    assert_eq!(m.value_of("host"), Some("alpha"));
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn completion_hook_flag_without_hooks() {
+    let err = App::new("myapp")
+        .arg(Arg::with_name("host").long("host").takes_value(true))
+        .get_matches_from_safe(vec!["myapp", "--generate-completion-values", "host"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn completion_hook_prints_values() {
+    use std::env;
+    use std::process::Command;
+
+    if env::var_os(HOOK_CHILD_ENV).is_some() {
+        let app = App::new("myapp")
+            .arg(Arg::with_name("host")
+                .long("host")
+                .completion(hosts))
+            .subcommand(SubCommand::with_name("connect")
+                .alias("c")
+                .arg(Arg::with_name("remote")
+                    .long("remote")
+                    .completion(|| vec!["origin".to_owned(), "upstream".to_owned()]))
+                .arg(Arg::with_name("target")
+                    .completion(hosts)));
+        match app.get_matches_from_safe(vec!["myapp",
+                                                    "--generate-completion-values",
+                                                    "host"]) {
+            Err(e) => e.exit(),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    let out = Command::new(env::current_exe().unwrap())
+        .arg("completion_hook_prints_values")
+        .arg("--nocapture")
+        .arg("--quiet")
+        .env(HOOK_CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert_eq!(out.status.code(), Some(0));
+    assert!(stdout.contains("alpha\nbeta\ngamma\n"));
+}
+
+#[test]
+fn completion_hook_in_scripts() {
+    let mut buf = vec![];
+    let mut app = App::new("myapp")
+        .arg(Arg::with_name("host")
+            .long("host")
+            .completion(hosts))
+        .subcommand(SubCommand::with_name("connect")
+            .alias("c")
+            .arg(Arg::with_name("remote")
+                .long("remote")
+                .completion(|| vec!["origin".to_owned(), "upstream".to_owned()]))
+            .arg(Arg::with_name("target")
+                .completion(hosts)));
+    app.gen_completions_to("myapp", Shell::Bash, &mut buf);
+    let bash = String::from_utf8(buf).unwrap();
+    assert!(bash.contains("$(compgen -W \"$(myapp --generate-completion-values host)\" -- ${cur})"));
+    assert!(bash.contains("$(myapp --generate-completion-values connect remote)"));
+
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", Shell::Fish, &mut buf);
+    let fish = String::from_utf8(buf).unwrap();
+    assert!(fish.contains("-l remote -r -f -a '(myapp --generate-completion-values connect remote)'"));
+
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", Shell::Zsh, &mut buf);
+    let zsh = String::from_utf8(buf).unwrap();
+    assert!(zsh.contains("--host+[]: :($(myapp --generate-completion-values host))"));
+
+    let mut buf = vec![];
+    app.gen_completions_to("myapp", Shell::PowerShell, &mut buf);
+    let ps = String::from_utf8(buf).unwrap();
+    assert!(ps.contains("& 'myapp' '--generate-completion-values' 'connect' 'remote' | %{"));
+}
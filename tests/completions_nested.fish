@@ -0,0 +1,18 @@
+complete -c myapp -n '__fish_use_subcommand' -l color -d 'When to use color' -r -f -a 'auto always never'
+complete -c myapp -n '__fish_use_subcommand' -s v -l verbose -d 'Uses verbose output'
+complete -c myapp -n '__fish_use_subcommand' -s h -l help -d 'Prints help information'
+complete -c myapp -n '__fish_use_subcommand' -s V -l version -d 'Prints version information'
+complete -c myapp -n '__fish_use_subcommand' -f -a 'build' -d 'Builds the project'
+complete -c myapp -n '__fish_use_subcommand' -f -a 'help' -d 'Prints this message or the help of the given subcommand(s)'
+complete -c myapp -n '__fish_seen_subcommand_from build; and not __fish_seen_subcommand_from docs help' -s j -l jobs -d 'Number of jobs' -r
+complete -c myapp -n '__fish_seen_subcommand_from build; and not __fish_seen_subcommand_from docs help' -s h -l help -d 'Prints help information'
+complete -c myapp -n '__fish_seen_subcommand_from build; and not __fish_seen_subcommand_from docs help' -s V -l version -d 'Prints version information'
+complete -c myapp -n '__fish_seen_subcommand_from build; and not __fish_seen_subcommand_from docs help' -f -a 'docs' -d 'Builds the project\'s docs'
+complete -c myapp -n '__fish_seen_subcommand_from build; and not __fish_seen_subcommand_from docs help' -f -a 'help' -d 'Prints this message or the help of the given subcommand(s)'
+complete -c myapp -n '__fish_seen_subcommand_from build; and __fish_seen_subcommand_from docs' -l open -d 'Opens the docs in a browser'
+complete -c myapp -n '__fish_seen_subcommand_from build; and __fish_seen_subcommand_from docs' -s h -l help -d 'Prints help information'
+complete -c myapp -n '__fish_seen_subcommand_from build; and __fish_seen_subcommand_from docs' -s V -l version -d 'Prints version information'
+complete -c myapp -n '__fish_seen_subcommand_from build; and __fish_seen_subcommand_from help' -s h -l help -d 'Prints help information'
+complete -c myapp -n '__fish_seen_subcommand_from build; and __fish_seen_subcommand_from help' -s V -l version -d 'Prints version information'
+complete -c myapp -n '__fish_seen_subcommand_from help' -s h -l help -d 'Prints help information'
+complete -c myapp -n '__fish_seen_subcommand_from help' -s V -l version -d 'Prints version information'
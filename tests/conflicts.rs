@@ -3,7 +3,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, ErrorKind, ArgGroup};
+use clap::{App, AppSettings, Arg, ErrorKind, ArgGroup};
 
 static CONFLICT_ERR: &'static str = "error: The argument '-F' cannot be used with '--flag'
 
@@ -86,3 +86,57 @@ fn conflict_output() {
 fn conflict_output_rev() {
     test::check_err_output(test::complex_app(), "clap-test val1 -F --long-option-2 val2 --flag", CONFLICT_ERR_REV, true);
 }
+
+#[test]
+fn conflicts_with_all_each_listed_arg() {
+    for args in &[vec!["prog", "-c", "a.conf", "-d"],
+                  vec!["prog", "-c", "a.conf", "file.txt"],
+                  vec!["prog", "-d", "-c", "a.conf"],
+                  vec!["prog", "file.txt", "-c", "a.conf"]] {
+        let err = App::new("prog")
+            .arg(Arg::from_usage("-c, --config [file] 'some config'")
+                .conflicts_with_all(&["debug", "input"]))
+            .arg(Arg::from_usage("-d, --debug 'debug mode'"))
+            .arg(Arg::from_usage("-v, --verbose 'verbose mode'"))
+            .arg(Arg::from_usage("[input] 'some input'"))
+            .get_matches_from_safe(args.clone()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::ArgumentConflict, "args: {:?}", args);
+    }
+}
+
+#[test]
+fn conflicts_with_all_unlisted_arg_ok() {
+    let m = App::new("prog")
+        .arg(Arg::from_usage("-c, --config [file] 'some config'")
+            .conflicts_with_all(&["debug", "input"]))
+        .arg(Arg::from_usage("-d, --debug 'debug mode'"))
+        .arg(Arg::from_usage("-v, --verbose 'verbose mode'"))
+        .arg(Arg::from_usage("[input] 'some input'"))
+        .get_matches_from(vec!["prog", "-v", "-c", "a.conf"]);
+
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_of("config"), Some("a.conf"));
+}
+
+#[test]
+fn conflict_symmetric_error_names_both() {
+    for args in &[vec!["prog", "--config", "a.conf", "--debug"],
+                  vec!["prog", "--debug", "--config", "a.conf"]] {
+        let err = App::new("prog")
+            .arg(Arg::from_usage("-c, --config [file] 'some config'")
+                .conflicts_with_all(&["debug", "input"]))
+            .arg(Arg::from_usage("-d, --debug 'debug mode'"))
+            .arg(Arg::from_usage("-v, --verbose 'verbose mode'"))
+            .arg(Arg::from_usage("[input] 'some input'"))
+            .setting(AppSettings::ColorNever)
+            .get_matches_from_safe(args.clone())
+            .unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::ArgumentConflict);
+        let info = err.info.unwrap();
+        assert!(info.iter().any(|n| n == "debug" || n == "--debug"), "{:?}", info);
+        assert!(info.iter().any(|n| n == "config" || n.starts_with("--config")), "{:?}", info);
+        assert!(err.message.contains("--debug"));
+        assert!(err.message.contains("--config"));
+    }
+}
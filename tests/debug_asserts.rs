@@ -0,0 +1,139 @@
+extern crate clap;
+
+use clap::{App, Arg, ArgGroup, SubCommand};
+
+#[test]
+fn valid_app_passes() {
+    App::new("prog")
+        .arg(Arg::with_name("debug").short("d").long("debug").conflicts_with("quiet"))
+        .arg(Arg::with_name("quiet").short("q").overrides_with("debug"))
+        .arg(Arg::with_name("config").long("config").takes_value(true).requires("output"))
+        .arg(Arg::with_name("input").required_unless("stdin"))
+        .arg(Arg::with_name("output"))
+        .arg(Arg::with_name("stdin").long("stdin").group("sources"))
+        .group(ArgGroup::with_name("mode").args(&["debug", "quiet"]).requires("sources"))
+        .subcommand(SubCommand::with_name("test").arg(Arg::with_name("file")))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Non-unique argument name: debug is already in use")]
+fn duplicate_name() {
+    App::new("prog")
+        .arg(Arg::with_name("debug").short("d"))
+        .arg(Arg::with_name("debug").long("debug"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument short must be unique\n\n\t-d is already in use")]
+fn duplicate_short() {
+    App::new("prog")
+        .arg(Arg::with_name("debug").short("d"))
+        .arg(Arg::with_name("dry-run").short("d"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument long must be unique\n\n\t--debug is already in use")]
+fn duplicate_long() {
+    App::new("prog")
+        .arg(Arg::with_name("debug").long("debug"))
+        .arg(Arg::with_name("verbose").long("debug"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument \"config\" requires \"output\" which isn't a valid argument or group")]
+fn requires_unknown_arg() {
+    App::new("prog")
+        .arg(Arg::with_name("config").long("config").takes_value(true).requires("output"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument \"debug\" conflicts with \"quiet\" which isn't a valid argument or group")]
+fn conflicts_with_unknown_arg() {
+    App::new("prog")
+        .arg(Arg::with_name("debug").short("d").conflicts_with("quiet"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument \"debug\" overrides \"quiet\" which isn't a valid argument or group")]
+fn overrides_with_unknown_arg() {
+    App::new("prog")
+        .arg(Arg::with_name("debug").short("d").overrides_with("quiet"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument \"input\" is required unless \"stdin\" which isn't a valid argument or group")]
+fn required_unless_unknown_arg() {
+    App::new("prog")
+        .arg(Arg::with_name("input").required_unless("stdin"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument group \"mode\" contains \"fast\" which isn't a valid argument")]
+fn group_unknown_member() {
+    App::new("prog")
+        .arg(Arg::with_name("slow").long("slow"))
+        .group(ArgGroup::with_name("mode").args(&["slow", "fast"]))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument group \"mode\" refers to \"input\" which isn't a valid argument or group")]
+fn group_requires_unknown_arg() {
+    App::new("prog")
+        .arg(Arg::with_name("slow").long("slow"))
+        .group(ArgGroup::with_name("mode").arg("slow").requires("input"))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Positional index 2 is not defined, but \"third\" has index 3")]
+fn positional_index_gap() {
+    App::new("prog")
+        .arg(Arg::with_name("first").index(1))
+        .arg(Arg::with_name("third").index(3))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument \"debug\" conflicts with \"quiet\" which isn't a valid argument or group")]
+fn subcommands_are_checked() {
+    App::new("prog")
+        .subcommand(SubCommand::with_name("test")
+            .arg(Arg::with_name("debug").short("d").conflicts_with("quiet")))
+        .debug_assert();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Argument \"config\" requires \"output\" which isn't a valid argument or group")]
+fn checked_when_parsing() {
+    let _ = App::new("prog")
+        .arg(Arg::with_name("config").long("config").takes_value(true).requires("output"))
+        .get_matches_from_safe(vec!["prog"]);
+}
+
+#[test]
+#[should_panic(expected = "Argument short -h of \"host\" is also used by App::help_short")]
+fn help_short_taken() {
+    App::new("prog")
+        .help_short("h")
+        .arg(Arg::with_name("host").short("h").takes_value(true))
+        .debug_assert();
+}
+
+#[test]
+#[should_panic(expected = "Argument short -v of \"verbose\" is also used by App::version_short")]
+fn version_short_taken() {
+    App::new("prog")
+        .version_short("v")
+        .arg(Arg::with_name("verbose").short("v"))
+        .debug_assert();
+}
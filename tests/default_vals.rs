@@ -0,0 +1,166 @@
+extern crate clap;
+
+use std::env;
+
+use clap::{App, Arg, ErrorKind};
+
+#[test]
+fn default_ifs_first_condition_wins() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("format").long("format").takes_value(true))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_ifs(&[("quiet", None, "stdout"),
+                                 ("format", Some("json"), "report.json"),
+                                 ("format", None, "report.out")])
+            .default_value("report.txt"))
+        .get_matches_from(vec!["prog", "--format", "json", "--quiet"]);
+
+    assert_eq!(m.value_of("output"), Some("stdout"));
+    assert_eq!(m.occurrences_of("output"), 0);
+}
+
+#[test]
+fn default_ifs_condition_with_value() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("format").long("format").takes_value(true))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_ifs(&[("quiet", None, "stdout"),
+                                 ("format", Some("json"), "report.json"),
+                                 ("format", None, "report.out")])
+            .default_value("report.txt"))
+        .get_matches_from(vec!["prog", "--format", "json"]);
+
+    assert_eq!(m.value_of("output"), Some("report.json"));
+}
+
+#[test]
+fn default_ifs_condition_without_value() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("format").long("format").takes_value(true))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_ifs(&[("quiet", None, "stdout"),
+                                 ("format", Some("json"), "report.json"),
+                                 ("format", None, "report.out")])
+            .default_value("report.txt"))
+        .get_matches_from(vec!["prog", "--format", "csv"]);
+
+    assert_eq!(m.value_of("output"), Some("report.out"));
+}
+
+#[test]
+fn default_ifs_fallthrough_default() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("format").long("format").takes_value(true))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_ifs(&[("quiet", None, "stdout"),
+                                 ("format", Some("json"), "report.json"),
+                                 ("format", None, "report.out")])
+            .default_value("report.txt"))
+        .get_matches_from(vec!["prog"]);
+
+    assert_eq!(m.value_of("output"), Some("report.txt"));
+}
+
+#[test]
+fn default_ifs_no_default_without_match() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_if("quiet", None, "stdout"))
+        .get_matches_from(vec!["prog"]);
+
+    assert!(!m.is_present("output"));
+    assert_eq!(m.value_of("output"), None);
+}
+
+#[test]
+fn default_ifs_explicit_value_overrides() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("format").long("format").takes_value(true))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_ifs(&[("quiet", None, "stdout"),
+                                 ("format", Some("json"), "report.json"),
+                                 ("format", None, "report.out")])
+            .default_value("report.txt"))
+        .get_matches_from(vec!["prog", "--quiet", "--output", "out.log"]);
+
+    assert_eq!(m.value_of("output"), Some("out.log"));
+    assert_eq!(m.occurrences_of("output"), 1);
+}
+
+#[test]
+fn default_if_added_in_order() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("level")
+            .long("level")
+            .default_value_if("verbose", None, "debug")
+            .default_value_if("quiet", None, "error"))
+        .get_matches_from(vec!["prog", "--quiet", "--verbose"]);
+
+    assert_eq!(m.value_of("level"), Some("debug"));
+}
+
+#[test]
+fn default_ifs_ignore_other_defaults() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("format").long("format").default_value("json"))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .default_value_if("format", Some("json"), "report.json")
+            .default_value("report.txt"))
+        .get_matches_from(vec!["prog"]);
+
+    assert_eq!(m.value_of("format"), Some("json"));
+    assert_eq!(m.value_of("output"), Some("report.txt"));
+}
+
+#[test]
+fn default_ifs_positional() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("output").default_value_if("quiet", None, "-"))
+        .get_matches_from(vec!["prog", "--quiet"]);
+
+    assert_eq!(m.value_of("output"), Some("-"));
+}
+
+#[test]
+fn default_ifs_env_takes_precedence() {
+    env::set_var("CLP_TEST_ENV_DEFAULT_IFS", "env.txt");
+    let m = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .env("CLP_TEST_ENV_DEFAULT_IFS")
+            .default_value_if("quiet", None, "stdout"))
+        .get_matches_from(vec!["prog", "--quiet"]);
+
+    assert_eq!(m.value_of("output"), Some("env.txt"));
+}
+
+#[test]
+fn default_ifs_validated() {
+    let res = App::new("prog")
+        .arg(Arg::with_name("quiet").long("quiet"))
+        .arg(Arg::with_name("output")
+            .long("output")
+            .possible_values(&["stdout", "stderr"])
+            .default_value_if("quiet", None, "nowhere"))
+        .get_matches_from_safe(vec!["prog", "--quiet"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidValue);
+}
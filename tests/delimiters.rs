@@ -1,6 +1,8 @@
 extern crate clap;
 
-use clap::{App, Arg};
+use std::ffi::OsStr;
+
+use clap::{App, Arg, ArgGroup};
 
 #[test]
 fn opt_default_no_delim() {
@@ -137,3 +139,55 @@ fn opt_eq_mult_def_delim() {
     assert_eq!(m.occurrences_of("option"), 1);
     assert_eq!(m.values_of("option").unwrap().collect::<Vec<_>>(), &["val1", "val2", "val3"]);
 }
+
+#[test]
+fn get_raw_keeps_delimited_token() {
+    let m = App::new("raw")
+        .arg(Arg::with_name("option")
+            .long("opt")
+            .multiple(true)
+            .use_delimiter(true)
+            .takes_value(true))
+        .get_matches_from(vec![
+            "",
+            "--opt=val1,val2",
+            "--opt",
+            "val3",
+        ]);
+
+    assert_eq!(m.values_of("option").unwrap().collect::<Vec<_>>(), &["val1", "val2", "val3"]);
+    assert_eq!(m.get_raw("option").unwrap().collect::<Vec<_>>(),
+               &[OsStr::new("val1,val2"), OsStr::new("val3")]);
+}
+
+#[test]
+fn get_raw_positional_and_group() {
+    let m = App::new("raw")
+        .arg(Arg::with_name("pos")
+            .value_delimiter(":"))
+        .group(ArgGroup::with_name("grp").arg("pos"))
+        .get_matches_from(vec![
+            "",
+            "a:b:c",
+        ]);
+
+    assert_eq!(m.values_of("pos").unwrap().collect::<Vec<_>>(), &["a", "b", "c"]);
+    assert_eq!(m.get_raw("pos").unwrap().collect::<Vec<_>>(), &[OsStr::new("a:b:c")]);
+    assert_eq!(m.get_raw("grp").unwrap().collect::<Vec<_>>(), &[OsStr::new("a:b:c")]);
+    assert!(m.get_raw("missing").is_none());
+}
+
+#[test]
+fn get_raw_before_normalizing() {
+    let m = App::new("raw")
+        .arg(Arg::with_name("path")
+            .long("path")
+            .takes_value(true)
+            .normalize_path_separators(true))
+        .get_matches_from(vec![
+            "",
+            "--path", "a/b\\c",
+        ]);
+
+    assert_eq!(m.get_raw("path").unwrap().collect::<Vec<_>>(), &[OsStr::new("a/b\\c")]);
+}
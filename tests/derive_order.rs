@@ -247,3 +247,76 @@ fn unified_help_and_derive_order_subcommand_propagate_with_explicit_display_orde
 
     "));
 }
+
+#[test]
+fn derive_order_matches_declaration() {
+    let app = App::new("test")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .args(&[
+            Arg::with_name("zeta").long("zeta").help("first"),
+            Arg::with_name("alpha").long("alpha").takes_value(true).help("second"),
+            Arg::with_name("mu").long("mu").help("third"),
+            Arg::with_name("beta").long("beta").takes_value(true).help("fourth"),
+        ]);
+    let help = get_help(app, &["test"]);
+    let pos = |s: &str| help.iter().position(|l| l.ends_with(s)).unwrap();
+
+    assert!(pos("first") < pos("second"));
+    assert!(pos("second") < pos("third"));
+    assert!(pos("third") < pos("fourth"));
+}
+
+#[test]
+fn derive_order_write_help() {
+    let mut buf = vec![];
+    let app = App::new("test")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .args(&[
+            Arg::with_name("zeta").long("zeta").help("first"),
+            Arg::with_name("alpha").long("alpha").takes_value(true).help("second"),
+            Arg::with_name("mu").long("mu").help("third"),
+            Arg::with_name("beta").long("beta").takes_value(true).help("fourth"),
+        ]);
+    app.write_help(&mut buf).expect("failed to write help");
+
+    assert_eq!(normalize(str::from_utf8(&buf).unwrap()),
+               get_help(app, &["test"]));
+}
+
+#[test]
+fn derive_order_with_explicit_display_order() {
+    let app = App::new("test")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .args(&[
+            Arg::with_name("zeta").long("zeta").help("first"),
+            Arg::with_name("alpha").long("alpha").takes_value(true).help("second"),
+            Arg::with_name("mu").long("mu").help("third"),
+            Arg::with_name("beta").long("beta").takes_value(true).help("fourth"),
+        ])
+        .arg(Arg::with_name("omega").long("omega").help("fifth").display_order(0));
+    let help = get_help(app, &["test"]);
+    let pos = |s: &str| help.iter().position(|l| l.ends_with(s)).unwrap();
+
+    assert!(pos("fifth") < pos("first"));
+    assert!(pos("first") < pos("second"));
+    assert!(pos("third") < pos("fourth"));
+}
+
+#[test]
+fn derive_order_args_from_vec() {
+    let args = vec![Arg::with_name("zeta").long("zeta").help("first"),
+                    Arg::with_name("alpha").long("alpha").takes_value(true).help("second"),
+                    Arg::with_name("mu").long("mu").help("third")];
+    let app = App::new("test")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .args(args);
+    let help = get_help(app, &["test"]);
+    let pos = |s: &str| help.iter().position(|l| l.ends_with(s)).unwrap();
+
+    assert!(pos("first") < pos("second"));
+    assert!(pos("second") < pos("third"));
+}
@@ -0,0 +1,200 @@
+extern crate clap;
+
+use std::env;
+use std::ffi::OsString;
+
+use clap::{App, AppSettings, Arg, SubCommand, ErrorKind, ValueSource};
+
+#[test]
+fn env() {
+    env::set_var("CLP_TEST_ENV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn env_not_set() {
+    env::remove_var("CLP_TEST_ENV_NONE");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_NONE"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    assert!(!r.unwrap().is_present("arg"));
+}
+
+#[test]
+fn env_empty() {
+    env::set_var("CLP_TEST_ENV_EMPTY", "");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_EMPTY")
+            .default_value("default"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("arg").unwrap(), "default");
+}
+
+#[test]
+fn env_over_default() {
+    env::set_var("CLP_TEST_ENV_DEFAULT", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("-o [opt] 'some opt'")
+            .env("CLP_TEST_ENV_DEFAULT")
+            .default_value("default"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("o").unwrap(), "env");
+}
+
+#[test]
+fn user_value_over_env() {
+    env::set_var("CLP_TEST_ENV_USER", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("-o [opt] 'some opt'").env("CLP_TEST_ENV_USER"))
+        .get_matches_from_safe(vec!["", "-o", "user"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.occurrences_of("o"), 1);
+    assert_eq!(m.value_of("o").unwrap(), "user");
+}
+
+#[test]
+fn env_satisfies_required() {
+    env::set_var("CLP_TEST_ENV_REQ", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("<arg> 'some opt'").env("CLP_TEST_ENV_REQ"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn invalid_env_value() {
+    env::set_var("CLP_TEST_ENV_INVALID", "eighty");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--port [port] 'some port'")
+            .env("CLP_TEST_ENV_INVALID")
+            .validator(|v| v.parse::<u16>().map(|_| ()).map_err(|e| e.to_string())))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_err());
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert!(err.message.contains("from env CLP_TEST_ENV_INVALID"));
+}
+
+#[test]
+fn subcommand_env() {
+    env::set_var("CLP_TEST_ENV_SC", "env");
+
+    let r = App::new("df")
+        .subcommand(SubCommand::with_name("sub")
+            .arg(Arg::from_usage("-o [opt] 'some opt'").env("CLP_TEST_ENV_SC")))
+        .get_matches_from_safe(vec!["", "sub"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.subcommand_matches("sub").unwrap().value_of("o"), Some("env"));
+}
+
+#[test]
+fn subcommand_env_user_value() {
+    env::set_var("CLP_TEST_ENV_SC_USER", "env");
+
+    let r = App::new("df")
+        .subcommand(SubCommand::with_name("sub")
+            .arg(Arg::from_usage("-o [opt] 'some opt'").env("CLP_TEST_ENV_SC_USER")))
+        .get_matches_from_safe(vec!["", "sub", "-o", "user"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.subcommand_matches("sub").unwrap().value_of("o"), Some("user"));
+}
+
+#[test]
+fn resolved_includes_defaults_and_env() {
+    env::set_var("CLP_TEST_ENV_RESOLVED", "from-env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--host [host] 'some host'").env("CLP_TEST_ENV_RESOLVED"))
+        .arg(Arg::from_usage("--port [port] 'some port'").default_value("80"))
+        .arg(Arg::from_usage("--user [user] 'some user'"))
+        .arg(Arg::from_usage("-f, --flag 'some flag'"))
+        .arg(Arg::from_usage("-o, --opt [opt]... 'some opt'").default_value("unused"))
+        .get_matches_from_safe(vec!["", "-f", "-o", "a", "-o", "b"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.resolved(),
+               vec![("flag".to_owned(), vec![]),
+                    ("host".to_owned(), vec![OsString::from("from-env")]),
+                    ("opt".to_owned(), vec![OsString::from("a"), OsString::from("b")]),
+                    ("port".to_owned(), vec![OsString::from("80")])]);
+    assert_eq!(m.value_source("flag"), Some(ValueSource::CommandLine));
+    assert_eq!(m.value_source("host"), Some(ValueSource::EnvVariable));
+    assert_eq!(m.value_source("opt"), Some(ValueSource::CommandLine));
+    assert_eq!(m.value_source("port"), Some(ValueSource::DefaultValue));
+    assert_eq!(m.value_source("user"), None);
+}
+
+#[test]
+fn env_delimited_values() {
+    env::set_var("CLP_TEST_ENV_INCLUDE", "/usr/include:/opt/include");
+
+    let m = App::new("df")
+        .arg(Arg::from_usage("--include [dir]... 'include dirs'")
+            .env("CLP_TEST_ENV_INCLUDE")
+            .value_delimiter(":"))
+        .get_matches_from(vec![""]);
+
+    assert_eq!(m.values_of("include").unwrap().collect::<Vec<_>>(),
+               ["/usr/include", "/opt/include"]);
+    assert_eq!(m.occurrences_of("include"), 0);
+}
+
+#[test]
+fn env_delimited_values_validated() {
+    env::set_var("CLP_TEST_ENV_DELIM_INVALID", "fast,loud");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--mode [mode]... 'modes'")
+            .env("CLP_TEST_ENV_DELIM_INVALID")
+            .use_delimiter(true)
+            .possible_values(&["fast", "slow"]))
+        .setting(AppSettings::ColorNever)
+        .get_matches_from_safe(vec![""]);
+
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+    assert!(err.message.contains("Invalid value 'loud' from env CLP_TEST_ENV_DELIM_INVALID"));
+}
+
+#[test]
+fn env_not_delimited() {
+    env::set_var("CLP_TEST_ENV_NO_DELIM", "a:b,c");
+
+    let m = App::new("df")
+        .arg(Arg::from_usage("--opt [opt] 'some opt'").env("CLP_TEST_ENV_NO_DELIM"))
+        .get_matches_from(vec![""]);
+
+    assert_eq!(m.values_of("opt").unwrap().collect::<Vec<_>>(), ["a:b,c"]);
+}
@@ -0,0 +1,178 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
+
+fn kind_of(app: App<'static, 'static>, args: &[&'static str]) -> ErrorKind {
+    app.get_matches_from_safe(args.to_vec()).unwrap_err().kind
+}
+
+#[test]
+fn kind_argument_conflict() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("a").short("a").conflicts_with("b"))
+        .arg(Arg::with_name("b").short("b"));
+    assert_eq!(kind_of(app, &["prog", "-a", "-b"]), ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn kind_empty_value() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("o").long("opt").takes_value(true).empty_values(false));
+    assert_eq!(kind_of(app, &["prog", "--opt="]), ErrorKind::EmptyValue);
+}
+
+#[test]
+fn kind_invalid_value() {
+    let app = App::new("prog").arg(Arg::with_name("speed").possible_values(&["fast", "slow"]));
+    assert_eq!(kind_of(app, &["prog", "medium"]), ErrorKind::InvalidValue);
+}
+
+#[test]
+fn kind_unknown_argument() {
+    let app = App::new("prog").arg(Arg::with_name("a").short("a"));
+    assert_eq!(kind_of(app, &["prog", "--nope"]), ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn kind_missing_required_argument() {
+    let app = App::new("prog").arg(Arg::with_name("input").required(true));
+    assert_eq!(kind_of(app, &["prog"]), ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn kind_too_many_values() {
+    let app = App::new("prog").arg(Arg::with_name("pos").max_values(2));
+    assert_eq!(kind_of(app, &["prog", "a", "b", "c"]), ErrorKind::TooManyValues);
+}
+
+#[test]
+fn kind_too_few_values() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("o").short("o").takes_value(true).multiple(true).min_values(3));
+    assert_eq!(kind_of(app, &["prog", "-o", "a", "-o", "b"]), ErrorKind::TooFewValues);
+}
+
+#[test]
+fn kind_wrong_number_of_values() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("o").short("o").takes_value(true).number_of_values(2));
+    assert_eq!(kind_of(app, &["prog", "-o", "a"]), ErrorKind::WrongNumberOfValues);
+}
+
+#[test]
+fn kind_value_validation() {
+    let app = App::new("prog").arg(Arg::with_name("num").validator(|v| {
+        v.parse::<u32>().map(|_| ()).map_err(|e| e.to_string())
+    }));
+    assert_eq!(kind_of(app, &["prog", "ten"]), ErrorKind::ValueValidation);
+}
+
+#[test]
+fn kind_help_displayed() {
+    let res = App::new("prog").get_matches_from_safe(vec!["prog", "--help"]);
+    let err = res.unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    assert!(!err.use_stderr());
+}
+
+#[test]
+fn kind_version_displayed() {
+    let res = App::new("prog").version("1.0").get_matches_from_safe(vec!["prog", "-V"]);
+    let err = res.unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::VersionDisplayed);
+    assert!(!err.use_stderr());
+}
+
+#[test]
+fn exit_code_help_and_version() {
+    let app = App::new("prog").version("1.0");
+
+    let err = app.clone().get_matches_from_safe(vec!["prog", "--help"]).unwrap_err();
+    assert_eq!(err.exit_code(), 0);
+
+    let err = app.get_matches_from_safe(vec!["prog", "--version"]).unwrap_err();
+    assert_eq!(err.exit_code(), 0);
+}
+
+#[test]
+fn exit_code_usage_errors() {
+    let app = App::new("prog").arg(Arg::with_name("input").required(true));
+
+    let err = app.clone().get_matches_from_safe(vec!["prog"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert_eq!(err.exit_code(), 2);
+
+    let err = app.get_matches_from_safe(vec!["prog", "in", "--nope"]).unwrap_err();
+    assert_eq!(err.exit_code(), 2);
+}
+
+#[test]
+fn to_json_conflict() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("a").long("aa").conflicts_with("b"))
+        .arg(Arg::with_name("b").long("bb"))
+        .get_matches_from_safe(vec!["prog", "--aa", "--bb"])
+        .unwrap_err();
+    let json = err.to_json();
+
+    assert!(json.starts_with("{\"kind\":\"ArgumentConflict\",\"arg\":\"b\",\"message\":\""),
+            "{}",
+            json);
+    assert!(json.ends_with("\"}"));
+    assert!(!json.contains('\n'));
+    assert!(!json.contains('\x1b'));
+}
+
+#[test]
+fn to_json_invalid_value() {
+    let err = App::new("prog")
+        .arg(Arg::with_name("speed").possible_values(&["fast", "slow"]))
+        .get_matches_from_safe(vec!["prog", "\"medium\""])
+        .unwrap_err();
+    let json = err.to_json();
+
+    assert!(json.contains("\"kind\":\"InvalidValue\""));
+    assert!(json.contains("\"arg\":\"speed\""));
+    // quotes and newlines in the message are escaped
+    assert!(json.contains("\\\"medium\\\""));
+    assert!(json.contains("\\n"));
+}
+
+#[test]
+fn to_json_without_arg() {
+    let err = App::new("prog").get_matches_from_safe(vec!["prog", "--help"]).unwrap_err();
+
+    assert!(err.to_json().starts_with("{\"kind\":\"HelpDisplayed\",\"arg\":null,"));
+}
+
+#[test]
+fn kind_no_equals() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("o").long("opt").takes_value(true).require_equals(true));
+    assert_eq!(kind_of(app, &["prog", "--opt", "val"]), ErrorKind::NoEquals);
+}
+
+#[test]
+fn try_get_matches_never_exits() {
+    let app = App::new("prog")
+        .version("1.0")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v"));
+
+    let err = app.clone().try_get_matches_from(vec!["prog", "--help"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    assert!(err.message.contains("USAGE:\n    prog [FLAGS]"));
+
+    let err = app.clone().try_get_matches_from(vec!["prog", "--version"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::VersionDisplayed);
+    assert_eq!(err.message, "prog 1.0");
+
+    let err = app.clone().try_get_matches_from(vec!["prog", "--bogus"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert!(err.message.starts_with("error: Found argument '--bogus'"));
+
+    let m = app.try_get_matches_from(vec!["prog", "-v"]).unwrap();
+    assert!(m.is_present("verbose"));
+}
@@ -145,3 +145,54 @@ fn short_flag_name_missing() {
     assert!(a.num_vals.is_none());
 
 }
+
+#[test]
+fn is_present_any_mixed() {
+    let m = App::new("flags")
+        .args_from_usage("-d, --debug 'turns on debugging'
+                          -v, --verbose 'prints more'
+                          -q, --quiet 'prints less'")
+        .get_matches_from(vec!["flags", "-v"]);
+
+    assert!(m.is_present_any(&["debug", "verbose"]));
+    assert!(m.is_present_any(&["verbose", "nonexistent"]));
+    assert!(!m.is_present_any(&["debug", "quiet"]));
+    assert!(!m.is_present_any::<&str>(&[]));
+}
+
+#[test]
+fn is_present_all_mixed() {
+    let m = App::new("flags")
+        .args_from_usage("-d, --debug 'turns on debugging'
+                          -v, --verbose 'prints more'
+                          -q, --quiet 'prints less'")
+        .get_matches_from(vec!["flags", "-d", "-v"]);
+
+    assert!(m.is_present_all(&["debug", "verbose"]));
+    assert!(!m.is_present_all(&["debug", "verbose", "quiet"]));
+    assert!(!m.is_present_all(&["quiet", "nonexistent"]));
+    assert!(m.is_present_all::<&str>(&[]));
+}
+
+#[test]
+fn get_flag_set_and_unset() {
+    let m = App::new("flags")
+        .args_from_usage("-d, --debug 'turns on debugging'
+                          -q, --quiet 'prints less'")
+        .get_matches_from(vec!["flags", "--debug"]);
+
+    assert!(m.get_flag("debug"));
+    assert!(!m.get_flag("quiet"));
+    assert_eq!(m.get_flag("debug"), m.is_present("debug"));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "ArgMatches::get_flag used on 'output' which takes values")]
+fn get_flag_on_arg_with_values() {
+    let m = App::new("flags")
+        .arg(Arg::with_name("output").short("o").takes_value(true))
+        .get_matches_from(vec!["flags", "-o", "out.txt"]);
+
+    m.get_flag("output");
+}
@@ -0,0 +1,99 @@
+extern crate clap;
+
+use clap::{App, Arg, ArgMatches, ErrorKind, FromArgMatches, parse_field, parse_field_opt,
+           parse_fields};
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    name: String,
+    jobs: u32,
+    verbose: bool,
+    limit: Option<u64>,
+    ports: Vec<u16>,
+}
+
+impl FromArgMatches for Config {
+    fn from_arg_matches(m: &ArgMatches) -> clap::Result<Self> {
+        Ok(Config {
+            name: try!(parse_field(m, "name")),
+            jobs: try!(parse_field(m, "jobs")),
+            verbose: m.is_present("verbose"),
+            limit: try!(parse_field_opt(m, "limit")),
+            ports: try!(parse_fields(m, "ports")),
+        })
+    }
+}
+
+#[test]
+fn from_arg_matches_all_fields() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("name").long("name").takes_value(true))
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .arg(Arg::with_name("limit").long("limit").takes_value(true))
+        .arg(Arg::with_name("ports").long("port").takes_value(true).multiple(true))
+        .get_matches_from(vec!["prog", "--name", "srv", "--jobs", "4", "-v", "--limit", "100",
+                               "--port", "80", "--port", "443"]);
+    let cfg = Config::from_arg_matches(&m).unwrap();
+    assert_eq!(cfg,
+               Config {
+                   name: "srv".to_owned(),
+                   jobs: 4,
+                   verbose: true,
+                   limit: Some(100),
+                   ports: vec![80, 443],
+               });
+}
+
+#[test]
+fn from_arg_matches_optional_fields() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("name").long("name").takes_value(true))
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .arg(Arg::with_name("limit").long("limit").takes_value(true))
+        .arg(Arg::with_name("ports").long("port").takes_value(true).multiple(true))
+        .get_matches_from(vec!["prog", "--name", "srv", "--jobs", "1"]);
+    let cfg = Config::from_arg_matches(&m).unwrap();
+    assert_eq!(cfg,
+               Config {
+                   name: "srv".to_owned(),
+                   jobs: 1,
+                   verbose: false,
+                   limit: None,
+                   ports: vec![],
+               });
+}
+
+#[test]
+fn from_arg_matches_missing_field() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("name").long("name").takes_value(true))
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .arg(Arg::with_name("limit").long("limit").takes_value(true))
+        .arg(Arg::with_name("ports").long("port").takes_value(true).multiple(true))
+        .get_matches_from(vec!["prog", "--name", "srv"]);
+    let err = Config::from_arg_matches(&m).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentNotFound);
+    assert_eq!(err.info, Some(vec!["jobs".to_owned()]));
+}
+
+#[test]
+fn from_arg_matches_invalid_field() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("name").long("name").takes_value(true))
+        .arg(Arg::with_name("jobs").long("jobs").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .arg(Arg::with_name("limit").long("limit").takes_value(true))
+        .arg(Arg::with_name("ports").long("port").takes_value(true).multiple(true));
+
+    let m = app.clone().get_matches_from(vec!["prog", "--name", "srv", "--jobs", "many"]);
+    let err = Config::from_arg_matches(&m).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert!(err.message.contains("The argument 'many' isn't a valid value"));
+
+    let m = app.get_matches_from(vec!["prog", "--name", "srv", "--jobs", "1", "--port", "99999"]);
+    let err = Config::from_arg_matches(&m).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+}
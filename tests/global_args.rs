@@ -0,0 +1,307 @@
+extern crate clap;
+extern crate regex;
+
+include!("../clap-test.rs");
+
+use clap::{App, Arg, SubCommand};
+
+static BUILD_HELP: &'static str = "prog-build 
+
+USAGE:
+    prog build [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+        --release    Builds in release mode
+    -V, --version    Prints version information
+
+OPTIONS:
+        --jobs <jobs>    Number of jobs
+
+GLOBAL OPTIONS:
+        --color <color>    Colors the output
+    -v, --verbose          Prints more output";
+
+static BUILD_HELP_NETWORK: &'static str = "prog-build 
+
+USAGE:
+    prog build [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+        --release    Builds in release mode
+    -V, --version    Prints version information
+
+OPTIONS:
+        --jobs <jobs>    Number of jobs
+
+GLOBAL OPTIONS:
+        --color <color>    Colors the output
+    -v, --verbose          Prints more output
+
+NETWORK:
+        --host <host>    The host to connect to";
+
+static BUILD_DOCS_HELP: &'static str = "prog-build-docs 
+
+USAGE:
+    prog build docs [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+GLOBAL OPTIONS:
+        --color <color>    Colors the output
+    -v, --verbose          Prints more output";
+
+static PROG_HELP: &'static str = "prog 
+
+USAGE:
+    prog [FLAGS] [OPTIONS] [SUBCOMMAND]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+    -v, --verbose    Prints more output
+
+OPTIONS:
+        --color <color>    Colors the output
+
+SUBCOMMANDS:
+    build    
+    help     Prints this message or the help of the given subcommand(s)";
+
+#[test]
+fn global_flag_before_subcommand() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")))
+        .get_matches_from(vec!["prog", "--verbose", "outer", "inner"]);
+
+    assert!(m.is_present("verbose"));
+    let outer = m.subcommand_matches("outer").unwrap();
+    assert!(outer.is_present("verbose"));
+    assert!(outer.subcommand_matches("inner").unwrap().is_present("verbose"));
+}
+
+#[test]
+fn global_flag_after_subcommand() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")))
+        .get_matches_from(vec!["prog", "outer", "inner", "-vv"]);
+
+    // values are only passed down the chain, never up
+    assert_eq!(m.occurrences_of("verbose"), 0);
+    let outer = m.subcommand_matches("outer").unwrap();
+    assert_eq!(outer.occurrences_of("verbose"), 0);
+    assert_eq!(outer.subcommand_matches("inner").unwrap().occurrences_of("verbose"), 2);
+}
+
+#[test]
+fn global_option_value_below_use() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")))
+        .get_matches_from(vec!["prog", "outer", "--color=never", "inner"]);
+
+    assert_eq!(m.value_of("color"), Some("auto"));
+    let outer = m.subcommand_matches("outer").unwrap();
+    assert_eq!(outer.value_of("color"), Some("never"));
+    assert_eq!(outer.subcommand_matches("inner").unwrap().value_of("color"), Some("never"));
+}
+
+#[test]
+fn global_option_used_value_wins_over_default() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")))
+        .get_matches_from(vec!["prog", "--color", "always", "outer", "inner"]);
+
+    let inner = m.subcommand_matches("outer").unwrap().subcommand_matches("inner").unwrap();
+    assert_eq!(inner.value_of("color"), Some("always"));
+    assert_eq!(m.value_of("color"), Some("always"));
+}
+
+#[test]
+fn global_option_default_only() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")))
+        .get_matches_from(vec!["prog", "outer"]);
+
+    assert!(!m.is_present("verbose"));
+    assert_eq!(m.value_of("color"), Some("auto"));
+    assert_eq!(m.subcommand_matches("outer").unwrap().value_of("color"), Some("auto"));
+}
+
+#[test]
+fn value_of_global_from_any_depth() {
+    for args in &[vec!["prog", "--color=never", "outer", "inner"],
+                  vec!["prog", "outer", "--color=never", "inner"],
+                  vec!["prog", "outer", "inner", "--color=never"]] {
+        let m = App::new("prog")
+            .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+            .arg(Arg::with_name("color")
+                .long("color")
+                .takes_value(true)
+                .default_value("auto")
+                .global(true))
+            .subcommand(SubCommand::with_name("outer")
+                .subcommand(SubCommand::with_name("inner")))
+            .get_matches_from(args.clone());
+        let outer = m.subcommand_matches("outer").unwrap();
+        let inner = outer.subcommand_matches("inner").unwrap();
+
+        assert_eq!(m.value_of_global("color"), Some("never"));
+        assert_eq!(outer.value_of_global("color"), Some("never"));
+        assert_eq!(inner.value_of_global("color"), Some("never"));
+    }
+}
+
+#[test]
+fn value_of_global_falls_back_to_default() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").long("verbose").multiple(true).global(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("outer")
+            .subcommand(SubCommand::with_name("inner")))
+        .get_matches_from(vec!["prog", "outer", "inner"]);
+
+    assert_eq!(m.value_of_global("color"), Some("auto"));
+}
+
+#[test]
+fn value_of_global_leaf_only_arg() {
+    let m = App::new("prog")
+        .subcommand(SubCommand::with_name("outer")
+            .arg(Arg::with_name("level").long("level").takes_value(true).default_value("1"))
+            .subcommand(SubCommand::with_name("inner")
+                .arg(Arg::with_name("level").long("level").takes_value(true))))
+        .get_matches_from(vec!["prog", "outer", "inner", "--level", "3"]);
+
+    assert_eq!(m.value_of("level"), None);
+    // the leaf is searched first, and a used value wins over the default in "outer"
+    assert_eq!(m.value_of_global("level"), Some("3"));
+    assert_eq!(m.value_of_global("missing"), None);
+}
+
+#[test]
+fn global_args_under_own_heading_in_subcommand_help() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .global(true)
+            .help("Prints more output"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .global(true)
+            .help("Colors the output"))
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release").help("Builds in release mode"))
+            .arg(Arg::with_name("jobs").long("jobs").takes_value(true).help("Number of jobs")));
+
+    test::check_err_output(app, "prog build --help", BUILD_HELP, false);
+}
+
+#[test]
+fn global_args_keep_explicit_heading_in_subcommand_help() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .global(true)
+            .help("Prints more output"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .global(true)
+            .help("Colors the output"))
+        .arg(Arg::with_name("host")
+            .long("host")
+            .takes_value(true)
+            .global(true)
+            .help_heading("NETWORK")
+            .help("The host to connect to"))
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release").help("Builds in release mode"))
+            .arg(Arg::with_name("jobs").long("jobs").takes_value(true).help("Number of jobs")));
+
+    test::check_err_output(app, "prog build --help", BUILD_HELP_NETWORK, false);
+}
+
+#[test]
+fn global_args_heading_in_nested_subcommand_help() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .global(true)
+            .help("Prints more output"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .global(true)
+            .help("Colors the output"))
+        .subcommand(SubCommand::with_name("build")
+            .subcommand(SubCommand::with_name("docs")));
+
+    test::check_err_output(app, "prog build docs --help", BUILD_DOCS_HELP, false);
+}
+
+#[test]
+fn global_args_not_under_heading_in_parent_help() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .global(true)
+            .help("Prints more output"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .global(true)
+            .help("Colors the output"))
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release").help("Builds in release mode"))
+            .arg(Arg::with_name("jobs").long("jobs").takes_value(true).help("Number of jobs")));
+
+    test::check_err_output(app, "prog --help", PROG_HELP, false);
+}
@@ -3,7 +3,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, ArgGroup, ErrorKind};
+use clap::{App, AppSettings, Arg, ArgGroup, ErrorKind};
 
 #[test]
 fn required_group_missing_arg() {
@@ -180,3 +180,158 @@ fn group_multiple_args_error() {
     assert_eq!(err.kind, ErrorKind::ArgumentConflict);
 
 }
+
+#[test]
+fn group_required_count_below_min() {
+    let result = App::new("group")
+        .setting(AppSettings::ColorNever)
+        .args_from_usage("-x [x] 'x coordinate'
+                          -y [y] 'y coordinate'
+                          -z [z] 'z coordinate'
+                          -w [w] 'w coordinate'")
+        .group(ArgGroup::with_name("coords")
+            .args(&["x", "y", "z", "w"])
+            .required_count(2, 3))
+        .get_matches_from_safe(vec!["group", "-x", "1"]);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert!(err.message.contains("between 2 and 3"));
+    assert!(err.message.contains("-x <x>"));
+}
+
+#[test]
+fn group_required_count_none() {
+    let result = App::new("group")
+        .setting(AppSettings::ColorNever)
+        .args_from_usage("-x [x] 'x coordinate'
+                          -y [y] 'y coordinate'
+                          -z [z] 'z coordinate'
+                          -w [w] 'w coordinate'")
+        .group(ArgGroup::with_name("coords")
+            .args(&["x", "y", "z", "w"])
+            .required_count(2, 3))
+        .get_matches_from_safe(vec!["group"]);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn group_required_count_in_range() {
+    let result = App::new("group")
+        .setting(AppSettings::ColorNever)
+        .args_from_usage("-x [x] 'x coordinate'
+                          -y [y] 'y coordinate'
+                          -z [z] 'z coordinate'
+                          -w [w] 'w coordinate'")
+        .group(ArgGroup::with_name("coords")
+            .args(&["x", "y", "z", "w"])
+            .required_count(2, 3))
+        .get_matches_from_safe(vec!["group", "-x", "1", "-y", "2"]);
+    assert!(result.is_ok());
+    let m = result.unwrap();
+    assert!(m.is_present("coords"));
+    assert_eq!(m.value_of("x"), Some("1"));
+    assert_eq!(m.value_of("y"), Some("2"));
+}
+
+#[test]
+fn group_required_count_above_max() {
+    let result = App::new("group")
+        .setting(AppSettings::ColorNever)
+        .args_from_usage("-x [x] 'x coordinate'
+                          -y [y] 'y coordinate'
+                          -z [z] 'z coordinate'
+                          -w [w] 'w coordinate'")
+        .group(ArgGroup::with_name("coords")
+            .args(&["x", "y", "z", "w"])
+            .required_count(2, 3))
+        .get_matches_from_safe(vec!["group", "-x", "1", "-y", "2", "-z", "3", "-w", "4"]);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentConflict);
+    assert!(err.message.contains("4 were supplied"));
+}
+
+#[test]
+fn exclusive_group_one_member() {
+    let result = App::new("group")
+        .args_from_usage("--json 'json output'
+                          --yaml 'yaml output'
+                          --toml 'toml output'")
+        .group(ArgGroup::with_name("format")
+            .args(&["json", "yaml", "toml"])
+            .required(true))
+        .get_matches_from_safe(vec!["group", "--yaml"]);
+    assert!(result.is_ok());
+    let m = result.unwrap();
+    assert!(m.is_present("format"));
+    assert!(m.is_present("yaml"));
+    assert!(!m.is_present("json"));
+    assert!(!m.is_present("toml"));
+}
+
+#[test]
+fn exclusive_group_two_members() {
+    let result = App::new("group")
+        .args_from_usage("--json 'json output'
+                          --yaml 'yaml output'
+                          --toml 'toml output'")
+        .group(ArgGroup::with_name("format")
+            .args(&["json", "yaml", "toml"])
+            .required(true))
+        .get_matches_from_safe(vec!["group", "--json", "--toml"]);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn exclusive_group_none_required() {
+    let result = App::new("group")
+        .args_from_usage("--json 'json output'
+                          --yaml 'yaml output'
+                          --toml 'toml output'")
+        .group(ArgGroup::with_name("format")
+            .args(&["json", "yaml", "toml"])
+            .required(true))
+        .get_matches_from_safe(vec!["group"]);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn value_of_group_member() {
+    let m = App::new("group")
+        .args_from_usage("--json 'json output'
+                          --yaml 'yaml output'
+                          --toml 'toml output'")
+        .group(ArgGroup::with_name("format")
+            .args(&["json", "yaml", "toml"])
+            .required(true))
+        .get_matches_from(vec!["group", "--toml"]);
+    assert_eq!(m.value_of_group("format"), Some("toml"));
+}
+
+#[test]
+fn value_of_group_empty() {
+    let m = App::new("group")
+        .args_from_usage("--json 'json output'
+                          --yaml 'yaml output'")
+        .group(ArgGroup::with_name("format")
+            .args(&["json", "yaml"]))
+        .get_matches_from(vec!["group"]);
+    assert_eq!(m.value_of_group("format"), None);
+    assert_eq!(m.value_of_group("nope"), None);
+}
+
+#[test]
+fn value_of_group_option_member() {
+    let m = App::new("group")
+        .args_from_usage("-i, --input [FILE] 'an input'
+                          --stdin 'read stdin'")
+        .group(ArgGroup::with_name("source")
+            .args(&["input", "stdin"]))
+        .get_matches_from(vec!["group", "-i", "file.txt"]);
+    assert_eq!(m.value_of_group("source"), Some("input"));
+    assert_eq!(m.value_of("source"), Some("file.txt"));
+}
@@ -3,7 +3,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, AppSettings, SubCommand, ErrorKind, Arg};
+use clap::{App, AppSettings, ColorChoice, SubCommand, ErrorKind, Arg};
 
 static HELP: &'static str = "clap-test v1.4.8
 Kevin K. <kbknapp@gmail.com>
@@ -58,11 +58,15 @@ USAGE:
     subcmd [FLAGS] [OPTIONS] [--] [scpositional]
 
 FLAGS:
-    -f, --flag    tests flags
+    -h, --help       Prints help information
+    -V, --version    Prints version information
 
 OPTIONS:
     -o, --option <scoption>...    tests options
 
+GLOBAL OPTIONS:
+    -f, --flag    tests flags
+
 ARGS:
     <scpositional>    tests positionals";
 
@@ -133,6 +137,26 @@ OPTIONS:
     -c, --cafe <FILE>    A coffeehouse, coffee shop, or café.
     -p, --pos <VAL>      Some vals [values: fast, slow]";
 
+static HIDE_DEFAULT_VAL: &'static str = "default 0.1
+
+USAGE:
+    default [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -a, --arg <argument>    Pass an argument to the program.
+    -m, --mode <MODE>       The mode [default: fast]";
+
+static OVERRIDE_HELP: &'static str = "myapp v1.0
+Does awesome things
+
+Usage: myapp [-v] <file>
+
+    -v    Be verbose";
+
 static OLD_NEWLINE_CHARS: &'static str = "ctest 0.1
 
 USAGE:
@@ -145,6 +169,45 @@ FLAGS:
     -V, --version    Prints version information";
 
 
+static HELP_TOPICS: &'static str = "ctest 0.1
+
+USAGE:
+    ctest
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+TOPICS:
+    config
+    paths";
+
+static LONG_HELP_LONG: &'static str = "ctest 0.1
+
+USAGE:
+    ctest [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+    -v               Uses verbose output
+
+OPTIONS:
+        --config <config>    Sets the config file to use instead of the default one found in the current directory";
+
+static LONG_HELP_SHORT: &'static str = "ctest 0.1
+
+USAGE:
+    ctest [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+    -v               Uses verbose output
+
+OPTIONS:
+        --config <config>    Sets the config file";
+
 static ISSUE_688: &'static str = "ctest 0.1
 
 USAGE:
@@ -159,6 +222,26 @@ OPTIONS:
                              images. The default is Linear (Bilinear). [values: Nearest, Linear, Cubic, Gaussian,
                              Lanczos3]";
 
+static BEFORE_AFTER_HELP: &'static str = "BEFORE TEXT
+
+ctest 0.1
+
+USAGE:
+    ctest [OPTIONS]
+
+FLAGS:
+    -h, --help
+            Prints help information
+    -V, --version
+            Prints version information
+
+OPTIONS:
+        --opt <opt>
+            some option
+
+EXAMPLES: ctest --opt val runs the tool
+with a value given for the option";
+
 static ISSUE_702: &'static str = "myapp 1.0
 foo
 bar
@@ -179,6 +262,46 @@ ARGS:
     <arg1>       some option
     <arg2>...    some option";
 
+static HELP_HEADINGS: &'static str = "ctest 0.1
+
+USAGE:
+    ctest [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+    -v, --verbose    Uses verbose output
+
+NETWORK:
+    -p, --port <port>          Sets the port
+    -H, --host <host>          Sets the host
+    -t, --timeout <timeout>    Sets the timeout
+
+OUTPUT:
+    -q, --quiet        Silences output
+    -o, --out <out>    Sets the output file";
+
+static APP_HELP_HEADINGS: &'static str = "ctest 0.1
+
+USAGE:
+    ctest [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+    -v, --verbose    Uses verbose output
+
+OPTIONS:
+    -c, --config <config>    Sets the config file
+
+NETWORK OPTIONS:
+    -H, --host <host>    Sets the host
+    -O, --offline        Works offline
+
+OUTPUT OPTIONS:
+    -o, --out <out>    Sets the output file
+    -q, --quiet        Silences output";
+
 #[test]
 fn help_short() {
     let m = App::new("test")
@@ -343,6 +466,63 @@ fn hide_possible_vals() {
     test::check_err_output(app, "ctest --help", HIDE_POS_VALS, false);
 }
 
+#[test]
+fn hide_default_val() {
+    let app = App::new("default")
+        .version("0.1")
+        .arg(Arg::with_name("argument")
+           .short("a")
+           .long("arg")
+           .value_name("argument")
+           .help("Pass an argument to the program.")
+           .default_value("default-argument")
+           .hide_default_value(true))
+        .arg(Arg::with_name("mode")
+           .short("m")
+           .long("mode")
+           .value_name("MODE")
+           .help("The mode")
+           .default_value("fast"));
+    test::check_err_output(app, "default --help", HIDE_DEFAULT_VAL, false);
+}
+
+#[test]
+fn hide_default_val_still_applied() {
+    let m = App::new("default")
+        .version("0.1")
+        .arg(Arg::with_name("argument")
+           .short("a")
+           .long("arg")
+           .value_name("argument")
+           .help("Pass an argument to the program.")
+           .default_value("default-argument")
+           .hide_default_value(true))
+        .arg(Arg::with_name("mode")
+           .short("m")
+           .long("mode")
+           .value_name("MODE")
+           .help("The mode")
+           .default_value("fast"))
+        .get_matches_from(vec!["default"]);
+
+    assert_eq!(m.value_of("argument"), Some("default-argument"));
+    assert_eq!(m.value_of("mode"), Some("fast"));
+}
+
+#[test]
+fn hide_possible_vals_still_validated() {
+    let res = App::new("ctest")
+        .arg(Arg::with_name("cafe")
+           .short("c")
+           .takes_value(true)
+           .hide_possible_values(true)
+           .possible_values(&["fast", "slow"]))
+        .get_matches_from_safe(vec!["ctest", "-c", "medium"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
 #[test]
 fn issue_626_panic() {
     let app = App::new("ctest")
@@ -453,4 +633,704 @@ fn issue_702_multiple_values() {
              .multiple(true)
              .takes_value(true));
     test::check_err_output(app, "myapp --help", ISSUE_702, false);
-}
\ No newline at end of file
+}
+
+#[test]
+fn help_topic_known() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .help_topic("config", "Configuration is read from ~/.ctest")
+        .help_topic("paths", "Paths may be relative or absolute");
+    test::check_err_output(app, "ctest --help=config", "Configuration is read from ~/.ctest", false);
+}
+
+#[test]
+fn help_topic_subcommand() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .help_topic("config", "Configuration is read from ~/.ctest")
+        .help_topic("paths", "Paths may be relative or absolute")
+        .subcommand(SubCommand::with_name("run"));
+    test::check_err_output(app, "ctest help topic paths", "Paths may be relative or absolute", false);
+}
+
+#[test]
+fn help_topic_unknown() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .help_topic("config", "Configuration is read from ~/.ctest")
+        .help_topic("paths", "Paths may be relative or absolute");
+    test::check_err_output(app,
+                           "ctest --help=nope",
+                           "error: 'nope' isn't a known help topic\n\t[topics: config, paths]",
+                           true);
+}
+
+#[test]
+fn help_topic_none() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .help_topic("config", "Configuration is read from ~/.ctest")
+        .help_topic("paths", "Paths may be relative or absolute");
+    test::check_err_output(app, "ctest --help", HELP_TOPICS, false);
+}
+
+#[test]
+fn help_headings_display_order() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("timeout")
+            .short("t")
+            .long("timeout")
+            .takes_value(true)
+            .help_heading("NETWORK")
+            .display_order(3)
+            .help("Sets the timeout"))
+        .arg(Arg::with_name("host")
+            .short("H")
+            .long("host")
+            .takes_value(true)
+            .help_heading("NETWORK")
+            .display_order(2)
+            .help("Sets the host"))
+        .arg(Arg::with_name("out")
+            .short("o")
+            .long("out")
+            .takes_value(true)
+            .help_heading("OUTPUT")
+            .display_order(2)
+            .help("Sets the output file"))
+        .arg(Arg::with_name("quiet")
+            .short("q")
+            .long("quiet")
+            .help_heading("OUTPUT")
+            .display_order(1)
+            .help("Silences output"))
+        .arg(Arg::with_name("port")
+            .short("p")
+            .long("port")
+            .takes_value(true)
+            .help_heading("NETWORK")
+            .display_order(1)
+            .help("Sets the port"));
+    test::check_err_output(app, "ctest --help", HELP_HEADINGS, false);
+}
+
+#[test]
+fn app_help_headings() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .takes_value(true)
+            .help("Sets the config file"))
+        .help_heading("NETWORK OPTIONS")
+        .arg(Arg::with_name("offline")
+            .short("O")
+            .long("offline")
+            .help("Works offline"))
+        .arg(Arg::with_name("host")
+            .short("H")
+            .long("host")
+            .takes_value(true)
+            .help("Sets the host"))
+        .arg(Arg::with_name("out")
+            .short("o")
+            .long("out")
+            .takes_value(true)
+            .help_heading("OUTPUT OPTIONS")
+            .help("Sets the output file"))
+        .help_heading("OUTPUT OPTIONS")
+        .arg(Arg::with_name("quiet")
+            .short("q")
+            .long("quiet")
+            .help("Silences output"));
+    test::check_err_output(app, "ctest --help", APP_HELP_HEADINGS, false);
+}
+
+#[test]
+fn app_help_heading_sets_arg_heading() {
+    let app = App::new("ctest")
+        .arg(Arg::with_name("verbose").short("v"))
+        .help_heading("NETWORK OPTIONS")
+        .arg(Arg::with_name("host").long("host").takes_value(true));
+    let headings = app.get_arguments()
+        .map(|a| (a.get_name(), a.help_heading))
+        .collect::<Vec<_>>();
+    assert_eq!(headings,
+               [("verbose", None), ("host", Some("NETWORK OPTIONS"))]);
+}
+
+#[test]
+fn write_help_before_parsing() {
+    let mut buf = vec![];
+    App::new("repl")
+        .version("0.1")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("file")
+            .help("The file to load"))
+        .write_help(&mut buf).expect("failed to write help");
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.starts_with("repl 0.1"));
+    assert!(help.contains("USAGE:\n    repl [FLAGS] [file]"));
+    assert!(help.contains("Uses verbose output"));
+    assert!(help.contains("The file to load"));
+    assert!(help.contains("-h, --help       Prints help information"));
+}
+
+#[test]
+fn write_help_same_as_help_flag() {
+    let mut buf = vec![];
+    let app = App::new("repl")
+        .version("0.1")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("file")
+            .help("The file to load"));
+    app.write_help(&mut buf).expect("failed to write help");
+    test::check_err_output(app,
+                           "repl --help",
+                           &*String::from_utf8(buf).unwrap(),
+                           false);
+}
+
+#[test]
+fn write_help_repeatedly() {
+    let mut app = App::new("repl")
+        .version("0.1")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .arg(Arg::with_name("file")
+            .help("The file to load"));
+    let mut first = vec![];
+    app.write_help(&mut first).expect("failed to write help");
+    let _ = app.get_matches_from_safe_borrow(vec![""]);
+    let mut second = vec![];
+    app.write_help(&mut second).expect("failed to write help");
+
+    assert_eq!(first, second);
+    assert_eq!(String::from_utf8(second).unwrap().matches("Prints help information").count(), 1);
+}
+
+#[test]
+fn long_help_shown_for_help_long() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(120)
+        .arg(Arg::with_name("config")
+            .long("config")
+            .takes_value(true)
+            .help("Sets the config file")
+            .long_help("Sets the config file to use instead of the default one found in the \
+                        current directory"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"));
+    test::check_err_output(app, "ctest --help", LONG_HELP_LONG, false);
+}
+
+#[test]
+fn short_help_shown_for_help_short() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(120)
+        .arg(Arg::with_name("config")
+            .long("config")
+            .takes_value(true)
+            .help("Sets the config file")
+            .long_help("Sets the config file to use instead of the default one found in the \
+                        current directory"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"));
+    test::check_err_output(app, "ctest -h", LONG_HELP_SHORT, false);
+}
+
+#[test]
+fn write_long_help_uses_long_help() {
+    let mut short = vec![];
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(120)
+        .arg(Arg::with_name("config")
+            .long("config")
+            .takes_value(true)
+            .help("Sets the config file")
+            .long_help("Sets the config file to use instead of the default one found in the \
+                        current directory"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"));
+    app.write_help(&mut short).expect("failed to write help");
+    let mut long = vec![];
+    app.write_long_help(&mut long).expect("failed to write help");
+
+    assert!(!String::from_utf8(short).unwrap().contains("instead of the default one"));
+    assert!(String::from_utf8(long).unwrap().contains("instead of the default one"));
+}
+
+#[test]
+fn before_after_help_short() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(40)
+        .before_help("BEFORE TEXT")
+        .after_help("EXAMPLES: ctest --opt val runs the tool with a value given for the option")
+        .arg(Arg::with_name("opt")
+            .long("opt")
+            .takes_value(true)
+            .help("some option"));
+
+    test::check_err_output(app, "ctest -h", BEFORE_AFTER_HELP, false);
+}
+
+#[test]
+fn before_after_help_long() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(40)
+        .before_help("BEFORE TEXT")
+        .after_help("EXAMPLES: ctest --opt val runs the tool with a value given for the option")
+        .arg(Arg::with_name("opt")
+            .long("opt")
+            .takes_value(true)
+            .help("some option"));
+
+    test::check_err_output(app, "ctest --help", BEFORE_AFTER_HELP, false);
+}
+
+fn assert_help_width(app: App, width: usize) {
+    let mut buf = vec![];
+    app.write_help(&mut buf).expect("failed to write help");
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.lines().count() > 12, "help was not wrapped:\n{}", help);
+    for line in help.lines() {
+        assert!(line.len() <= width,
+                "line exceeds {} columns: {:?}\n{}",
+                width,
+                line,
+                help);
+    }
+}
+
+#[test]
+fn wrap_help_to_term_width() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .value_name("FILE")
+            .help("Sets the configuration file which is read before any of the other options \
+                   are applied, falling back to the defaults when it does not exist"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Prints additional information about every step that is taken while running, \
+                   which can be useful when tracking down problems"))
+        .arg(Arg::with_name("input")
+            .help("The input file to process, which may also be a directory in which case every \
+                   file inside of it is processed in turn"));
+    assert_help_width(app.set_term_width(60), 60);
+}
+
+#[test]
+fn wrap_help_to_max_term_width() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .value_name("FILE")
+            .help("Sets the configuration file which is read before any of the other options \
+                   are applied, falling back to the defaults when it does not exist"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Prints additional information about every step that is taken while running, \
+                   which can be useful when tracking down problems"))
+        .arg(Arg::with_name("input")
+            .help("The input file to process, which may also be a directory in which case every \
+                   file inside of it is processed in turn"));
+    assert_help_width(app.max_term_width(60), 60);
+}
+
+#[test]
+fn wrap_help_to_narrow_term_width() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .value_name("FILE")
+            .help("Sets the configuration file which is read before any of the other options \
+                   are applied, falling back to the defaults when it does not exist"))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .long("verbose")
+            .help("Prints additional information about every step that is taken while running, \
+                   which can be useful when tracking down problems"))
+        .arg(Arg::with_name("input")
+            .help("The input file to process, which may also be a directory in which case every \
+                   file inside of it is processed in turn"));
+    assert_help_width(app.set_term_width(45), 45);
+}
+
+#[cfg(all(feature = "color", not(target_os = "windows")))]
+#[test]
+fn colored_help_always() {
+    let mut buf = vec![];
+    App::new("ctest")
+        .version("0.1")
+        .setting(AppSettings::ColoredHelp)
+        .color(ColorChoice::Always)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .write_help(&mut buf).expect("failed to write help");
+
+    assert!(String::from_utf8(buf).unwrap().contains("\u{1b}["));
+}
+
+#[test]
+fn colored_help_never() {
+    let mut buf = vec![];
+    App::new("ctest")
+        .version("0.1")
+        .setting(AppSettings::ColoredHelp)
+        .color(ColorChoice::Never)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .write_help(&mut buf).expect("failed to write help");
+
+    assert!(!String::from_utf8(buf).unwrap().contains("\u{1b}["));
+}
+
+#[cfg(all(feature = "color", not(target_os = "windows")))]
+#[test]
+fn colored_error_always() {
+    let err = App::new("ctest")
+        .version("0.1")
+        .setting(AppSettings::ColoredHelp)
+        .color(ColorChoice::Always)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .get_matches_from_safe(vec!["ctest", "--bad"])
+        .unwrap_err();
+
+    assert!(err.message.contains("\u{1b}["));
+}
+
+#[test]
+fn colored_error_never() {
+    let err = App::new("ctest")
+        .version("0.1")
+        .setting(AppSettings::ColoredHelp)
+        .color(ColorChoice::Never)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .help("Uses verbose output"))
+        .get_matches_from_safe(vec!["ctest", "--bad"])
+        .unwrap_err();
+
+    assert!(!err.message.contains("\u{1b}["));
+}
+
+#[test]
+fn color_choice_replaces_previous() {
+    let app = App::new("ctest")
+        .setting(AppSettings::ColorAlways)
+        .color(ColorChoice::Never);
+
+    assert!(app.p.is_set(AppSettings::ColorNever));
+    assert!(!app.p.is_set(AppSettings::ColorAlways));
+}
+
+fn render_help(mut app: App<'static, 'static>) -> String {
+    let _ = app.get_matches_from_safe_borrow(vec![""]);
+    let mut help = vec![];
+    app.write_help(&mut help).expect("failed to print help");
+    String::from_utf8(help).unwrap()
+}
+
+// Returns the line containing `pat` along with the line after it
+fn line_and_next<'a>(help: &'a str, pat: &str) -> (&'a str, &'a str) {
+    let lines = help.lines().collect::<Vec<_>>();
+    let i = lines.iter().position(|l| l.contains(pat)).expect(pat);
+    (lines[i], lines.get(i + 1).map_or("", |l| *l))
+}
+
+#[test]
+fn next_line_help_for_single_arg() {
+    let app = App::new("nlh")
+        .version("0.1")
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .value_name("FILE")
+            .help("Sets a custom config file"))
+        .arg(Arg::with_name("description")
+            .short("d")
+            .long("description")
+            .value_name("TEXT")
+            .next_line_help(true)
+            .help("A very long description of what exactly this option is supposed to do"));
+    let help = render_help(app);
+
+    let (line, next) = line_and_next(&help, "--description <TEXT>");
+    assert_eq!(line.trim_right(), "    -d, --description <TEXT>");
+    assert_eq!(next,
+               "            A very long description of what exactly this option is supposed to do");
+
+    // the other args stay inline, and aren't pushed out by the long flagged arg
+    let (line, _) = line_and_next(&help, "--config <FILE>");
+    assert_eq!(line, "    -c, --config <FILE>    Sets a custom config file");
+}
+
+#[test]
+fn next_line_help_setting_for_all_args() {
+    let app = App::new("nlh")
+        .version("0.1")
+        .arg(Arg::with_name("config")
+            .short("c")
+            .long("config")
+            .value_name("FILE")
+            .help("Sets a custom config file"))
+        .arg(Arg::with_name("description")
+            .short("d")
+            .long("description")
+            .value_name("TEXT")
+            .next_line_help(true)
+            .help("A very long description of what exactly this option is supposed to do"));
+    let help = render_help(app.setting(AppSettings::NextLineHelp));
+
+    let (line, next) = line_and_next(&help, "--config <FILE>");
+    assert_eq!(line.trim_right(), "    -c, --config <FILE>");
+    assert_eq!(next, "            Sets a custom config file");
+
+    let (line, next) = line_and_next(&help, "--help");
+    assert_eq!(line.trim_right(), "    -h, --help");
+    assert_eq!(next, "            Prints help information");
+}
+
+#[test]
+fn two_column_alignment() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(120)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("all")
+            .short("a")
+            .long("all")
+            .help("Shows everything"))
+        .arg(Arg::with_name("config")
+            .long("configuration-file")
+            .value_name("FILE")
+            .help("Overrides the configuration file"))
+        .arg(Arg::with_name("mode")
+            .short("m")
+            .long("mode")
+            .value_name("MODE")
+            .help("Sets the mode"))
+        .arg(Arg::with_name("quiet")
+            .short("q")
+            .long("quiet")
+            .help("Silences all output, including the warnings that are normally printed"));
+    let help = render_help(app);
+
+    // short and long switches share a single help column, sized by the longest of them
+    let (line, _) = line_and_next(&help, "--all");
+    assert_eq!(line, "    -a, --all                          Shows everything");
+    let (line, _) = line_and_next(&help, "--configuration-file <FILE>");
+    assert_eq!(line,
+               "        --configuration-file <FILE>    Overrides the configuration file");
+    let (line, _) = line_and_next(&help, "--mode <MODE>");
+    assert_eq!(line, "    -m, --mode <MODE>                  Sets the mode");
+}
+
+#[test]
+fn two_column_width_capped() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(70)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("all")
+            .short("a")
+            .long("all")
+            .help("Shows everything"))
+        .arg(Arg::with_name("config")
+            .long("configuration-file")
+            .value_name("FILE")
+            .help("Overrides the configuration file"))
+        .arg(Arg::with_name("mode")
+            .short("m")
+            .long("mode")
+            .value_name("MODE")
+            .help("Sets the mode"))
+        .arg(Arg::with_name("quiet")
+            .short("q")
+            .long("quiet")
+            .help("Silences all output, including the warnings that are normally printed"));
+    let help = render_help(app);
+
+    // the arg wider than half of the terminal doesn't push the others' help out
+    let (line, next) = line_and_next(&help, "--configuration-file <FILE>");
+    assert_eq!(line.trim_right(), "        --configuration-file <FILE>");
+    assert_eq!(next, "            Overrides the configuration file");
+
+    let (line, _) = line_and_next(&help, "--all");
+    assert_eq!(line, "    -a, --all            Shows everything");
+    let (line, _) = line_and_next(&help, "--help");
+    assert_eq!(line, "    -h, --help           Prints help information");
+    let (line, _) = line_and_next(&help, "--mode <MODE>");
+    assert_eq!(line, "    -m, --mode <MODE>    Sets the mode");
+}
+
+#[test]
+fn two_column_wraps_with_hanging_indent() {
+    let app = App::new("ctest")
+        .version("0.1")
+        .set_term_width(70)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("all")
+            .short("a")
+            .long("all")
+            .help("Shows everything"))
+        .arg(Arg::with_name("config")
+            .long("configuration-file")
+            .value_name("FILE")
+            .help("Overrides the configuration file"))
+        .arg(Arg::with_name("mode")
+            .short("m")
+            .long("mode")
+            .value_name("MODE")
+            .help("Sets the mode"))
+        .arg(Arg::with_name("quiet")
+            .short("q")
+            .long("quiet")
+            .help("Silences all output, including the warnings that are normally printed"));
+    let help = render_help(app);
+
+    let (line, next) = line_and_next(&help, "--quiet");
+    assert_eq!(line,
+               "    -q, --quiet          Silences all output, including the warnings");
+    assert_eq!(next, "                         that are normally printed");
+    for line in help.lines() {
+        assert!(line.len() <= 70, "line too wide: {:?}", line);
+    }
+}
+
+#[test]
+fn override_usage_in_help() {
+    let app = App::new("mode")
+        .override_usage("mode (--fast | --slow) <input>")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("fast").long("fast").conflicts_with("slow"))
+        .arg(Arg::with_name("slow").long("slow"))
+        .arg(Arg::with_name("input").required(true));
+    let help = render_help(app);
+
+    assert!(help.contains("USAGE:\n    mode (--fast | --slow) <input>\n"));
+    assert!(!help.contains("mode [FLAGS] <input>"));
+}
+
+#[test]
+fn override_usage_in_error() {
+    let err = App::new("mode")
+        .override_usage("mode (--fast | --slow) <input>")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("fast").long("fast").conflicts_with("slow"))
+        .arg(Arg::with_name("slow").long("slow"))
+        .arg(Arg::with_name("input").required(true))
+        .get_matches_from_safe(vec!["mode", "--fast", "--slow", "file"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ArgumentConflict);
+    assert!(err.message.contains("USAGE:\n    mode (--fast | --slow) <input>\n"),
+            "{}",
+            err.message);
+}
+
+#[test]
+fn override_usage_in_matches() {
+    let m = App::new("mode")
+        .override_usage("mode (--fast | --slow) <input>")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("fast").long("fast").conflicts_with("slow"))
+        .arg(Arg::with_name("slow").long("slow"))
+        .arg(Arg::with_name("input").required(true))
+        .get_matches_from(vec!["mode", "--fast", "file"]);
+
+    assert_eq!(m.usage(), "USAGE:\n    mode (--fast | --slow) <input>");
+}
+
+#[test]
+fn override_help_with_flag() {
+    let app = App::new("myapp")
+        .version("1.0")
+        .about("This about text is generated")
+        .override_help(OVERRIDE_HELP)
+        .arg(Arg::with_name("verbose").short("v").help("Generated help for verbose"))
+        .arg(Arg::with_name("file").required(true));
+    test::check_err_output(app, "myapp --help", OVERRIDE_HELP, false);
+}
+
+#[test]
+fn override_help_print_help() {
+    let app = App::new("myapp")
+        .version("1.0")
+        .about("This about text is generated")
+        .override_help(OVERRIDE_HELP)
+        .arg(Arg::with_name("verbose").short("v").help("Generated help for verbose"))
+        .arg(Arg::with_name("file").required(true));
+    test::check_help(app.clone(), OVERRIDE_HELP);
+
+    let help = render_help(app);
+    assert!(!help.contains("USAGE:"));
+    assert!(!help.contains("Generated help for verbose"));
+    assert!(!help.contains("This about text is generated"));
+}
+
+#[test]
+fn custom_help_short() {
+    let app = App::new("prog")
+        .help_short("?")
+        .arg(Arg::with_name("host").short("h").takes_value(true));
+
+    let res = app.clone().get_matches_from_safe(vec!["prog", "-?"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::HelpDisplayed);
+
+    let m = app.clone().get_matches_from(vec!["prog", "-h", "localhost"]);
+    assert_eq!(m.value_of("host"), Some("localhost"));
+
+    let help = render_help(app);
+    assert!(help.contains("-?, --help"));
+    assert!(!help.contains("-h, --help"));
+}
+
+#[test]
+fn custom_help_short_frees_default() {
+    let res = App::new("prog")
+        .help_short("?")
+        .get_matches_from_safe(vec!["prog", "-h"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
@@ -1,6 +1,31 @@
 extern crate clap;
+extern crate regex;
 
-use clap::{App, Arg};
+include!("../clap-test.rs");
+
+use clap::{App, AppSettings, Arg, SubCommand};
+
+static HIDDEN_SHORT_HELP: &'static str = "test 1.3
+
+USAGE:
+    test [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -q, --quiet      quiet mode
+    -V, --version    Prints version information";
+
+static HIDDEN_LONG_HELP: &'static str = "test 1.3
+
+USAGE:
+    test [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --cache-dir <dir>    sets the cache directory";
 
 #[test]
 fn hidden_args() {
@@ -32,3 +57,56 @@ FLAGS:
 OPTIONS:
         --option <opt>    some option"));
 }
+
+#[test]
+fn hidden_short_help() {
+    let app = App::new("test")
+        .version("1.3")
+        .arg(Arg::from_usage("--cache-dir [dir] 'sets the cache directory'")
+            .hidden_short_help(true))
+        .arg(Arg::from_usage("-q, --quiet 'quiet mode'").hidden_long_help(true))
+        .arg(Arg::from_usage("-d, --debug 'debug mode'").hidden(true));
+    test::check_err_output(app, "test -h", HIDDEN_SHORT_HELP, false);
+}
+
+#[test]
+fn hidden_long_help() {
+    let app = App::new("test")
+        .version("1.3")
+        .arg(Arg::from_usage("--cache-dir [dir] 'sets the cache directory'")
+            .hidden_short_help(true))
+        .arg(Arg::from_usage("-q, --quiet 'quiet mode'").hidden_long_help(true))
+        .arg(Arg::from_usage("-d, --debug 'debug mode'").hidden(true));
+    test::check_err_output(app, "test --help", HIDDEN_LONG_HELP, false);
+}
+
+#[test]
+fn hidden_args_still_parse() {
+    let m = App::new("test")
+        .version("1.3")
+        .arg(Arg::from_usage("--cache-dir [dir] 'sets the cache directory'")
+            .hidden_short_help(true))
+        .arg(Arg::from_usage("-q, --quiet 'quiet mode'").hidden_long_help(true))
+        .arg(Arg::from_usage("-d, --debug 'debug mode'").hidden(true))
+        .get_matches_from_safe(vec!["test", "--cache-dir", "/tmp", "-q", "-d"])
+        .unwrap();
+
+    assert_eq!(m.value_of("cache-dir"), Some("/tmp"));
+    assert!(m.is_present("quiet"));
+    assert!(m.is_present("debug"));
+}
+
+#[test]
+fn hidden_subcommand_still_parses() {
+    let mut app = App::new("test")
+        .subcommand(SubCommand::with_name("secret").setting(AppSettings::Hidden))
+        .subcommand(SubCommand::with_name("public"));
+    let mut help = vec![];
+    app.write_help(&mut help).expect("failed to print help");
+    let help = String::from_utf8(help).unwrap();
+
+    assert!(help.contains("public"));
+    assert!(!help.contains("secret"));
+    let m = app.get_matches_from_safe_borrow(vec!["test", "secret"]).unwrap();
+    assert_eq!(m.subcommand_name(), Some("secret"));
+}
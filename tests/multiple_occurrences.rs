@@ -1,6 +1,8 @@
 extern crate clap;
 
-use clap::{App, Arg};
+use std::iter;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
 
 
 #[test]
@@ -74,3 +76,131 @@ fn multiple_occurrences_of_flags_large_quantity() {
     assert!(m.is_present("multflag"));
     assert_eq!(m.occurrences_of("multflag"), 1024);
 }
+
+#[test]
+fn max_occurrences_exact() {
+    let m = App::new("multiple_occurrences")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .max_occurrences(3))
+        .arg(Arg::with_name("include")
+            .short("I")
+            .takes_value(true)
+            .max_occurrences(2))
+        .arg(Arg::with_name("debug")
+            .short("d"))
+        .get_matches_from_safe(vec!["", "-vvv", "-I", "a", "-I", "b"])
+        .unwrap();
+
+    assert_eq!(m.occurrences_of("verbose"), 3);
+    assert_eq!(m.occurrences_of("include"), 2);
+}
+
+#[test]
+fn max_occurrences_exceeded_flag() {
+    let err = App::new("multiple_occurrences")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .max_occurrences(3))
+        .arg(Arg::with_name("include")
+            .short("I")
+            .takes_value(true)
+            .max_occurrences(2))
+        .arg(Arg::with_name("debug")
+            .short("d"))
+        .get_matches_from_safe(vec!["", "-vv", "-vv"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::TooManyOccurrences);
+    assert!(err.message.contains("'-v' may be used at most 3 times, but was used 4 times"));
+}
+
+#[test]
+fn max_occurrences_exceeded_option() {
+    let err = App::new("multiple_occurrences")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .max_occurrences(3))
+        .arg(Arg::with_name("include")
+            .short("I")
+            .takes_value(true)
+            .max_occurrences(2))
+        .arg(Arg::with_name("debug")
+            .short("d"))
+        .get_matches_from_safe(vec!["", "-I", "a", "-I", "b", "-I", "c"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::TooManyOccurrences);
+    assert!(err.message.contains("at most 2 times, but was used 3 times"));
+}
+
+#[test]
+fn max_occurrences_non_multiple_unaffected() {
+    let app = App::new("multiple_occurrences")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .max_occurrences(3))
+        .arg(Arg::with_name("include")
+            .short("I")
+            .takes_value(true)
+            .max_occurrences(2))
+        .arg(Arg::with_name("debug")
+            .short("d"));
+    let m = app.clone().get_matches_from_safe(vec!["", "-d"]).unwrap();
+    assert_eq!(m.occurrences_of("debug"), 1);
+
+    let err = app.get_matches_from_safe(vec!["", "-d", "-d"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnexpectedMultipleUsage);
+}
+
+#[test]
+fn flag_count_matches_occurrences() {
+    let m = App::new("multiple_occurrences")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .get_matches_from(vec!["", "-vvv", "-v"]);
+
+    assert_eq!(m.flag_count("verbose"), 4);
+    assert_eq!(m.occurrences_of("verbose"), 4);
+}
+
+#[test]
+fn flag_count_not_present() {
+    let m = App::new("multiple_occurrences")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .get_matches_from(vec![""]);
+
+    assert_eq!(m.flag_count("verbose"), 0);
+}
+
+#[test]
+fn flag_count_at_u8_max() {
+    let flags = format!("-{}", iter::repeat('v').take(255).collect::<String>());
+    let m = App::new("multiple_occurrences")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .get_matches_from(vec!["", &*flags]);
+
+    assert_eq!(m.occurrences_of("verbose"), 255);
+    assert_eq!(m.flag_count("verbose"), 255);
+}
+
+#[test]
+fn flag_count_saturates() {
+    let flags = format!("-{}", iter::repeat('v').take(300).collect::<String>());
+    let m = App::new("multiple_occurrences")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .get_matches_from(vec!["", &*flags, "-v"]);
+
+    assert_eq!(m.occurrences_of("verbose"), 301);
+    assert_eq!(m.flag_count("verbose"), 255);
+}
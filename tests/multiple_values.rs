@@ -1,6 +1,8 @@
 extern crate clap;
 
-use clap::{App, Arg, ErrorKind, SubCommand};
+use std::ffi::OsStr;
+
+use clap::{App, AppSettings, Arg, ErrorKind, SubCommand};
 
 #[test]
 fn option_long() {
@@ -113,12 +115,9 @@ fn option_exact_exact() {
             "-o", "val3",
         ]);
 
-    assert!(m.is_ok());
-    let m = m.unwrap();
-
-    assert!(m.is_present("option"));
-    assert_eq!(m.occurrences_of("option"), 3);
-    assert_eq!(m.values_of("option").unwrap().collect::<Vec<_>>(), ["val1", "val2", "val3"]);
+    // The number of values applies to each occurrence, not the total
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::WrongNumberOfValues);
 }
 
 #[test]
@@ -1105,3 +1104,194 @@ fn low_index_positional_with_flag() {
     assert_eq!(m.value_of("target").unwrap(), "target");
     assert!(m.is_present("flg"));
 }
+
+#[test]
+fn number_of_values_exact() {
+    let m = App::new("coords")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("coord")
+            .long("coord")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(2))
+        .arg(Arg::with_name("flag")
+            .short("f"))
+        .get_matches_from_safe(vec!["", "--coord", "1", "2"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.occurrences_of("coord"), 1);
+    assert_eq!(m.values_of("coord").unwrap().collect::<Vec<_>>(), ["1", "2"]);
+}
+
+#[test]
+fn number_of_values_each_occurrence() {
+    let m = App::new("coords")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("coord")
+            .long("coord")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(2))
+        .arg(Arg::with_name("flag")
+            .short("f"))
+        .get_matches_from_safe(vec!["", "--coord", "1", "2", "--coord", "3", "4"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.occurrences_of("coord"), 2);
+    let vals = m.values_of("coord").unwrap().collect::<Vec<_>>();
+    assert_eq!(vals.chunks(2).collect::<Vec<_>>(), [["1", "2"], ["3", "4"]]);
+}
+
+#[test]
+fn number_of_values_too_few() {
+    let m = App::new("coords")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("coord")
+            .long("coord")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(2))
+        .arg(Arg::with_name("flag")
+            .short("f"))
+        .get_matches_from_safe(vec!["", "--coord", "1"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfValues);
+    assert!(err.message.contains("requires 2 values, but 1 was provided"));
+}
+
+#[test]
+fn number_of_values_too_few_first_occurrence() {
+    let m = App::new("coords")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("coord")
+            .long("coord")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(2))
+        .arg(Arg::with_name("flag")
+            .short("f"))
+        .get_matches_from_safe(vec!["", "--coord", "1", "-f", "--coord", "2"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfValues);
+    assert!(err.message.contains("requires 2 values, but 1 was provided"));
+}
+
+#[test]
+fn number_of_values_too_many() {
+    let m = App::new("coords")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("coord")
+            .long("coord")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(2))
+        .arg(Arg::with_name("flag")
+            .short("f"))
+        .get_matches_from_safe(vec!["", "--coord", "1", "2", "3"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn req_delimiter_stops_before_positional() {
+    let m = App::new("lists")
+        .arg(Arg::with_name("list")
+            .long("list")
+            .takes_value(true)
+            .multiple(true)
+            .require_delimiter(true))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["lists", "--list", "a,b,c", "file.txt"]);
+
+    assert_eq!(m.values_of("list").unwrap().collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!(m.value_of("file"), Some("file.txt"));
+}
+
+#[test]
+fn no_req_delimiter_consumes_positional() {
+    let m = App::new("lists")
+        .arg(Arg::with_name("list")
+            .long("list")
+            .takes_value(true)
+            .multiple(true)
+            .require_delimiter(false))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["lists", "--list", "a,b,c", "file.txt"]);
+
+    // without a delimiter the token isn't split, and the following positional is swallowed
+    assert_eq!(m.values_of("list").unwrap().collect::<Vec<_>>(), ["a,b,c", "file.txt"]);
+    assert!(!m.is_present("file"));
+}
+
+#[test]
+fn req_delimiter_space_separated_goes_to_positional() {
+    let res = App::new("lists")
+        .arg(Arg::with_name("list")
+            .long("list")
+            .takes_value(true)
+            .multiple(true)
+            .require_delimiter(true))
+        .arg(Arg::with_name("file"))
+        .get_matches_from_safe(vec!["lists", "--list", "a", "b"]);
+    let m = res.unwrap();
+
+    assert_eq!(m.values_of("list").unwrap().collect::<Vec<_>>(), ["a"]);
+    assert_eq!(m.value_of("file"), Some("b"));
+}
+
+#[test]
+fn req_delimiter_with_number_of_values() {
+    let app = App::new("lists")
+        .arg(Arg::with_name("point")
+            .long("point")
+            .number_of_values(3)
+            .require_delimiter(true))
+        .arg(Arg::with_name("file"));
+
+    let m = app.clone().get_matches_from(vec!["lists", "--point", "1,2,3", "file.txt"]);
+    assert_eq!(m.values_of("point").unwrap().collect::<Vec<_>>(), ["1", "2", "3"]);
+    assert_eq!(m.value_of("file"), Some("file.txt"));
+
+    // space separated values aren't gathered, so there are too few of them
+    let res = app.get_matches_from_safe(vec!["lists", "--point", "1", "2", "3"]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn values_peek() {
+    let m = App::new("peek")
+        .arg(Arg::with_name("vals").multiple(true))
+        .get_matches_from(vec!["", "a", "b"]);
+
+    let mut vals = m.values_of("vals").unwrap();
+    assert_eq!(vals.peek(), Some("a"));
+    assert_eq!(vals.peek(), Some("a"));
+    assert_eq!(vals.next(), Some("a"));
+    assert_eq!(vals.peek(), Some("b"));
+    assert_eq!(vals.next(), Some("b"));
+    assert_eq!(vals.peek(), None);
+    assert_eq!(vals.next(), None);
+}
+
+#[test]
+fn os_values_peek() {
+    let m = App::new("peek")
+        .arg(Arg::with_name("vals").multiple(true))
+        .get_matches_from(vec!["", "a", "b"]);
+
+    let mut vals = m.values_of_os("vals").unwrap();
+    assert_eq!(vals.peek(), Some(OsStr::new("a")));
+    assert_eq!(vals.peek(), Some(OsStr::new("a")));
+    assert_eq!(vals.next(), Some(OsStr::new("a")));
+    assert_eq!(vals.peek(), Some(OsStr::new("b")));
+    assert_eq!(vals.next(), Some(OsStr::new("b")));
+    assert_eq!(vals.peek(), None);
+    assert_eq!(vals.next(), None);
+}
@@ -3,7 +3,12 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, ErrorKind};
+use std::cell::Cell;
+use std::ffi::OsString;
+use std::path::MAIN_SEPARATOR;
+use std::rc::Rc;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
 
 #[test]
 fn stdin_char() {
@@ -172,6 +177,90 @@ fn default_values_user_value() {
     assert_eq!(m.value_of("o").unwrap(), "value");
 }
 
+#[test]
+fn default_values_invalid_validator() {
+    let r = App::new("df")
+        .arg( Arg::from_usage("-p [port] 'some port'")
+            .default_value("eighty")
+            .validator(|v| v.parse::<u16>().map(|_| ()).map_err(|e| e.to_string())))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_err());
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert!(err.message.contains("from default value"));
+}
+
+#[test]
+fn default_values_invalid_possible_value() {
+    let r = App::new("df")
+        .arg( Arg::from_usage("-c [color] 'some color'")
+            .possible_values(&["red", "green"])
+            .default_value("blue"))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_err());
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+    assert!(err.message.contains("from default value"));
+}
+
+#[test]
+fn default_values_invalid_not_used() {
+    let r = App::new("df")
+        .arg( Arg::from_usage("-c [color] 'some color'")
+            .possible_values(&["red", "green"])
+            .default_value("blue"))
+        .get_matches_from_safe(vec!["", "-c", "red"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("c").unwrap(), "red");
+}
+
+#[test]
+fn default_value_fn_omitted() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let r = App::new("df")
+        .arg(Arg::from_usage("-d [dir] 'some dir'")
+            .default_value_fn(move || {
+                counter.set(counter.get() + 1);
+                OsString::from("computed")
+            }))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("d"), Some("computed"));
+    assert_eq!(m.occurrences_of("d"), 0);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn default_value_fn_not_called_when_present() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let r = App::new("df")
+        .arg(Arg::from_usage("-d [dir] 'some dir'")
+            .default_value_fn(move || {
+                counter.set(counter.get() + 1);
+                OsString::from("computed")
+            }))
+        .get_matches_from_safe(vec!["", "-d", "given"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("d"), Some("given"));
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn default_value_fn_invalid() {
+    let r = App::new("df")
+        .arg( Arg::from_usage("-c [color] 'some color'")
+            .possible_values(&["red", "green"])
+            .default_value_fn(|| OsString::from("blue")))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_err());
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+    assert!(err.message.contains("from default value"));
+}
+
 #[test]
 fn multiple_vals_pos_arg_equals() {
     let r = App::new("mvae")
@@ -237,3 +326,463 @@ USAGE:
 
 For more information try --help", true);
 }
+
+#[test]
+fn opt_value_forms() {
+    let forms = vec![vec!["", "--define=foo"],
+                     vec!["", "--define", "foo"],
+                     vec!["", "-Dfoo"],
+                     vec!["", "-D=foo"],
+                     vec!["", "-D", "foo"]];
+    for args in forms {
+        let r = App::new("opts")
+            .arg(Arg::from_usage("-D, --define [def] 'some definition'"))
+            .arg(Arg::from_usage("[file] 'some file'"))
+            .get_matches_from_safe(args.clone());
+        assert!(r.is_ok(), "{:?}", args);
+        let m = r.unwrap();
+        assert_eq!(m.value_of("define"), Some("foo"), "{:?}", args);
+        assert!(!m.is_present("file"), "{:?}", args);
+    }
+}
+
+#[test]
+fn opt_value_with_embedded_eq() {
+    let forms = vec![vec!["", "--define=KEY=VALUE"],
+                     vec!["", "--define", "KEY=VALUE"],
+                     vec!["", "-DKEY=VALUE"],
+                     vec!["", "-D=KEY=VALUE"],
+                     vec!["", "-D", "KEY=VALUE"]];
+    for args in forms {
+        let r = App::new("opts")
+            .arg(Arg::from_usage("-D, --define [def] 'some definition'"))
+            .arg(Arg::from_usage("[file] 'some file'"))
+            .get_matches_from_safe(args.clone());
+        assert!(r.is_ok(), "{:?}", args);
+        assert_eq!(r.unwrap().value_of("define"), Some("KEY=VALUE"), "{:?}", args);
+    }
+}
+
+#[test]
+fn opt_value_leading_eq() {
+    let app = App::new("opts")
+        .arg(Arg::from_usage("-D, --define [def] 'some definition'"))
+        .arg(Arg::from_usage("[file] 'some file'"));
+    let r = app.clone().get_matches_from_safe(vec!["", "--define==foo"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("define"), Some("=foo"));
+
+    let r = app.get_matches_from_safe(vec!["", "-D==foo"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("define"), Some("=foo"));
+}
+
+#[test]
+fn opt_long_eq_stops_multiple_values() {
+    let r = App::new("opts")
+        .arg(Arg::from_usage("-o, --option [opt]... 'some opt'"))
+        .arg(Arg::from_usage("[file] 'some file'"))
+        .get_matches_from_safe(vec!["", "--option=val", "some"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.values_of("option").unwrap().collect::<Vec<_>>(), &["val"]);
+    assert_eq!(m.value_of("file"), Some("some"));
+}
+
+#[test]
+fn normalize_path_separators_unix_style() {
+    let r = App::new("opts")
+        .arg(Arg::from_usage("-p, --path [path]... 'some path'")
+            .use_delimiter(true)
+            .normalize_path_separators(true))
+        .get_matches_from_safe(vec!["", "--path", "a/b/c"]);
+    assert!(r.is_ok());
+    let expected = format!("a{0}b{0}c", MAIN_SEPARATOR);
+    assert_eq!(r.unwrap().value_of("path"), Some(&*expected));
+}
+
+#[test]
+fn normalize_path_separators_windows_style() {
+    let r = App::new("opts")
+        .arg(Arg::from_usage("-p, --path [path]... 'some path'")
+            .use_delimiter(true)
+            .normalize_path_separators(true))
+        .get_matches_from_safe(vec!["", "-p", "C:\\a\\b"]);
+    assert!(r.is_ok());
+    let expected = format!("C:{0}a{0}b", MAIN_SEPARATOR);
+    assert_eq!(r.unwrap().value_of("path"), Some(&*expected));
+}
+
+#[test]
+fn normalize_path_separators_mixed_multiple() {
+    let r = App::new("opts")
+        .arg(Arg::from_usage("-p, --path [path]... 'some path'")
+            .use_delimiter(true)
+            .normalize_path_separators(true))
+        .get_matches_from_safe(vec!["", "-p", "a/b\\c,d\\e"]);
+    assert!(r.is_ok());
+    let first = format!("a{0}b{0}c", MAIN_SEPARATOR);
+    let second = format!("d{0}e", MAIN_SEPARATOR);
+    assert_eq!(r.unwrap().values_of("path").unwrap().collect::<Vec<_>>(),
+               &[&*first, &*second]);
+}
+
+#[test]
+fn normalize_path_separators_default_value() {
+    let r = App::new("opts")
+        .arg(Arg::from_usage("-p, --path [path] 'some path'")
+            .default_value("a/b")
+            .normalize_path_separators(true))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_ok());
+    let expected = format!("a{}b", MAIN_SEPARATOR);
+    assert_eq!(r.unwrap().value_of("path"), Some(&*expected));
+}
+
+#[test]
+fn normalize_path_separators_off() {
+    let r = App::new("opts")
+        .arg(Arg::from_usage("-p, --path [path]... 'some path'")
+            .use_delimiter(true)
+            .normalize_path_separators(false))
+        .get_matches_from_safe(vec!["", "-p", "a/b\\c"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("path"), Some("a/b\\c"));
+}
+
+#[test]
+#[cfg(feature="suggestions")]
+fn did_you_mean_near_miss() {
+    let err = App::new("test")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true))
+        .arg(Arg::with_name("quiet")
+            .long("silent")
+            .visible_alias("quiet"))
+        .get_matches_from_safe(vec!["test", "--colr", "auto"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert!(err.message.contains("Did you mean --color?"));
+}
+
+#[test]
+#[cfg(feature="suggestions")]
+fn did_you_mean_visible_alias() {
+    let err = App::new("test")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true))
+        .arg(Arg::with_name("quiet")
+            .long("silent")
+            .visible_alias("quiet"))
+        .get_matches_from_safe(vec!["test", "--quiett"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert!(err.message.contains("Did you mean --quiet?"));
+}
+
+#[test]
+fn did_you_mean_wildly_different() {
+    let err = App::new("test")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true))
+        .arg(Arg::with_name("quiet")
+            .long("silent")
+            .visible_alias("quiet"))
+        .get_matches_from_safe(vec!["test", "--zzzzzzzz"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert!(!err.message.contains("Did you mean"));
+}
+
+#[test]
+fn value_names_in_usage_and_help() {
+    let mut buf = Vec::new();
+    App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("addr")
+            .long("addr")
+            .required(true)
+            .value_names(&["HOST", "PORT"])
+            .help("Address to listen on"))
+        .write_help(&mut buf).unwrap();
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.contains("server --addr <HOST> <PORT>"), "{}", help);
+    assert!(help.lines()
+                .any(|l| l.contains("--addr <HOST> <PORT>") && l.ends_with("Address to listen on")),
+            "{}",
+            help);
+}
+
+#[test]
+fn value_names_values() {
+    let m = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("addr")
+            .long("addr")
+            .required(true)
+            .value_names(&["HOST", "PORT"])
+            .help("Address to listen on"))
+        .get_matches_from(vec!["server", "--addr", "localhost", "8080"]);
+
+    assert_eq!(m.values_of("addr").unwrap().collect::<Vec<_>>(), ["localhost", "8080"]);
+}
+
+#[test]
+fn value_names_wrong_count() {
+    let err = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("addr")
+            .long("addr")
+            .required(true)
+            .value_names(&["HOST", "PORT"])
+            .help("Address to listen on"))
+        .get_matches_from_safe(vec!["server", "--addr", "localhost"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfValues);
+    assert!(err.message.contains("--addr <HOST> <PORT>"));
+}
+
+#[test]
+fn allow_hyphen_values_long() {
+    let m = App::new("sed")
+        .arg(Arg::with_name("expr")
+            .long("sed-expr")
+            .short("e")
+            .takes_value(true)
+            .allow_hyphen_values(true))
+        .arg(Arg::with_name("file").long("file").takes_value(true))
+        .arg(Arg::with_name("quiet").short("q"))
+        .get_matches_from(vec!["sed", "--sed-expr", "-n", "-q"]);
+
+    assert_eq!(m.value_of("expr"), Some("-n"));
+    assert!(m.is_present("quiet"));
+}
+
+#[test]
+fn allow_hyphen_values_short_and_long_looking() {
+    let m = App::new("sed")
+        .arg(Arg::with_name("expr")
+            .long("sed-expr")
+            .short("e")
+            .takes_value(true)
+            .allow_hyphen_values(true))
+        .arg(Arg::with_name("file").long("file").takes_value(true))
+        .arg(Arg::with_name("quiet").short("q"))
+        .get_matches_from(vec!["sed", "-e", "--not-an-arg"]);
+
+    assert_eq!(m.value_of("expr"), Some("--not-an-arg"));
+}
+
+#[test]
+fn allow_hyphen_values_only_for_that_arg() {
+    let app = App::new("sed")
+        .arg(Arg::with_name("expr")
+            .long("sed-expr")
+            .short("e")
+            .takes_value(true)
+            .allow_hyphen_values(true))
+        .arg(Arg::with_name("file").long("file").takes_value(true))
+        .arg(Arg::with_name("quiet").short("q"));
+    let res = app.clone().get_matches_from_safe(vec!["sed", "--file", "-n"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+
+    // a stray leading hyphen token which isn't a value is still an error
+    let res = app.get_matches_from_safe(vec!["sed", "-e", "-n", "-x"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn require_equals_with_equals() {
+    let m = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .min_values(0)
+            .require_equals(true))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["prog", "--color=never", "file.txt"]);
+
+    assert_eq!(m.value_of("color"), Some("never"));
+    assert_eq!(m.value_of("file"), Some("file.txt"));
+}
+
+#[test]
+fn require_equals_bare_does_not_consume_next() {
+    let m = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .min_values(0)
+            .require_equals(true))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["prog", "--color", "file.txt"]);
+
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), None);
+    assert_eq!(m.value_of("file"), Some("file.txt"));
+}
+
+#[test]
+fn require_equals_space_separated_fails() {
+    let res = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .require_equals(true))
+        .get_matches_from_safe(vec!["prog", "--color", "never"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NoEquals);
+    assert!(err.message.contains("Equal sign is needed when assigning values to '--color=<WHEN>'"),
+            "{}",
+            err.message);
+}
+
+#[test]
+fn require_equals_short() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("color").short("c").takes_value(true).require_equals(true));
+
+    let m = app.clone().get_matches_from(vec!["prog", "-c=never"]);
+    assert_eq!(m.value_of("color"), Some("never"));
+
+    let res = app.get_matches_from_safe(vec!["prog", "-cnever"]);
+    assert_eq!(res.unwrap_err().kind, ErrorKind::NoEquals);
+}
+
+#[test]
+fn default_missing_value_absent() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .min_values(0)
+            .require_equals(true)
+            .possible_values(&["auto", "always", "never"])
+            .default_missing_value("auto"))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["prog", "file.txt"]);
+
+    assert!(!m.is_present("color"));
+    assert_eq!(m.value_of("color"), None);
+}
+
+#[test]
+fn default_missing_value_bare() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .min_values(0)
+            .require_equals(true)
+            .possible_values(&["auto", "always", "never"])
+            .default_missing_value("auto"))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["prog", "--color", "file.txt"]);
+
+    assert!(m.is_present("color"));
+    assert_eq!(m.occurrences_of("color"), 1);
+    assert_eq!(m.value_of("color"), Some("auto"));
+    assert_eq!(m.value_of("file"), Some("file.txt"));
+}
+
+#[test]
+fn default_missing_value_explicit() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .min_values(0)
+            .require_equals(true)
+            .possible_values(&["auto", "always", "never"])
+            .default_missing_value("auto"))
+        .arg(Arg::with_name("file"))
+        .get_matches_from(vec!["prog", "--color=never"]);
+
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), Some("never"));
+}
+
+#[test]
+fn default_missing_value_with_default_value() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .min_values(0)
+            .require_equals(true)
+            .default_value("never")
+            .default_missing_value("auto"));
+
+    let m = app.clone().get_matches_from(vec!["prog"]);
+    assert_eq!(m.value_of("color"), Some("never"));
+
+    let m = app.get_matches_from(vec!["prog", "--color"]);
+    assert_eq!(m.value_of("color"), Some("auto"));
+}
+
+#[test]
+fn value_range_in_range() {
+    for port in &["1", "8080", "65535"] {
+        let m = App::new("server")
+            .setting(AppSettings::ColorNever)
+            .arg(Arg::from_usage("-p, --port [port] 'the port to listen on'").value_range(1, 65535))
+            .get_matches_from(vec!["server", "--port", port]);
+        assert_eq!(m.value_of("port"), Some(*port));
+    }
+}
+
+#[test]
+fn value_range_below_range() {
+    let err = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::from_usage("-p, --port [port] 'the port to listen on'").value_range(1, 65535))
+        .get_matches_from_safe(vec!["server", "--port", "0"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.message, "error: 0 is not in range 1..=65535");
+}
+
+#[test]
+fn value_range_above_range() {
+    let err = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::from_usage("-p, --port [port] 'the port to listen on'").value_range(1, 65535))
+        .get_matches_from_safe(vec!["server", "-p", "65536"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.message, "error: 65536 is not in range 1..=65535");
+}
+
+#[test]
+fn value_range_not_a_number() {
+    let err = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::from_usage("-p, --port [port] 'the port to listen on'").value_range(1, 65535))
+        .get_matches_from_safe(vec!["server", "--port", "http"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.message, "error: 'http' is not a number");
+}
+
+#[test]
+fn value_range_negative() {
+    let app = App::new("offset")
+        .arg(Arg::from_usage("[offset] 'the offset'").value_range(-10, 10))
+        .setting(AppSettings::AllowNegativeNumbers);
+
+    let m = app.clone().get_matches_from(vec!["offset", "-10"]);
+    assert_eq!(m.value_of("offset"), Some("-10"));
+    assert!(app.get_matches_from_safe(vec!["offset", "-11"]).is_err());
+}
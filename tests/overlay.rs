@@ -0,0 +1,128 @@
+extern crate clap;
+
+use clap::{App, Arg, ArgGroup, SubCommand, ValueSource};
+
+#[test]
+fn overlay_fills_missing_args() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("host").long("host").takes_value(true))
+        .arg(Arg::with_name("port").long("port").takes_value(true))
+        .arg(Arg::with_name("verbose").short("v").multiple(true));
+    let mut cli = app.clone().get_matches_from(vec!["prog", "--port", "8080"]);
+    let defaults =
+        app.get_matches_from(vec!["prog", "--host", "localhost", "--port", "80", "-vv"]);
+    cli.overlay(&defaults);
+
+    assert_eq!(cli.value_of("host"), Some("localhost"));
+    assert_eq!(cli.value_of("port"), Some("8080"));
+    assert_eq!(cli.occurrences_of("port"), 1);
+    assert_eq!(cli.occurrences_of("verbose"), 2);
+}
+
+#[test]
+fn overlay_explicit_wins() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("include").long("include").takes_value(true).multiple(true));
+    let mut cli =
+        app.clone().get_matches_from(vec!["prog", "-v", "--include", "a", "--include", "b"]);
+    let defaults =
+        app.get_matches_from(vec!["prog", "-vvv", "--include", "b", "--include", "c"]);
+    cli.overlay(&defaults);
+
+    assert_eq!(cli.occurrences_of("verbose"), 1);
+    assert_eq!(cli.occurrences_of("include"), 2);
+    assert_eq!(cli.values_of("include").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+}
+
+#[test]
+fn overlay_layers() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("host").long("host").takes_value(true))
+        .arg(Arg::with_name("port").long("port").takes_value(true))
+        .arg(Arg::with_name("json").long("json"))
+        .arg(Arg::with_name("yaml").long("yaml"))
+        .group(ArgGroup::with_name("format").args(&["json", "yaml"]));
+    let mut cli = app.clone().get_matches_from(vec!["prog", "--port", "8080"]);
+    let config =
+        app.clone().get_matches_from(vec!["prog", "--host", "example.com", "--port", "443"]);
+    let defaults =
+        app.get_matches_from(vec!["prog", "--host", "localhost", "--port", "80", "--json"]);
+    cli.overlay(&config);
+    cli.overlay(&defaults);
+
+    assert_eq!(cli.value_of("host"), Some("example.com"));
+    assert_eq!(cli.value_of("port"), Some("8080"));
+    assert!(cli.is_present("json"));
+    assert_eq!(cli.value_of_group("format"), Some("json"));
+}
+
+#[test]
+fn overlay_group_used_wins() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("json").long("json"))
+        .arg(Arg::with_name("yaml").long("yaml"))
+        .group(ArgGroup::with_name("format").args(&["json", "yaml"]));
+    let mut cli = app.clone().get_matches_from(vec!["prog", "--yaml"]);
+    let defaults = app.get_matches_from(vec!["prog", "--json"]);
+    cli.overlay(&defaults);
+
+    assert!(cli.is_present("yaml"));
+    assert!(!cli.is_present("json"));
+    assert_eq!(cli.value_of_group("format"), Some("yaml"));
+}
+
+#[test]
+fn overlay_subcommand_copied() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("jobs").long("jobs").takes_value(true)));
+    let mut cli = app.clone().get_matches_from(vec!["prog", "-v"]);
+    let defaults = app.get_matches_from(vec!["prog", "build", "--jobs", "4"]);
+    cli.overlay(&defaults);
+
+    assert_eq!(cli.subcommand_name(), Some("build"));
+    assert_eq!(cli.subcommand_matches("build").unwrap().value_of("jobs"), Some("4"));
+}
+
+#[test]
+fn overlay_same_subcommand_merged() {
+    let app = App::new("prog")
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release"))
+            .arg(Arg::with_name("jobs").long("jobs").takes_value(true)));
+    let mut cli = app.clone().get_matches_from(vec!["prog", "build", "--release"]);
+    let defaults = app.get_matches_from(vec!["prog", "build", "--jobs", "4"]);
+    cli.overlay(&defaults);
+
+    let sub_m = cli.subcommand_matches("build").unwrap();
+    assert!(sub_m.is_present("release"));
+    assert_eq!(sub_m.value_of("jobs"), Some("4"));
+}
+
+#[test]
+fn overlay_other_subcommand_kept() {
+    let app = App::new("prog")
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("jobs").long("jobs").takes_value(true)))
+        .subcommand(SubCommand::with_name("test"));
+    let mut cli = app.clone().get_matches_from(vec!["prog", "test"]);
+    let defaults = app.get_matches_from(vec!["prog", "build", "--jobs", "4"]);
+    cli.overlay(&defaults);
+
+    assert_eq!(cli.subcommand_name(), Some("test"));
+    assert!(cli.subcommand_matches("build").is_none());
+}
+
+#[test]
+fn overlay_replaces_default_values() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("port").long("port").takes_value(true).default_value("1"));
+    let mut cli = app.clone().get_matches_from(vec!["prog"]);
+    let config = app.get_matches_from(vec!["prog", "--port", "80"]);
+    cli.overlay(&config);
+
+    assert_eq!(cli.value_of("port"), Some("80"));
+    assert_eq!(cli.value_source("port"), Some(ValueSource::CommandLine));
+}
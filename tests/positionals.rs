@@ -1,6 +1,6 @@
 extern crate clap;
 
-use clap::{App, Arg, ErrorKind};
+use clap::{App, AppSettings, Arg, ErrorKind};
 
 #[test]
 fn only_pos_follow() {
@@ -214,3 +214,201 @@ fn single_positional_required_usage_string() {
         .get_matches_from(vec!["test", "file"]);
     assert_eq!(m.usage(), "USAGE:\n    test <FILE>");
 }
+
+#[test]
+#[should_panic(expected = "Only one positional argument with .multiple(true)")]
+fn two_variadic_positionals() {
+    let _ = App::new("test")
+        .arg_from_usage("[FIRST]... 'some files'")
+        .arg_from_usage("[SECOND]... 'more files'")
+        .get_matches_from_safe(vec!["test", "a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "Only one positional argument with .multiple(true)")]
+fn two_variadic_positionals_apart() {
+    let _ = App::new("test")
+        .arg_from_usage("<FIRST>... 'some files'")
+        .arg_from_usage("<MIDDLE> 'some file'")
+        .arg_from_usage("<LAST>... 'more files'")
+        .get_matches_from_safe(vec!["test", "a", "b", "c"]);
+}
+
+#[test]
+fn variadic_positional_after_fixed() {
+    let r = App::new("test")
+        .arg_from_usage("<SRC> 'some file'")
+        .arg_from_usage("<DEST> 'some file'")
+        .arg_from_usage("[REST]... 'more files'")
+        .get_matches_from_safe(vec!["test", "a", "b", "c", "d"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("SRC"), Some("a"));
+    assert_eq!(m.value_of("DEST"), Some("b"));
+    assert_eq!(m.values_of("REST").unwrap().collect::<Vec<_>>(), &["c", "d"]);
+}
+
+#[test]
+fn variadic_positional_after_fixed_empty() {
+    let r = App::new("test")
+        .arg_from_usage("<SRC> 'some file'")
+        .arg_from_usage("<DEST> 'some file'")
+        .arg_from_usage("[REST]... 'more files'")
+        .get_matches_from_safe(vec!["test", "a", "b"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("DEST"), Some("b"));
+    assert!(!m.is_present("REST"));
+}
+
+#[test]
+fn missing_positional_one_supplied() {
+    let res = App::new("cp")
+        .setting(AppSettings::AllowMissingPositional)
+        .arg(Arg::with_name("first").required(true))
+        .arg(Arg::with_name("middle"))
+        .arg(Arg::with_name("last").required(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .get_matches_from_safe(vec!["cp", "a"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn missing_positional_two_supplied() {
+    let m = App::new("cp")
+        .setting(AppSettings::AllowMissingPositional)
+        .arg(Arg::with_name("first").required(true))
+        .arg(Arg::with_name("middle"))
+        .arg(Arg::with_name("last").required(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .get_matches_from(vec!["cp", "a", "-v", "c"]);
+
+    assert_eq!(m.value_of("first"), Some("a"));
+    assert!(!m.is_present("middle"));
+    assert_eq!(m.value_of("last"), Some("c"));
+}
+
+#[test]
+fn missing_positional_three_supplied() {
+    let m = App::new("cp")
+        .setting(AppSettings::AllowMissingPositional)
+        .arg(Arg::with_name("first").required(true))
+        .arg(Arg::with_name("middle"))
+        .arg(Arg::with_name("last").required(true))
+        .arg(Arg::with_name("verbose").short("v"))
+        .get_matches_from(vec!["cp", "a", "b", "c"]);
+
+    assert_eq!(m.value_of("first"), Some("a"));
+    assert_eq!(m.value_of("middle"), Some("b"));
+    assert_eq!(m.value_of("last"), Some("c"));
+}
+
+#[test]
+fn missing_positional_only_dest() {
+    let m = App::new("cp")
+        .setting(AppSettings::AllowMissingPositional)
+        .arg(Arg::with_name("source"))
+        .arg(Arg::with_name("dest").required(true))
+        .get_matches_from(vec!["cp", "out"]);
+
+    assert!(!m.is_present("source"));
+    assert_eq!(m.value_of("dest"), Some("out"));
+}
+
+#[test]
+fn last_positional_after_dashes() {
+    let m = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("target"))
+        .arg(Arg::with_name("extra").multiple(true).last(true))
+        .get_matches_from(vec!["prog", "--verbose", "tgt", "--", "extra1", "-x", "--y"]);
+
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_of("target"), Some("tgt"));
+    assert_eq!(m.values_of("extra").unwrap().collect::<Vec<_>>(), ["extra1", "-x", "--y"]);
+}
+
+#[test]
+fn last_positional_skips_earlier_positionals() {
+    let m = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("target"))
+        .arg(Arg::with_name("extra").multiple(true).last(true))
+        .get_matches_from(vec!["prog", "--", "extra1", "extra2"]);
+
+    assert!(!m.is_present("target"));
+    assert_eq!(m.values_of("extra").unwrap().collect::<Vec<_>>(), ["extra1", "extra2"]);
+}
+
+#[test]
+fn last_positional_before_dashes() {
+    let res = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("target"))
+        .arg(Arg::with_name("extra").multiple(true).last(true))
+        .get_matches_from_safe(vec!["prog", "tgt", "extra1"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert!(err.message.contains("'extra1'"));
+}
+
+#[test]
+fn last_positional_usage() {
+    let mut buf = Vec::new();
+    App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("target"))
+        .arg(Arg::with_name("extra").multiple(true).last(true))
+        .write_help(&mut buf).unwrap();
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.contains("prog [FLAGS] [target] [-- <extra>...]"));
+}
+
+#[test]
+#[should_panic(expected = "Positional index 2 is not defined, but \"third\" has index 3")]
+fn positional_index_gap() {
+    let _ = App::new("test")
+        .arg(Arg::with_name("first").index(1))
+        .arg(Arg::with_name("third").index(3))
+        .get_matches_from_safe(vec!["test", "a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "Positional index 2 defined twice, by \"second\" and \"other\"")]
+fn positional_index_duplicate() {
+    let _ = App::new("test")
+        .arg(Arg::with_name("first").index(1))
+        .arg(Arg::with_name("second").index(2))
+        .arg(Arg::with_name("other").index(2));
+}
+
+#[test]
+#[should_panic(expected = "Positional index 2 defined twice, by \"explicit\" and \"implicit\"")]
+fn positional_index_explicit_and_implicit_collide() {
+    let _ = App::new("test")
+        .arg(Arg::with_name("explicit").index(2))
+        .arg(Arg::with_name("implicit"));
+}
+
+#[test]
+fn positional_index_auto_assigned_in_order() {
+    let m = App::new("test")
+        .arg(Arg::with_name("first"))
+        .arg(Arg::with_name("flag").short("f"))
+        .arg(Arg::with_name("second"))
+        .arg(Arg::with_name("third").index(3))
+        .get_matches_from(vec!["test", "a", "-f", "b", "c"]);
+
+    assert_eq!(m.value_of("first"), Some("a"));
+    assert_eq!(m.value_of("second"), Some("b"));
+    assert_eq!(m.value_of("third"), Some("c"));
+}
@@ -203,3 +203,49 @@ fn require_overriden_4() {
     let err = result.err().unwrap();
     assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
 }
+
+#[test]
+fn override_last_one_wins() {
+    let app = App::new("posix")
+        .arg(Arg::from_usage("--enable 'enable it'"))
+        .arg(Arg::from_usage("--disable 'disable it'").overrides_with("enable"));
+
+    let m = app.clone().get_matches_from(vec!["", "--enable", "--disable"]);
+    assert!(m.is_present("disable"));
+    assert!(!m.is_present("enable"));
+
+    let m = app.get_matches_from(vec!["", "--disable", "--enable"]);
+    assert!(m.is_present("enable"));
+    assert!(!m.is_present("disable"));
+}
+
+#[test]
+fn option_overrides_itself() {
+    let m = App::new("posix")
+        .arg(Arg::from_usage("--color [color] 'some option'").overrides_with("color"))
+        .get_matches_from(vec!["", "--color=always", "--color", "auto", "--color=never"]);
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), Some("never"));
+    assert_eq!(m.values_of("color").unwrap().collect::<Vec<_>>(), ["never"]);
+    assert_eq!(m.occurrences_of("color"), 1);
+}
+
+#[test]
+fn flag_overrides_itself() {
+    let m = App::new("posix")
+        .arg(Arg::from_usage("-f, --flag 'some flag'").overrides_with("flag"))
+        .get_matches_from(vec!["", "-f", "--flag", "-f"]);
+    assert!(m.is_present("flag"));
+    assert_eq!(m.occurrences_of("flag"), 1);
+}
+
+#[test]
+fn self_and_cross_override() {
+    let m = App::new("posix")
+        .arg(Arg::from_usage("--color [color] 'some option'")
+            .overrides_with_all(&["color", "no-color"]))
+        .arg(Arg::from_usage("--no-color 'disable color'"))
+        .get_matches_from(vec!["", "--no-color", "--color=always", "--color=never"]);
+    assert!(!m.is_present("no-color"));
+    assert_eq!(m.value_of("color"), Some("never"));
+}
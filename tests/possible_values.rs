@@ -161,3 +161,21 @@ fn possible_values_of_option_multiple_fail() {
 fn possible_values_output() {
     test::check_err_output(test::complex_app(), "clap-test -O slo", PV_ERROR, true);
 }
+
+#[test]
+fn possible_values_of_declared() {
+    let m = App::new("possible_values_of")
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .possible_values(&["fast", "slow", "medium"]))
+        .arg(Arg::with_name("shape").possible_value("round").possible_value("square"))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .get_matches_from(vec!["myprog", "--mode", "fast"]);
+
+    assert_eq!(m.possible_values_of("mode"), Some(vec!["fast", "slow", "medium"]));
+    // declared values are available even when the arg wasn't used
+    assert_eq!(m.possible_values_of("shape"), Some(vec!["round", "square"]));
+    assert_eq!(m.possible_values_of("output"), None);
+    assert_eq!(m.possible_values_of("nonexistent"), None);
+}
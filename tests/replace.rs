@@ -0,0 +1,107 @@
+extern crate clap;
+
+use clap::{App, Arg, SubCommand};
+
+#[test]
+fn replace_expands_flags() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("foo").long("foo"))
+        .arg(Arg::with_name("bar").long("bar"))
+        .arg(Arg::with_name("baz").long("baz"))
+        .arg(Arg::with_name("out").long("out").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .replace("--all", &["--foo", "--bar", "--baz"])
+        .get_matches_from(vec!["prog", "--all"]);
+    assert!(m.is_present("foo"));
+    assert!(m.is_present("bar"));
+    assert!(m.is_present("baz"));
+}
+
+#[test]
+fn replace_not_used() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("foo").long("foo"))
+        .arg(Arg::with_name("bar").long("bar"))
+        .arg(Arg::with_name("baz").long("baz"))
+        .arg(Arg::with_name("out").long("out").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .replace("--all", &["--foo", "--bar", "--baz"])
+        .get_matches_from(vec!["prog", "--foo"]);
+    assert!(m.is_present("foo"));
+    assert!(!m.is_present("bar"));
+    assert!(!m.is_present("baz"));
+}
+
+#[test]
+fn replace_with_option_value() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("foo").long("foo"))
+        .arg(Arg::with_name("bar").long("bar"))
+        .arg(Arg::with_name("baz").long("baz"))
+        .arg(Arg::with_name("out").long("out").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .replace("--all", &["--foo", "--bar", "--baz"])
+        .replace("--release", &["--out", "target/release"])
+        .get_matches_from(vec!["prog", "--release", "file"]);
+    assert_eq!(m.value_of("out"), Some("target/release"));
+    assert_eq!(m.value_of("files"), Some("file"));
+}
+
+#[test]
+fn replace_self_referential() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("foo").long("foo"))
+        .arg(Arg::with_name("bar").long("bar"))
+        .arg(Arg::with_name("baz").long("baz"))
+        .arg(Arg::with_name("out").long("out").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .replace("--all", &["--foo", "--bar", "--baz"])
+        .replace("--foo", &["--foo", "--bar"])
+        .get_matches_from(vec!["prog", "--foo"]);
+    assert!(m.is_present("foo"));
+    assert!(m.is_present("bar"));
+    assert!(!m.is_present("baz"));
+}
+
+#[test]
+fn replace_mutually_referential() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("foo").long("foo"))
+        .arg(Arg::with_name("bar").long("bar"))
+        .arg(Arg::with_name("baz").long("baz"))
+        .arg(Arg::with_name("out").long("out").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .replace("--all", &["--foo", "--bar", "--baz"])
+        .replace("--foo", &["--bar"])
+        .replace("--bar", &["--foo"])
+        .get_matches_from(vec!["prog", "--foo"]);
+    assert!(m.is_present("bar"));
+    assert!(!m.is_present("foo"));
+}
+
+#[test]
+fn replace_not_after_trailing() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("foo").long("foo"))
+        .arg(Arg::with_name("bar").long("bar"))
+        .arg(Arg::with_name("baz").long("baz"))
+        .arg(Arg::with_name("out").long("out").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .replace("--all", &["--foo", "--bar", "--baz"])
+        .get_matches_from(vec!["prog", "--", "--all"]);
+    assert!(!m.is_present("foo"));
+    assert_eq!(m.value_of("files"), Some("--all"));
+}
+
+#[test]
+fn replace_in_subcommand() {
+    let m = App::new("prog")
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release"))
+            .arg(Arg::with_name("verbose").long("verbose"))
+            .replace("-R", &["--release", "--verbose"]))
+        .get_matches_from(vec!["prog", "build", "-R"]);
+    let sub_m = m.subcommand_matches("build").unwrap();
+    assert!(sub_m.is_present("release"));
+    assert!(sub_m.is_present("verbose"));
+}
@@ -32,7 +32,7 @@ fn flag_required_2() {
 fn option_required() {
     let result = App::new("option_required")
         .arg(Arg::from_usage("-f [flag] 'some flag'")
-            .requires("color"))
+            .requires("c"))
         .arg(Arg::from_usage("-c [color] 'third flag'"))
         .get_matches_from_safe(vec!["", "-f", "val"]);
     assert!(result.is_err());
@@ -124,6 +124,22 @@ fn group_required_3() {
     assert!(m.is_present("flag"));
 }
 
+#[test]
+fn requires_multiple_missing_single_error() {
+    let result = App::new("requires_all")
+        .arg(Arg::from_usage("-a 'some flag'")
+            .requires_all(&["b", "c"]))
+        .arg(Arg::from_usage("-b 'some other flag'"))
+        .arg(Arg::from_usage("-c [opt] 'some option'"))
+        .get_matches_from_safe(vec!["", "-a"]);
+    assert!(result.is_err());
+    let err = result.err().unwrap();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert_eq!(err.message.matches("were not provided").count(), 1);
+    assert!(err.message.contains("-b"));
+    assert!(err.message.contains("-c <opt>"));
+}
+
 #[test]
 fn arg_require_group() {
     let result = App::new("arg_require_group")
@@ -210,6 +226,67 @@ fn required_unless_err() {
     assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
 }
 
+#[test]
+fn required_unless_err_lists_alternatives() {
+    let res = App::new("unlesstest")
+        .arg(Arg::with_name("cfg")
+            .required_unless_one(&["dbg", "infile"])
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("dbg")
+            .long("debug"))
+        .arg(Arg::with_name("infile")
+            .short("i")
+            .takes_value(true))
+        .get_matches_from_safe(vec![
+            "unlesstest"
+        ]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert!(err.message.contains("(unless one of: --debug, -i <infile>)"));
+}
+
+#[test]
+fn required_unless_present_self() {
+    let res = App::new("unlesstest")
+        .arg(Arg::with_name("cfg")
+            .required_unless("dbg")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("dbg")
+            .long("debug"))
+        .get_matches_from_safe(vec![
+            "unlesstest", "--config", "file"
+        ]);
+
+    assert!(res.is_ok());
+    let m = res.unwrap();
+    assert!(!m.is_present("dbg"));
+    assert_eq!(m.value_of("cfg"), Some("file"));
+}
+
+#[test]
+fn required_unless_with_conflicts() {
+    let res = App::new("unlesstest")
+        .arg(Arg::with_name("cfg")
+            .required_unless("dbg")
+            .conflicts_with("quiet")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("dbg")
+            .long("debug"))
+        .arg(Arg::with_name("quiet")
+            .long("quiet"))
+        .get_matches_from_safe(vec![
+            "unlesstest", "--debug"
+        ]);
+
+    assert!(res.is_ok());
+    assert!(!res.unwrap().is_present("cfg"));
+}
+
 // REQUIRED_UNLESS_ALL
 
 #[test]
@@ -358,3 +435,144 @@ USAGE:
 
 For more information try --help", true)
 }
+
+// REQUIREMENTS SATISFIED
+
+#[test]
+fn requirements_satisfied() {
+    let m = App::new("reqs_satisfied")
+        .arg(Arg::from_usage("-f, --flag 'some flag'")
+            .requires("color"))
+        .arg(Arg::from_usage("-c, --color [color] 'third flag'"))
+        .get_matches_from(vec!["", "-f", "-c", "red"]);
+    assert_eq!(m.requirements_satisfied(), [("flag", "color")]);
+}
+
+#[test]
+fn requirements_satisfied_group() {
+    let m = App::new("reqs_satisfied")
+        .arg(Arg::from_usage("-f, --flag 'some flag'")
+            .requires("gr"))
+        .group(ArgGroup::with_name("gr")
+            .arg("some")
+            .arg("other"))
+        .arg(Arg::from_usage("--some 'some arg'"))
+        .arg(Arg::from_usage("--other 'other arg'"))
+        .get_matches_from(vec!["", "-f", "--other"]);
+    assert_eq!(m.requirements_satisfied(), [("flag", "gr")]);
+}
+
+#[test]
+fn requirements_satisfied_none() {
+    let m = App::new("reqs_satisfied")
+        .arg(Arg::from_usage("-f, --flag 'some flag'")
+            .requires("color"))
+        .arg(Arg::from_usage("-c, --color [color] 'third flag'"))
+        .get_matches_from(vec!["", "-c", "red"]);
+    assert!(m.requirements_satisfied().is_empty());
+}
+
+// REQUIRES_IF
+
+#[test]
+fn requires_if_present_val() {
+    let res = App::new("requires_if")
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .requires_if("ssl", "cert"))
+        .arg(Arg::with_name("cert")
+            .long("cert")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["requires_if", "--mode", "ssl"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert!(err.message.contains("--cert <cert>"));
+    assert!(err.message.contains("ssl"));
+}
+
+#[test]
+fn requires_if_present_val_satisfied() {
+    let res = App::new("requires_if")
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .requires_if("ssl", "cert"))
+        .arg(Arg::with_name("cert")
+            .long("cert")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["requires_if", "--mode", "ssl", "--cert", "my.pem"]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_if_present_mismatch_val() {
+    let res = App::new("requires_if")
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .requires_if("ssl", "cert"))
+        .arg(Arg::with_name("cert")
+            .long("cert")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["requires_if", "--mode", "plain"]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_if_not_present() {
+    let res = App::new("requires_if")
+        .arg(Arg::with_name("mode")
+            .long("mode")
+            .takes_value(true)
+            .requires_if("ssl", "cert"))
+        .arg(Arg::with_name("cert")
+            .long("cert")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["requires_if"]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_all_companions_present() {
+    let m = App::new("server")
+        .arg(Arg::from_usage("--tls 'enable tls'").requires_all(&["cert", "key", "ca"]))
+        .arg(Arg::from_usage("--cert [cert] 'certificate file'"))
+        .arg(Arg::from_usage("--key [key] 'private key file'"))
+        .arg(Arg::from_usage("--ca [ca] 'certificate authority'"))
+        .get_matches_from(vec!["server", "--tls", "--cert", "c.pem", "--key", "k.pem", "--ca", "ca.pem"]);
+
+    assert!(m.is_present("tls"));
+    assert_eq!(m.value_of("key"), Some("k.pem"));
+}
+
+#[test]
+fn requires_all_lists_only_missing() {
+    let err = App::new("server")
+        .arg(Arg::from_usage("--tls 'enable tls'").requires_all(&["cert", "key", "ca"]))
+        .arg(Arg::from_usage("--cert [cert] 'certificate file'"))
+        .arg(Arg::from_usage("--key [key] 'private key file'"))
+        .arg(Arg::from_usage("--ca [ca] 'certificate authority'"))
+        .get_matches_from_safe(vec!["server", "--cert", "c.pem", "--tls"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    let missing = err.message.split("USAGE:").next().unwrap();
+    assert!(missing.contains("--key <key>"));
+    assert!(missing.contains("--ca <ca>"));
+    assert!(!missing.contains("--cert <cert>"));
+}
+
+#[test]
+fn requires_all_trigger_absent() {
+    let m = App::new("server")
+        .arg(Arg::from_usage("--tls 'enable tls'").requires_all(&["cert", "key", "ca"]))
+        .arg(Arg::from_usage("--cert [cert] 'certificate file'"))
+        .arg(Arg::from_usage("--key [key] 'private key file'"))
+        .arg(Arg::from_usage("--ca [ca] 'certificate authority'"))
+        .get_matches_from(vec!["server", "--cert", "c.pem"]);
+
+    assert!(!m.is_present("tls"));
+    assert_eq!(m.value_of("cert"), Some("c.pem"));
+}
@@ -0,0 +1,130 @@
+extern crate clap;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
+
+// Writes `contents` to a temporary file named after the calling test and returns its path
+fn arg_file(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("clap-response-{}", name));
+    File::create(&path).unwrap().write_all(contents.as_bytes()).unwrap();
+    path
+}
+
+fn at(path: &PathBuf) -> String {
+    format!("@{}", path.display())
+}
+
+#[test]
+fn response_file_tokens_parsed() {
+    let path = arg_file("basic", "-v --output out.o\n  a.c\tb.c\n\nc.c\n");
+    let m = App::new("prog")
+        .setting(AppSettings::ResponseFiles)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from(vec!["prog".to_owned(), at(&path), "d.c".to_owned()]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_of("output"), Some("out.o"));
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(),
+               ["a.c", "b.c", "c.c", "d.c"]);
+}
+
+#[test]
+fn response_file_comments_skipped() {
+    let path = arg_file("comments", "# build flags\n-v\n  # --output ignored.o\na.c\n");
+    let m = App::new("prog")
+        .setting(AppSettings::ResponseFiles)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from(vec!["prog".to_owned(), at(&path)]);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(m.occurrences_of("verbose"), 1);
+    assert!(!m.is_present("output"));
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), ["a.c"]);
+}
+
+#[test]
+fn response_file_nested() {
+    let inner = arg_file("inner", "-v b.c");
+    let outer = arg_file("outer", &format!("a.c {} -v", at(&inner)));
+    let m = App::new("prog")
+        .setting(AppSettings::ResponseFiles)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from(vec!["prog".to_owned(), at(&outer)]);
+    fs::remove_file(&inner).unwrap();
+    fs::remove_file(&outer).unwrap();
+
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), ["a.c", "b.c"]);
+}
+
+#[test]
+fn response_file_recursive_errors() {
+    let path = env::temp_dir().join("clap-response-recursive");
+    let contents = at(&path);
+    File::create(&path).unwrap().write_all(contents.as_bytes()).unwrap();
+    let res = App::new("prog")
+        .setting(AppSettings::ResponseFiles)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from_safe(vec!["prog".to_owned(), at(&path)]);
+    fs::remove_file(&path).unwrap();
+
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Io);
+    assert!(err.message.contains("nested more than 10 levels deep"));
+}
+
+#[test]
+fn response_file_missing() {
+    let path = env::temp_dir().join("clap-response-missing");
+    let err = App::new("prog")
+        .setting(AppSettings::ResponseFiles)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from_safe(vec!["prog".to_owned(), at(&path)]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::Io);
+    assert_eq!(err.info, Some(vec![path.display().to_string()]));
+    assert!(err.message.starts_with("error: Failed to read arguments from file"));
+}
+
+#[test]
+fn response_file_not_after_trailing() {
+    let path = env::temp_dir().join("clap-response-unused");
+    let m = App::new("prog")
+        .setting(AppSettings::ResponseFiles)
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("verbose").short("v").multiple(true))
+        .arg(Arg::with_name("output").long("output").takes_value(true))
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from(vec!["prog".to_owned(), "--".to_owned(), at(&path)]);
+
+    assert_eq!(m.value_of("files"), Some(&*at(&path)));
+}
+
+#[test]
+fn response_file_setting_off() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from(vec!["prog", "@args.txt", "@"]);
+
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), ["@args.txt", "@"]);
+}
@@ -3,7 +3,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, SubCommand, ErrorKind};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand, ErrorKind};
 
 static VISIBLE_ALIAS_HELP: &'static str = "clap-test 2.6
 
@@ -152,3 +152,298 @@ fn invisible_aliases_help_output() {
             .alias("invisible"));
     test::check_help(app, INVISIBLE_ALIAS_HELP);
 }
+
+fn dispatch_add(m: &ArgMatches) -> String {
+    format!("added {}", m.value_of("item").unwrap())
+}
+
+fn dispatch_remove(m: &ArgMatches) -> String {
+    format!("removed {}", m.value_of("item").unwrap())
+}
+
+#[test]
+fn dispatch_table() {
+    let table: &[(&str, fn(&ArgMatches) -> String)] = &[("add", dispatch_add),
+                                                        ("remove", dispatch_remove)];
+    let app = App::new("clap-test")
+        .subcommand(SubCommand::with_name("add")
+            .arg(Arg::with_name("item")))
+        .subcommand(SubCommand::with_name("remove")
+            .arg(Arg::with_name("item")));
+    let m = app.clone().get_matches_from(vec!["clap-test", "remove", "foo"]);
+    assert_eq!(m.dispatch(table), Some("removed foo".to_owned()));
+    let m = app.get_matches_from(vec!["clap-test", "add", "bar"]);
+    assert_eq!(m.dispatch(table), Some("added bar".to_owned()));
+}
+
+#[test]
+fn dispatch_table_no_match() {
+    let table: &[(&str, fn(&ArgMatches) -> String)] = &[("add", dispatch_add)];
+    let app = App::new("clap-test")
+        .subcommand(SubCommand::with_name("add")
+            .arg(Arg::with_name("item")))
+        .subcommand(SubCommand::with_name("remove")
+            .arg(Arg::with_name("item")));
+    let m = app.clone().get_matches_from(vec!["clap-test", "remove", "foo"]);
+    assert!(m.dispatch(table).is_none());
+    let m = app.get_matches_from(vec!["clap-test"]);
+    assert!(m.dispatch(table).is_none());
+}
+
+#[test]
+fn subcommand_by_each_alias() {
+    for name in &["checkout", "co", "switch"] {
+        let m = App::new("git")
+            .subcommand(SubCommand::with_name("checkout")
+                .alias("co")
+                .visible_alias("switch")
+                .arg(Arg::with_name("branch")))
+            .get_matches_from_safe(vec!["git", name, "master"]).unwrap();
+
+        assert_eq!(m.subcommand_name(), Some("checkout"));
+        let sub_m = m.subcommand_matches("checkout").unwrap();
+        assert_eq!(sub_m.value_of("branch"), Some("master"));
+    }
+}
+
+#[test]
+fn subcommand_alias_visibility_in_help() {
+    let mut buf = vec![];
+    App::new("git")
+        .subcommand(SubCommand::with_name("checkout")
+            .alias("co")
+            .visible_alias("switch")
+            .arg(Arg::with_name("branch")))
+        .write_help(&mut buf).expect("failed to write help");
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.contains("[aliases: switch]"));
+    assert!(!help.contains("co]") && !help.contains("co,"));
+}
+
+#[test]
+#[cfg(feature="suggestions")]
+fn did_you_mean_subcommand_visible_alias() {
+    let err = App::new("git")
+        .setting(AppSettings::ColorNever)
+        .subcommand(SubCommand::with_name("checkout").visible_alias("switch"))
+        .get_matches_from_safe(vec!["git", "swich"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::InvalidSubcommand);
+    assert!(err.message.contains("Did you mean 'switch'?"));
+}
+
+#[test]
+fn did_you_mean_subcommand_wildly_different() {
+    let err = App::new("git")
+        .setting(AppSettings::ColorNever)
+        .subcommand(SubCommand::with_name("checkout"))
+        .get_matches_from_safe(vec!["git", "zzzzzzzz"])
+        .unwrap_err();
+
+    assert!(!err.message.contains("Did you mean"));
+}
+
+#[test]
+fn infer_subcommand_unambiguous_prefix() {
+    let app = App::new("git")
+        .setting(AppSettings::InferSubcommands)
+        .setting(AppSettings::ColorNever)
+        .subcommand(SubCommand::with_name("commit"))
+        .subcommand(SubCommand::with_name("config"))
+        .subcommand(SubCommand::with_name("co").alias("checkout"))
+        .subcommand(SubCommand::with_name("status").arg(Arg::with_name("short").short("s")));
+    let m = app.clone().get_matches_from(vec!["git", "sta", "-s"]);
+
+    assert_eq!(m.subcommand_name(), Some("status"));
+    assert!(m.subcommand_matches("status").unwrap().is_present("short"));
+
+    let m = app.get_matches_from(vec!["git", "com"]);
+    assert_eq!(m.subcommand_name(), Some("commit"));
+}
+
+#[test]
+fn infer_subcommand_ambiguous_prefix() {
+    let err = App::new("git")
+        .setting(AppSettings::InferSubcommands)
+        .setting(AppSettings::ColorNever)
+        .subcommand(SubCommand::with_name("commit"))
+        .subcommand(SubCommand::with_name("config"))
+        .subcommand(SubCommand::with_name("co").alias("checkout"))
+        .subcommand(SubCommand::with_name("status").arg(Arg::with_name("short").short("s")))
+        .get_matches_from_safe(vec!["git", "c"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::InvalidSubcommand);
+    assert!(err.message.contains("The subcommand 'c' is ambiguous"));
+    assert!(err.message.contains("'commit', 'config', 'co'"));
+}
+
+#[test]
+fn infer_subcommand_exact_match_wins() {
+    let m = App::new("git")
+        .setting(AppSettings::InferSubcommands)
+        .setting(AppSettings::ColorNever)
+        .subcommand(SubCommand::with_name("commit"))
+        .subcommand(SubCommand::with_name("config"))
+        .subcommand(SubCommand::with_name("co").alias("checkout"))
+        .subcommand(SubCommand::with_name("status").arg(Arg::with_name("short").short("s")))
+        .get_matches_from(vec!["git", "co"]);
+
+    assert_eq!(m.subcommand_name(), Some("co"));
+}
+
+#[test]
+fn infer_subcommand_by_alias_prefix() {
+    let m = App::new("git")
+        .setting(AppSettings::InferSubcommands)
+        .setting(AppSettings::ColorNever)
+        .subcommand(SubCommand::with_name("commit"))
+        .subcommand(SubCommand::with_name("config"))
+        .subcommand(SubCommand::with_name("co").alias("checkout"))
+        .subcommand(SubCommand::with_name("status").arg(Arg::with_name("short").short("s")))
+        .get_matches_from(vec!["git", "checko"]);
+
+    assert_eq!(m.subcommand_name(), Some("co"));
+}
+
+#[test]
+fn infer_subcommand_not_set() {
+    let res = App::new("git")
+        .subcommand(SubCommand::with_name("commit"))
+        .get_matches_from_safe(vec!["git", "com"]);
+
+    assert!(res.is_err());
+}
+
+fn plugin_subcommands() -> Vec<App<'static, 'static>> {
+    ["fetch", "build", "deploy"]
+        .iter()
+        .map(|name| {
+            SubCommand::with_name(name)
+                .about("a generated subcommand")
+                .arg(Arg::with_name("target").short("t").takes_value(true))
+        })
+        .collect()
+}
+
+#[test]
+fn subcommands_bulk_from_vec() {
+    let app = App::new("plugins").subcommands(plugin_subcommands());
+
+    for name in &["fetch", "build", "deploy"] {
+        let m = app.clone().get_matches_from(vec!["plugins", name, "-t", "prod"]);
+
+        assert_eq!(m.subcommand_name(), Some(*name));
+        assert_eq!(m.subcommand_matches(name).unwrap().value_of("target"), Some("prod"));
+    }
+}
+
+#[test]
+fn subcommands_bulk_same_as_chained() {
+    let bulk = App::new("plugins").subcommands(plugin_subcommands());
+    let chained = plugin_subcommands()
+        .into_iter()
+        .fold(App::new("plugins"), |app, sc| app.subcommand(sc));
+
+    let mut bulk_help = vec![];
+    bulk.write_help(&mut bulk_help).unwrap();
+    let mut chained_help = vec![];
+    chained.write_help(&mut chained_help).unwrap();
+    assert_eq!(String::from_utf8(bulk_help).unwrap(),
+               String::from_utf8(chained_help).unwrap());
+}
+
+#[test]
+fn find_subcommand_by_name_and_alias() {
+    let app = App::new("plugins")
+        .subcommands(plugin_subcommands())
+        .subcommand(SubCommand::with_name("install")
+            .visible_alias("add")
+            .alias("get")
+            .subcommand(SubCommand::with_name("nested")));
+
+    assert_eq!(app.find_subcommand("build").unwrap().get_name(), "build");
+    assert_eq!(app.find_subcommand("install").unwrap().get_name(), "install");
+    assert_eq!(app.find_subcommand("add").unwrap().get_name(), "install");
+    assert_eq!(app.find_subcommand("get").unwrap().get_name(), "install");
+    assert!(app.find_subcommand("remove").is_none());
+    // only direct children are searched
+    assert!(app.find_subcommand("nested").is_none());
+    assert_eq!(app.find_subcommand("install")
+                   .and_then(|sc| sc.find_subcommand("nested"))
+                   .map(|sc| sc.get_name()),
+               Some("nested"));
+}
+
+#[test]
+fn subcommand_names_in_order() {
+    let app = App::new("plugins")
+        .subcommand(SubCommand::with_name("zeta"))
+        .subcommands(plugin_subcommands())
+        .subcommand(SubCommand::with_name("alpha").visible_alias("a"));
+
+    assert_eq!(app.subcommand_names().collect::<Vec<_>>(),
+               ["zeta", "fetch", "build", "deploy", "alpha"]);
+    assert_eq!(app.get_subcommands().map(|sc| sc.get_name()).collect::<Vec<_>>(),
+               ["zeta", "fetch", "build", "deploy", "alpha"]);
+}
+
+#[test]
+fn get_subcommands_walks_tree() {
+    fn walk(app: &App, prefix: &str, out: &mut Vec<String>) {
+        for sc in app.get_subcommands() {
+            let path = format!("{}{}", prefix, sc.get_name());
+            out.push(path.clone());
+            walk(sc, &*format!("{} ", path), out);
+        }
+    }
+
+    let app = App::new("git")
+        .subcommand(SubCommand::with_name("remote")
+            .subcommand(SubCommand::with_name("add"))
+            .subcommand(SubCommand::with_name("remove")))
+        .subcommand(SubCommand::with_name("status"));
+    let mut paths = vec![];
+    walk(&app, "", &mut paths);
+
+    assert_eq!(paths, ["remote", "remote add", "remote remove", "status"]);
+    assert_eq!(App::new("empty").subcommand_names().count(), 0);
+}
+
+#[test]
+fn subcommand_depth_root_only() {
+    let m = App::new("deep")
+        .subcommand(SubCommand::with_name("one")
+            .subcommand(SubCommand::with_name("two")
+                .subcommand(SubCommand::with_name("three"))))
+        .get_matches_from(vec!["deep"]);
+
+    assert_eq!(m.subcommand_depth(), 0);
+}
+
+#[test]
+fn subcommand_depth_one_level() {
+    let m = App::new("deep")
+        .subcommand(SubCommand::with_name("one")
+            .subcommand(SubCommand::with_name("two")
+                .subcommand(SubCommand::with_name("three"))))
+        .get_matches_from(vec!["deep", "one"]);
+
+    assert_eq!(m.subcommand_depth(), 1);
+    assert_eq!(m.subcommand_matches("one").unwrap().subcommand_depth(), 0);
+}
+
+#[test]
+fn subcommand_depth_three_levels() {
+    let m = App::new("deep")
+        .subcommand(SubCommand::with_name("one")
+            .subcommand(SubCommand::with_name("two")
+                .subcommand(SubCommand::with_name("three"))))
+        .get_matches_from(vec!["deep", "one", "two", "three"]);
+
+    assert_eq!(m.subcommand_depth(), 3);
+    let one = m.subcommand_matches("one").unwrap();
+    assert_eq!(one.subcommand_depth(), 2);
+    assert_eq!(one.subcommand_matches("two").unwrap().subcommand_depth(), 1);
+}
@@ -15,12 +15,15 @@ USAGE:
     MyApp [FLAGS] [OPTIONS] <output> [SUBCOMMAND]
 
 FLAGS:
-    -d        Turn debugging information on
+    -d               Turn debugging information on
+    -h, --help       Prints help information
+    -V, --version    Prints version information
 OPTIONS:
     -c, --config <FILE>    Sets a custom config file
 ARGS:
     <output>    Sets an optional output file
 SUBCOMMANDS:
+    help    Prints this message or the help of the given subcommand(s)
     test    does testing things";
 
 static SIMPLE_TEMPLATE: &'static str = "MyApp 1.0
@@ -31,7 +34,9 @@ USAGE:
     MyApp [FLAGS] [OPTIONS] <output> [SUBCOMMAND]
 
 FLAGS:
-    -d        Turn debugging information on
+    -d               Turn debugging information on
+    -h, --help       Prints help information
+    -V, --version    Prints version information
 
 OPTIONS:
     -c, --config <FILE>    Sets a custom config file
@@ -40,6 +45,7 @@ ARGS:
     <output>    Sets an optional output file
 
 SUBCOMMANDS:
+    help    Prints this message or the help of the given subcommand(s)
     test    does testing things";
 
 fn build_new_help(app: &App) -> String {
@@ -115,6 +121,43 @@ fn template_author_version() {
     assert!(compare_app_str(&app, "Kevin K. <kbknapp@gmail.com>\n1.0\nDoes awesome things\nMyApp"));
 }
 
+#[test]
+fn help_template_section_order() {
+    let app = app_example1()
+        .after_help("See the manual for more.")
+        .help_template("{bin} {version}\n{about}\n\nSUBCOMMANDS:\n{subcommands}\n\n\
+                        OPTIONS:\n{options}\n\nUSAGE:\n    {usage}\n\n{after-help}");
+    let help = build_new_help(&app);
+
+    let order = ["MyApp 1.0",
+                 "Does awesome things",
+                 "SUBCOMMANDS:",
+                 "test    does testing things",
+                 "OPTIONS:",
+                 "-c, --config <FILE>    Sets a custom config file",
+                 "USAGE:",
+                 "MyApp [FLAGS] [OPTIONS] <output> [SUBCOMMAND]",
+                 "See the manual for more."];
+    let positions = order.iter()
+        .map(|s| help.find(s).expect(s))
+        .collect::<Vec<_>>();
+    for w in positions.windows(2) {
+        assert!(w[0] < w[1], "sections out of order:\n{}", help);
+    }
+    // sections which weren't asked for are left out
+    assert!(!help.contains("FLAGS:"));
+    assert!(!help.contains("Kevin K."));
+}
+
+#[test]
+fn help_template_same_as_template() {
+    let tmpl = "{bin} - {usage}\n{unknown}";
+    let help = build_new_help(&app_example1().help_template(tmpl));
+
+    assert_eq!(help, build_new_help(&app_example1().template(tmpl)));
+    assert_eq!(help, "MyApp - MyApp [FLAGS] [OPTIONS] <output> [SUBCOMMAND]\n{unknown}");
+}
+
 fn app_example1<'b, 'c>() -> App<'b, 'c> {
     App::new("MyApp")
         .version("1.0")
@@ -4,7 +4,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg, ErrorKind, SubCommand};
 
 static SCF2OP: &'static str = "flag NOT present
 option NOT present
@@ -328,3 +328,243 @@ fn sc_short_flag_x2_short_opt_eq_pos() {
 fn sc_short_flag_x2_long_opt_eq_pos() {
     test::check_complex_output("clap-test subcmd value -f -f --option=some", SCF2OP);
 }
+
+#[test]
+fn args_from_usage_block() {
+    let m = App::new("usage_block")
+        .args_from_usage("
+            -d, --debug... 'Turns on debugging'
+
+                -c, --config=[FILE] 'Sets a config file'
+            --level <LEVEL> 'Sets the level'
+            <input> 'The input file'
+            [extra]... 'Extra files'
+        ")
+        .get_matches_from_safe(vec!["usage_block", "-dd", "--debug", "-c", "my.toml", "--level",
+                                    "3", "in.txt", "a", "b"])
+        .unwrap();
+
+    assert_eq!(m.occurrences_of("debug"), 3);
+    assert_eq!(m.value_of("config"), Some("my.toml"));
+    assert_eq!(m.value_of("level"), Some("3"));
+    assert_eq!(m.value_of("input"), Some("in.txt"));
+    assert_eq!(m.values_of("extra").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+}
+
+#[test]
+fn args_from_usage_block_optional() {
+    let m = App::new("usage_block")
+        .args_from_usage("
+            -d, --debug... 'Turns on debugging'
+
+                -c, --config=[FILE] 'Sets a config file'
+            --level <LEVEL> 'Sets the level'
+            <input> 'The input file'
+            [extra]... 'Extra files'
+        ")
+        .get_matches_from_safe(vec!["usage_block", "--level", "1", "in.txt"])
+        .unwrap();
+
+    assert!(!m.is_present("debug"));
+    assert!(!m.is_present("config"));
+    assert!(!m.is_present("extra"));
+}
+
+#[test]
+fn args_from_usage_block_required() {
+    let res = App::new("usage_block")
+        .args_from_usage("
+            -d, --debug... 'Turns on debugging'
+
+                -c, --config=[FILE] 'Sets a config file'
+            --level <LEVEL> 'Sets the level'
+            <input> 'The input file'
+            [extra]... 'Extra files'
+        ")
+        .get_matches_from_safe(vec!["usage_block", "in.txt"]);
+
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn args_from_usage_block_help() {
+    let mut buf = vec![];
+    App::new("usage_block")
+        .args_from_usage("
+            -d, --debug... 'Turns on debugging'
+
+                -c, --config=[FILE] 'Sets a config file'
+            --level <LEVEL> 'Sets the level'
+            <input> 'The input file'
+            [extra]... 'Extra files'
+        ")
+        .write_help(&mut buf).unwrap();
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.contains("-c, --config <FILE>"));
+    assert!(help.contains("Turns on debugging"));
+    assert!(help.contains("<input>"));
+    assert!(help.contains("<extra>..."));
+}
+
+#[test]
+fn from_usage_default_value() {
+    let app = App::new("defaults")
+        .arg_from_usage("--level [LEVEL=info] 'sets the level'")
+        .arg_from_usage("[input=in.txt] 'the input'");
+
+    let m = app.clone().get_matches_from_safe(vec!["defaults"]).unwrap();
+    assert_eq!(m.value_of("level"), Some("info"));
+    assert_eq!(m.value_of("input"), Some("in.txt"));
+    assert_eq!(m.occurrences_of("level"), 0);
+
+    let m = app.get_matches_from_safe(vec!["defaults", "--level", "warn", "other.txt"]).unwrap();
+    assert_eq!(m.value_of("level"), Some("warn"));
+    assert_eq!(m.value_of("input"), Some("other.txt"));
+}
+
+#[test]
+fn get_matches_from_mut_reuses_app() {
+    let mut app = App::new("repl")
+        .setting(AppSettings::NoBinaryName)
+        .arg(Arg::with_name("verbose").short("v").global(true))
+        .arg(Arg::with_name("quiet").short("q").conflicts_with("verbose"))
+        .subcommand(SubCommand::with_name("get")
+            .arg(Arg::with_name("key").required(true)))
+        .subcommand(SubCommand::with_name("list")
+            .arg(Arg::with_name("all").short("a")));
+    let lines = ["-v get name", "list -a", "-q"];
+    let mut results = vec![];
+    for line in &lines {
+        results.push(app.get_matches_from_mut(line.split_whitespace()).unwrap());
+    }
+
+    assert!(results[0].is_present("verbose"));
+    assert_eq!(results[0].subcommand_matches("get").unwrap().value_of("key"), Some("name"));
+
+    assert!(!results[1].is_present("verbose"));
+    assert!(results[1].subcommand_matches("list").unwrap().is_present("all"));
+    assert!(results[1].subcommand_matches("get").is_none());
+
+    assert!(results[2].is_present("quiet"));
+    assert!(!results[2].is_present("verbose"));
+    assert!(results[2].subcommand_name().is_none());
+}
+
+#[test]
+fn get_matches_from_mut_errors_dont_leak() {
+    let mut app = App::new("repl")
+        .setting(AppSettings::NoBinaryName)
+        .arg(Arg::with_name("verbose").short("v").global(true))
+        .arg(Arg::with_name("quiet").short("q").conflicts_with("verbose"))
+        .subcommand(SubCommand::with_name("get")
+            .arg(Arg::with_name("key").required(true)))
+        .subcommand(SubCommand::with_name("list")
+            .arg(Arg::with_name("all").short("a")));
+
+    let err = app.get_matches_from_mut(vec!["get"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    let err = app.get_matches_from_mut(vec!["-v", "-q"]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ArgumentConflict);
+
+    let m = app.get_matches_from_mut(vec!["get", "key"]).unwrap();
+    assert_eq!(m.subcommand_matches("get").unwrap().value_of("key"), Some("key"));
+}
+
+fn shared_args() -> Vec<Arg<'static, 'static>> {
+    vec![Arg::with_name("verbose").short("v").multiple(true),
+         Arg::with_name("config").long("config").takes_value(true),
+         Arg::with_name("input").required(true),
+         Arg::with_name("output")]
+}
+
+#[test]
+fn args_from_vec() {
+    let m = App::new("prog")
+        .args(shared_args())
+        .get_matches_from(vec!["prog", "-vv", "in.txt", "--config", "c.toml", "out.txt"]);
+
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.value_of("config"), Some("c.toml"));
+    assert_eq!(m.value_of("input"), Some("in.txt"));
+    assert_eq!(m.value_of("output"), Some("out.txt"));
+}
+
+#[test]
+fn args_from_iter_of_refs() {
+    let args = shared_args();
+    let m = App::new("prog")
+        .args(args.iter().filter(|a| a.name != "output"))
+        .get_matches_from_safe(vec!["prog", "in.txt", "out.txt"]);
+
+    // "output" was filtered out, so there's no positional for the second value
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn get_arguments_in_declaration_order() {
+    let app = App::new("prog")
+        .args(shared_args())
+        .arg(Arg::with_name("debug").short("d").long("debug"))
+        .subcommand(SubCommand::with_name("sub").arg(Arg::with_name("sub_arg")));
+
+    // the generated help and version flags, and subcommands' arguments, aren't included
+    assert_eq!(app.get_arguments().count(), 5);
+    assert_eq!(app.get_arguments().map(|a| a.get_name()).collect::<Vec<_>>(),
+               ["verbose", "config", "input", "output", "debug"]);
+
+    let debug = app.get_arguments().find(|a| a.get_name() == "debug").unwrap();
+    assert_eq!(debug.get_short(), Some('d'));
+    assert_eq!(debug.get_long(), Some("debug"));
+    let input = app.get_arguments().find(|a| a.get_name() == "input").unwrap();
+    assert_eq!(input.get_short(), None);
+    assert_eq!(input.get_long(), None);
+}
+
+#[test]
+fn arg_getters() {
+    let arg = Arg::with_name("file")
+        .short("f")
+        .long("file")
+        .help("the file to use")
+        .value_names(&["PATH", "MODE"])
+        .required(true)
+        .multiple(true);
+
+    assert_eq!(arg.get_name(), "file");
+    assert_eq!(arg.get_short(), Some('f'));
+    assert_eq!(arg.get_long(), Some("file"));
+    assert_eq!(arg.get_help(), Some("the file to use"));
+    assert_eq!(arg.get_value_names(), Some(vec!["PATH", "MODE"]));
+    assert!(arg.is_required());
+    assert!(arg.is_multiple());
+
+    let arg = Arg::with_name("out").value_name("OUT");
+    assert_eq!(arg.get_value_names(), Some(vec!["OUT"]));
+}
+
+#[test]
+fn arg_getters_unset() {
+    let arg = Arg::with_name("plain");
+
+    assert_eq!(arg.get_name(), "plain");
+    assert_eq!(arg.get_short(), None);
+    assert_eq!(arg.get_long(), None);
+    assert_eq!(arg.get_help(), None);
+    assert_eq!(arg.get_value_names(), None);
+    assert!(!arg.is_required());
+    assert!(!arg.is_multiple());
+}
+
+#[test]
+fn arg_getters_from_usage() {
+    let arg = Arg::from_usage("-c, --config=[FILE]... 'sets the config'");
+
+    assert_eq!(arg.get_short(), Some('c'));
+    assert_eq!(arg.get_long(), Some("config"));
+    assert_eq!(arg.get_help(), Some("sets the config"));
+    assert_eq!(arg.get_value_names(), Some(vec!["FILE"]));
+    assert!(!arg.is_required());
+    assert!(arg.is_multiple());
+}
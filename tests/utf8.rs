@@ -2,8 +2,8 @@
 
 extern crate clap;
 
-use std::ffi::OsString;
-use std::os::unix::ffi::OsStringExt;
+use std::ffi::{OsStr, OsString};
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use clap::{App, Arg, AppSettings, ErrorKind};
 
 #[test]
@@ -221,3 +221,75 @@ fn invalid_utf8_option_long_equals() {
     assert!(m.is_present("arg"));
     assert_eq!(&*m.value_of_os("arg").unwrap(), &*OsString::from_vec(vec![0xe9]));
 }
+
+#[test]
+fn invalid_utf8_normalize_path_separators() {
+    let m = App::new("bad_utf8")
+        .arg(Arg::from_usage("<arg> 'some arg'").normalize_path_separators(true))
+        .get_matches_from_safe(vec![OsString::from(""),
+                                    OsString::from_vec(vec![b'a', b'\\', 0xe9])]);
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(&*m.value_of_os("arg").unwrap(),
+               &*OsString::from_vec(vec![b'a', b'/', 0xe9]));
+}
+
+#[test]
+fn invalid_utf8_default_value_os_option() {
+    let m = App::new("bad_utf8")
+        .arg(Arg::from_usage("-a, --arg [arg] 'some arg'")
+            .default_value_os(OsStr::from_bytes(&[b'/', 0xe9, b'x'])))
+        .get_matches_from_safe(vec![OsString::from("")])
+        .unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of_os("arg").unwrap().as_bytes(), &[b'/', 0xe9, b'x']);
+    assert_eq!(m.values_of_os("arg").unwrap().map(|v| v.as_bytes()).collect::<Vec<_>>(),
+               [&[b'/', 0xe9, b'x'][..]]);
+}
+
+#[test]
+fn invalid_utf8_default_value_os_positional() {
+    let m = App::new("bad_utf8")
+        .arg(Arg::from_usage("[arg] 'some arg'")
+            .default_value_os(OsStr::from_bytes(&[0xe9])))
+        .get_matches_from_safe(vec![OsString::from("")])
+        .unwrap();
+    assert_eq!(m.value_of_os("arg").unwrap().as_bytes(), &[0xe9]);
+}
+
+#[test]
+fn invalid_utf8_default_value_os_user_value() {
+    let m = App::new("bad_utf8")
+        .arg(Arg::from_usage("[arg] 'some arg'")
+            .default_value_os(OsStr::from_bytes(&[0xe9])))
+        .get_matches_from_safe(vec![OsString::from(""), OsString::from("given")])
+        .unwrap();
+    assert_eq!(m.value_of("arg"), Some("given"));
+}
+
+#[test]
+fn invalid_utf8_default_value_os_help() {
+    let mut help = vec![];
+    App::new("bad_utf8")
+        .arg(Arg::from_usage("[arg] 'some arg'")
+            .default_value_os(OsStr::from_bytes(&[b'a', 0xe9])))
+        .write_help(&mut help)
+        .unwrap();
+    assert!(String::from_utf8(help).unwrap().contains("[default: a\u{fffd}]"));
+}
+
+#[test]
+fn invalid_utf8_env_os() {
+    use std::env;
+
+    let name = OsStr::from_bytes(b"CLP_TEST_ENV_OS_\xe9");
+    env::set_var(name, OsStr::from_bytes(&[b'a', 0xe9]));
+
+    let m = App::new("bad_utf8")
+        .arg(Arg::from_usage("[arg] 'some arg'").env_os(name))
+        .get_matches_from_safe(vec![OsString::from("")])
+        .unwrap();
+    assert_eq!(m.value_of_os("arg").unwrap(), OsStr::from_bytes(&[b'a', 0xe9]));
+    assert_eq!(m.occurrences_of("arg"), 0);
+}
@@ -0,0 +1,58 @@
+extern crate clap;
+
+use std::env;
+use std::process::Command;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
+
+// Set when this test binary re-runs itself to exercise the real exit path
+const CHILD_ENV: &'static str = "CLAP_VALUE_OF_OR_EXIT_CHILD";
+
+#[test]
+fn value_of_or_err_present() {
+    let m = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("mode").long("mode").takes_value(true))
+        .arg(Arg::with_name("dest").long("dest").takes_value(true))
+        .get_matches_from(vec!["prog", "--dest", "out"]);
+    assert_eq!(m.value_of_or_err("dest").unwrap(), "out");
+    assert_eq!(m.value_of_or_exit("dest"), "out");
+}
+
+#[test]
+fn value_of_or_err_missing() {
+    let m = App::new("prog")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("mode").long("mode").takes_value(true))
+        .arg(Arg::with_name("dest").long("dest").takes_value(true))
+        .get_matches_from(vec!["prog", "--mode", "move"]);
+    let err = m.value_of_or_err("dest").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert_eq!(err.info, Some(vec!["dest".to_owned()]));
+    assert!(err.message.starts_with("error: The following required arguments were not provided:\n    dest"));
+    assert!(err.message.contains("USAGE:\n    prog [OPTIONS]"));
+}
+
+#[test]
+fn value_of_or_exit_missing_exits() {
+    if env::var_os(CHILD_ENV).is_some() {
+        let m = App::new("prog")
+            .setting(AppSettings::ColorNever)
+            .arg(Arg::with_name("mode").long("mode").takes_value(true))
+            .arg(Arg::with_name("dest").long("dest").takes_value(true))
+            .get_matches_from(vec!["prog", "--mode", "move"]);
+        m.value_of_or_exit("dest");
+        unreachable!();
+    }
+
+    let out = Command::new(env::current_exe().unwrap())
+        .arg("value_of_or_exit_missing_exits")
+        .arg("--nocapture")
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+
+    assert_eq!(out.status.code(), Some(2));
+    assert!(stderr.contains("error: The following required arguments were not provided:\n    dest"));
+}
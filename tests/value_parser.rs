@@ -0,0 +1,146 @@
+extern crate clap;
+
+use std::path::PathBuf;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
+
+fn port(v: &str) -> Result<u16, String> {
+    v.parse().map_err(|_| format!("'{}' isn't a valid port number", v))
+}
+
+#[test]
+fn value_parser_typed_round_trip() {
+    let m = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser(port))
+        .arg(Arg::with_name("root")
+            .takes_value(true)
+            .value_parser(|v| Ok(PathBuf::from(v))))
+        .get_matches_from(vec!["server", "--port", "8080", "/srv/www"]);
+
+    assert_eq!(m.value_of_t::<u16, _>("port"), Some(&8080));
+    assert_eq!(m.value_of_t::<PathBuf, _>("root"), Some(&PathBuf::from("/srv/www")));
+    // the original strings are still there
+    assert_eq!(m.value_of("port"), Some("8080"));
+}
+
+#[test]
+fn value_parser_not_present() {
+    let m = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser(port))
+        .arg(Arg::with_name("root")
+            .takes_value(true)
+            .value_parser(|v| Ok(PathBuf::from(v))))
+        .get_matches_from(vec!["server"]);
+
+    assert_eq!(m.value_of_t::<u16, _>("port"), None);
+    assert!(m.values_of_t::<PathBuf, _>("root").is_none());
+}
+
+#[test]
+fn value_parser_errors_during_parse() {
+    let err = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser(port))
+        .arg(Arg::with_name("root")
+            .takes_value(true)
+            .value_parser(|v| Ok(PathBuf::from(v))))
+        .get_matches_from_safe(vec!["server", "--port", "http"]).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.message, "error: 'http' isn't a valid port number");
+}
+
+#[test]
+fn value_parser_multiple_values() {
+    let m = App::new("nums")
+        .arg(Arg::with_name("nums")
+            .long("num")
+            .takes_value(true)
+            .multiple(true)
+            .use_delimiter(true)
+            .value_parser(|v| v.parse::<i64>().map_err(|e| e.to_string())))
+        .get_matches_from(vec!["nums", "--num", "1,-2", "--num", "3"]);
+
+    let nums: Vec<i64> = m.values_of_t::<i64, _>("nums").unwrap().into_iter().cloned().collect();
+    assert_eq!(nums, [1, -2, 3]);
+    assert_eq!(m.value_of_t::<i64, _>("nums"), Some(&1));
+}
+
+#[test]
+fn value_parser_default_value() {
+    let m = App::new("server")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .default_value("80")
+            .value_parser(port))
+        .get_matches_from(vec!["server"]);
+    assert_eq!(m.value_of_t::<u16, _>("port"), Some(&80));
+
+    let err = App::new("server")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .default_value("eighty")
+            .value_parser(port))
+        .get_matches_from_safe(vec!["server"])
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+#[should_panic(expected = "with a type other than the one its value_parser produces")]
+fn value_parser_wrong_type() {
+    let m = App::new("server")
+        .setting(AppSettings::ColorNever)
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser(port))
+        .arg(Arg::with_name("root")
+            .takes_value(true)
+            .value_parser(|v| Ok(PathBuf::from(v))))
+        .get_matches_from(vec!["server", "--port", "8080"]);
+    m.value_of_t::<u32, _>("port");
+}
+
+#[test]
+#[should_panic(expected = "which has no value_parser")]
+fn value_of_t_without_value_parser() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("name").takes_value(true))
+        .get_matches_from(vec!["prog", "me"]);
+    m.value_of_t::<String, _>("name");
+}
+
+#[test]
+#[should_panic(expected = "which has no value_parser")]
+fn values_of_t_on_flag() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose").short("v"))
+        .get_matches_from(vec!["prog", "-v"]);
+    m.values_of_t::<bool, _>("verbose");
+}
+
+#[test]
+fn values_of_t_option_without_value() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("level")
+            .long("level")
+            .takes_value(true)
+            .min_values(0)
+            .value_parser(|v| v.parse::<u8>().map_err(|e| e.to_string())))
+        .get_matches_from(vec!["prog", "--level"]);
+    assert!(m.values_of_t::<u8, _>("level").unwrap().is_empty());
+}
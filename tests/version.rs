@@ -35,3 +35,68 @@ fn version_long() {
 fn complex_version_output() {
     test::check_version(test::complex_app(), VERSION);
 }
+
+#[test]
+fn write_version_to_buffer() {
+    let mut buf = Vec::new();
+    App::new("test").version("1.3").write_version(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), "test 1.3");
+}
+
+#[test]
+fn write_version_ignores_long_version() {
+    let mut buf = Vec::new();
+    App::new("test")
+        .version("1.3")
+        .long_version("1.3\ncommit: 4a6f2bd\nfeatures: yaml")
+        .write_version(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), "test 1.3");
+}
+
+#[test]
+fn write_long_version_to_buffer() {
+    let mut buf = Vec::new();
+    App::new("test")
+        .version("1.3")
+        .long_version("1.3\ncommit: 4a6f2bd\nfeatures: yaml")
+        .write_long_version(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(),
+               "test 1.3\ncommit: 4a6f2bd\nfeatures: yaml");
+}
+
+#[test]
+fn write_long_version_falls_back_to_version() {
+    let mut buf = Vec::new();
+    App::new("test").version("1.3").write_long_version(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), "test 1.3");
+}
+
+#[test]
+fn long_version_still_displays() {
+    let m = App::new("test")
+        .version("1.3")
+        .long_version("1.3\ncommit: 4a6f2bd\nfeatures: yaml")
+        .get_matches_from_safe(vec!["myprog", "--version"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::VersionDisplayed);
+}
+
+#[test]
+fn custom_version_short() {
+    let app = App::new("prog")
+        .version("1.0")
+        .version_short("v")
+        .arg(clap::Arg::with_name("verbose").short("V"));
+
+    let res = app.clone().get_matches_from_safe(vec!["prog", "-v"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::VersionDisplayed);
+
+    let m = app.get_matches_from(vec!["prog", "-V"]);
+    assert!(m.is_present("verbose"));
+}
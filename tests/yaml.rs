@@ -3,10 +3,128 @@
 #[macro_use]
 extern crate clap;
 
-use clap::App;
+use clap::{App, Arg, ErrorKind, SubCommand, YamlLoader};
 
 #[test]
 fn create_app_from_yaml() {
     let yml = load_yaml!("app.yml");
     App::from_yaml(yml);
 }
+
+static SMALL_YAML: &'static str = "
+name: yamlapp
+version: \"1.0\"
+author: Kevin K.
+args:
+    - verbose:
+        short: v
+        multiple: true
+    - input:
+        help: the input file
+        index: 1
+        required: true
+subcommands:
+    - fetch:
+        args:
+            - depth:
+                long: depth
+                takes_value: true
+                default_value: \"1\"
+";
+
+#[test]
+fn yaml_matches_hand_built() {
+    let docs = YamlLoader::load_from_str(SMALL_YAML).unwrap();
+    let args = vec!["yamlapp", "-vv", "file.txt", "fetch"];
+
+    let ym = App::from_yaml(&docs[0]).get_matches_from_safe(args.clone()).unwrap();
+    let hm = App::new("yamlapp")
+        .version("1.0")
+        .author("Kevin K.")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .arg(Arg::with_name("input")
+            .help("the input file")
+            .index(1)
+            .required(true))
+        .subcommand(SubCommand::with_name("fetch")
+            .arg(Arg::with_name("depth")
+                .long("depth")
+                .takes_value(true)
+                .default_value("1")))
+        .get_matches_from_safe(args).unwrap();
+
+    for m in &[ym, hm] {
+        assert_eq!(m.occurrences_of("verbose"), 2);
+        assert_eq!(m.value_of("input"), Some("file.txt"));
+        let sub = m.subcommand_matches("fetch").unwrap();
+        assert_eq!(sub.value_of("depth"), Some("1"));
+    }
+}
+
+#[test]
+fn yaml_missing_required_like_hand_built() {
+    let docs = YamlLoader::load_from_str(SMALL_YAML).unwrap();
+
+    let ye = App::from_yaml(&docs[0]).get_matches_from_safe(vec!["yamlapp"]).unwrap_err();
+    let he = App::new("yamlapp")
+        .version("1.0")
+        .author("Kevin K.")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .arg(Arg::with_name("input")
+            .help("the input file")
+            .index(1)
+            .required(true))
+        .subcommand(SubCommand::with_name("fetch")
+            .arg(Arg::with_name("depth")
+                .long("depth")
+                .takes_value(true)
+                .default_value("1")))
+        .get_matches_from_safe(vec!["yamlapp"]).unwrap_err();
+
+    assert_eq!(ye.kind, ErrorKind::MissingRequiredArgument);
+    assert_eq!(ye.kind, he.kind);
+}
+
+#[test]
+#[should_panic(expected = "Unknown App setting 'arguments' in YAML file for App 'yamlapp'")]
+fn yaml_unknown_app_key() {
+    let docs = YamlLoader::load_from_str("name: yamlapp\narguments: []").unwrap();
+    App::from_yaml(&docs[0]);
+}
+
+#[test]
+#[should_panic(expected = "Unknown Arg setting 'shrt' in YAML file for arg 'verbose'")]
+fn yaml_unknown_arg_key() {
+    let docs = YamlLoader::load_from_str("name: yamlapp\nargs:\n    - verbose:\n        shrt: v")
+        .unwrap();
+    App::from_yaml(&docs[0]);
+}
+
+#[test]
+fn yaml_aliases() {
+    let docs = YamlLoader::load_from_str("name: yamlapp
+args:
+    - output:
+        long: output
+        takes_value: true
+        aliases: [out, dest]
+subcommands:
+    - install:
+        aliases: inst
+        visible_aliases: [add]")
+        .unwrap();
+    let app = App::from_yaml(&docs[0]);
+
+    for alias in &["--out", "--dest"] {
+        let m = app.clone().get_matches_from(vec!["yamlapp", alias, "file"]);
+        assert_eq!(m.value_of("output"), Some("file"));
+    }
+    for alias in &["inst", "add"] {
+        let m = app.clone().get_matches_from(vec!["yamlapp", alias]);
+        assert_eq!(m.subcommand_name(), Some("install"));
+    }
+}